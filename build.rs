@@ -1,6 +1,10 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Rebuild if i18n files change
     println!("cargo:rerun-if-changed=i18n");
+    // Where `just install` puts the binaries, for the paths pkexec runs
+    println!("cargo:rerun-if-env-changed=EXAMINE_PREFIX");
+    let prefix = std::env::var("EXAMINE_PREFIX").unwrap_or_else(|_| "/usr".to_string());
+    println!("cargo:rustc-env=EXAMINE_PREFIX={prefix}");
     // Emit version information (if not cached by just vendor)
     let mut vergen = vergen::EmitBuilder::builder();
     println!("cargo:rerun-if-env-changed=VERGEN_GIT_COMMIT_DATE");
@@ -2,16 +2,173 @@
 app-title = Examine
 about = About
 git-description = Git commit {$hash} on {$date}
+file = File
+copy-page = Copy Page
+copy-summary = Copy Summary
+export-page = Export Page
+export-json = JSON…
+export-html = HTML…
+export-markdown = Markdown…
+export-text = Plain Text…
+export-field = Field
+export-value = Value
+export-raw-output = Include Raw Tool Output
+generate-report = Generate Report
+report-title = System Report
+report-generated = Generated { $time } by Examine { $version }
+raw-tool-output = Raw Tool Output
+exported = Exported to { $path }
+export-failed = Export failed: { $error }
+save-snapshot = Save Snapshot…
+open-snapshot = Open Snapshot…
+close-snapshot = Close Snapshot
+snapshot-shown = Snapshot { $name }, taken { $time }
+compare-snapshot = Compare with Snapshot…
+diff-title = Changes
+diff-since = Since { $name }, taken { $time }
+diff-none = Nothing changed.
+diff-added = Added
+diff-removed = Removed
+diff-changed = Changed
+diff-board = Board
+diff-product = Product
+diff-bios-vendor = BIOS Vendor
+diff-bios-version = BIOS Version
+diff-bios-date = BIOS Date
+diff-disks = Disks
+diff-vbios = Video BIOS of { $card }
+diff-firmware = Firmware of { $device }
+search-page = Search this page
+search-no-results = No rows match the search.
+search-all = Search All Pages
+settings = Settings
+settings-general = General
+settings-privacy = Privacy
+settings-pages = Pages
+move-page-up = Move Up
+move-page-down = Move Down
+page-order = Page Order
+reset-page-order = Restore Default
+settings-seconds = { $seconds } s
+command-timeout = Tool Timeout
+command-timeout-description = How long an external tool such as dmidecode may run before it is stopped.
+byte-units = Size Units
+byte-units-binary = KiB, MiB, GiB (1024)
+byte-units-decimal = kB, MB, GB (1000)
+redact-exports = Hide Identifying Values
+redact-exports-description = Leaves serial numbers, UUIDs, asset tags, hostnames, addresses, Wi-Fi network names and locations out of exports, reports and copied pages, so they can be shared publicly.
+export-raw-output-description = Appends the verbatim output of the tools to exports. Not available while identifying values are hidden, as it cannot be redacted.
+redacted = [hidden]
+search-all-placeholder = Search
+search-all-description = Searches the data of every page, such as "MAC address" or "UUID". Pages not opened yet are collected first.
+search-show = Show on Page
+search-more = { $count } more
+check-baseline = Check Against Baseline…
+baseline-open = Open Baseline
+baseline-open-failed = Cannot open the baseline: { $error }
+baseline-title = Baseline
+baseline-empty = The baseline has no requirements.
+baseline-passed = Meets every requirement
+baseline-failed = { $count ->
+    [one] Fails 1 requirement
+   *[other] Fails { $count } requirements
+}
+baseline-incomplete = { $count ->
+    [one] 1 requirement could not be checked
+   *[other] { $count } requirements could not be checked
+}
+baseline-result = { $actual } (expected { $expected })
+baseline-at-least = at least { $value }
+baseline-disk = Largest Fixed Disk
+baseline-tpm-version = TPM Version
+snapshot-schedule = Record Snapshots
+snapshot-schedule-off = Never
+snapshot-schedule-launch = On Every Launch
+snapshot-schedule-daily = Daily
+snapshot-history = Snapshot History
+history-description = Snapshots recorded on the schedule chosen under File → Record Snapshots. Compare one with now to find devices that disappeared or memory that went missing.
+history-empty = No snapshots recorded yet.
+history-taken = { $time } UTC
+compare = Compare
+open = Open
+snapshot-open-failed = Cannot open the snapshot: { $error }
+snapshot-unsupported = { $path } is a snapshot of version { $version }, which this version of Examine cannot read
+cli-missing-value = { $option } needs a value
+cli-unknown-page = Unknown page “{ $page }”, expected one of: { $pages }
+cli-unknown-format = Unknown format “{ $format }”, expected json, html, markdown or text
 view = View
+page-help = What Am I Looking At?
+diagnostics = Diagnostics
+environment = Environment
+flatpak-sandbox = Flatpak sandbox
+tools = Tools
+installed = Installed
+tool-detecting = Checking…
+tool-package = Missing, install {$package}
+copy = Copy
+copied = Copied to clipboard
+refresh = Refresh
+refresh-all = Refresh All Pages
+auto-refresh = Auto Refresh
+auto-refresh-off = Off
+auto-refresh-every = Every { $seconds } s
+hotplug-added = Device added: { $page }
+hotplug-removed = Device removed: { $page }
+color-blind-palette = Color-Blind Friendly Status Colors
 no-page = Select a Page
+overview = Overview
 distribution = Distribution
+desktop-session = Desktop & Session
+system-identity = System Identity
 processor = Processor
+motherboard = Motherboard
 pci-devices = PCIs
 usb-devices = USBs
+memory = Memory
+thermal = Thermal
+filesystems = Filesystems
+firmware = Firmware
+uefi = UEFI
+graphics = Graphics
+power = Power
+cameras = Cameras
+displays = Displays
+thunderbolt = Thunderbolt
+audio = Audio
+wifi = Wi-Fi
+modem = Modem
+controllers = Game Controllers
+security-devices = Security Devices
+tpm = TPM
+bmc = BMC
+date-time = Date & Time
+boot-performance = Boot Performance
 not-provided = Not Provided
+unknown = Unknown
+enabled = Enabled
+disabled = Disabled
+none = None
+yes = Yes
+no = No
+not-available = Not available
+active = Active
+inactive = Inactive
+requires-root = Requires administrator access
+supported = Supported
+unsupported = Not supported
 wip = Work in Progress
 error-occurred = An error has occurred
 error-occurred-with-msg = An error has occurred: {$error}
+error-missing = {$program} is not installed.
+error-permission-denied = {$program} was not allowed to read this information.
+error-timed-out = {$program} did not finish in time. A busy or unresponsive device can cause this.
+error-cancelled = Reading this information was cancelled.
+error-install-hint = Install the {$package} package to provide it.
+retry = Retry
+tool-missing = Needs {$program}, from the {$package} package
+cancel = Cancel
+undo-changes = Undo All Changes
+operation-undo = Undoing changes…
 
 # Distribution Page
 pretty-name = Pretty Name
@@ -40,3 +197,543 @@ variant-id = Variant ID
 default-hostname = Default Hostname
 arch = Architecture
 portable-prefixes = Portable Prefixes
+
+# Device Details
+device-details = Device Details
+no-device-selected = No device selected
+usb-ids = Vendor and Product ID
+usb-location = Bus { $bus } Device { $dev } · ID { $vendor }:{ $product }
+no-usb-devices = No USB devices found
+usb-port = Port
+usb-version = USB Version
+usb-speed = Speed
+usb-speed-mbps = { $speed } Mbit/s
+usb-max-power = Maximum Power
+usb-class = Class
+usb-interfaces = Interfaces
+pci-slot = Slot
+pci-ids = Vendor and Device ID
+no-pci-devices = No PCI devices found
+numa-node = NUMA Node
+local-cpus = Local CPUs
+kernel-driver = Kernel Driver
+kernel-module = Kernel Module
+built-in = Built into the kernel
+module-parameters = Module Parameters
+kernel-modules = Kernel Modules
+driver-in-use = Driver: { $driver }
+no-driver-in-use = No driver in use
+available-modules = Modules: { $modules }
+loading = Loading…
+pci-general = Configuration
+pci-interrupt = Interrupt
+pci-regions = Memory Regions
+pci-capabilities = Capabilities
+pci-capabilities-denied = Capabilities are only readable by an administrator.
+
+# USBs Page
+runtime-pm = Runtime PM: {$status}
+autosuspend-delay = Autosuspend after {$delay} ms
+autosuspend-disabled = Autosuspend disabled
+autosuspend-warning = Autosuspend is enabled on an input or audio device, which can cause dropouts. Consider disabling it for this device.
+
+# Memory Page
+memory-usage = Usage
+memory-slots = Memory Slots
+dmi-tables = DMI Tables
+dmi-tables-read = Read every record from the firmware's DMI tables
+memory-slots-read = Read the memory slot layout from the DMI tables
+memory-slots-populated = Populated Slots
+slots-populated = { $populated } of { $total }
+memory-channel = Channel { $channel }
+memory-other-slots = Other Slots
+empty-slot = Empty
+error-correction = Error Correction
+ecc = ECC Memory
+ecc-active = Active
+ecc-inactive = Not active
+ecc-errors = { $corrected } corrected, { $uncorrected } uncorrected
+hugepages = Hugepages
+hugepages-reserved = Reserved Hugepages
+hugepages-free-of-total = { $free } free of { $total }
+hugepage-size = Default Hugepage Size
+thp-enabled = Transparent Hugepages
+thp-defrag = Transparent Hugepage Defragmentation
+numa-nodes = NUMA Nodes
+numa-node-id = Node { $id }
+numa-cpus = CPUs { $cpus }
+numa-no-cpus = Memory only, no CPUs
+numa-distances = Node Distances
+used-of-total = {$used} of {$total}
+available = Available
+swap = Swap
+pressure = Pressure Stall Information
+pressure-cpu = CPU Pressure
+pressure-memory = Memory Pressure
+pressure-io = I/O Pressure
+pressure-averages = Share of time with stalled tasks — 10 s: {$avg10} %, 1 min: {$avg60} %, 5 min: {$avg300} %
+
+# Filesystems Page
+mounted-filesystems = Mounted Filesystems
+size = Size
+disk-type = Type
+disk-health = Health
+disk-health-details = Health Attributes
+health-passed = Passed
+health-failed = Failing
+removable = Removable
+rotational = Hard disk
+solid-state = Solid state
+partition-start = Starts at {$offset}
+trim = TRIM
+periodic-trim = Periodic TRIM (fstrim.timer)
+trim-last-run = Last Run
+trim-next-run = Next Run
+continuous-discard = Mounted with Discard
+trim-warning = Neither periodic TRIM nor continuous discard is enabled for any SSD
+discard-support = Discard (TRIM)
+partition-table = Partition Table
+misaligned = This partition is not aligned to the physical block size of the disk, which slows down writes
+not-mib-aligned = This partition does not start on a 1 MiB boundary
+raid-array = RAID Array {$name}
+raid-level = Level
+raid-devices = Devices
+raid-working = {$working} of {$total} working
+raid-sync = Synchronization
+raid-spare = Spare
+raid-faulty = Faulty
+degraded = Degraded
+healthy = Healthy
+lvm = LVM
+encryption = Disk Encryption
+unlocked-as = Unlocked as {$name}
+locked = Locked
+cipher-requires-root = Cipher details require administrator access
+volume-group = Volume Group {$name}
+free-extents = Free Extents
+extents = {$count} × {$size}
+physical-volume = Physical volume
+logical-volume = Logical volume · {$kind}
+size-free = {$size}, {$free} free
+
+# Firmware Page
+fwupd-version = fwupd {$version}
+open-updater = Open Updater
+updater-failed = Opening the updater failed: { $error }
+vendor = Vendor
+firmware-version = Firmware Version
+fwupd-plugin = Plugin
+update-status = Update Status
+update-available = Version {$version} available
+up-to-date = Up to date
+not-updatable = Not updatable
+
+# UEFI Page
+boot-mode = Boot Mode
+boot-mode-uefi = UEFI
+boot-mode-legacy = Legacy BIOS
+secure-boot = Secure Boot
+setup-mode = Setup Mode
+platform-size = Firmware Platform Size
+bits = {$bits}-bit
+firmware-vendor = Firmware Vendor
+firmware-date = Firmware Date
+
+# Graphics Page
+gpu-firmware = GPU Firmware (VBIOS)
+render-nodes = Render Nodes
+render-node-permissions = group {$group}, mode {$mode}
+accessible = Accessible
+not-accessible = Not accessible to you
+vulkan = Vulkan
+vulkan-device = Vulkan — {$name}
+opengl = OpenGL
+vaapi = VA-API
+vdpau = VDPAU
+device-type = Device Type
+api-version = API Version
+driver-name = Driver
+driver-info = Driver Info
+renderer = Renderer
+core-profile-version = Core Profile Version
+compat-profile-version = Compatibility Profile Version
+es-profile-version = OpenGL ES Version
+decode-profiles = Decode Profiles
+encode-profiles = Encode Profiles
+
+# Power Page
+ac-adapter = AC Adapter
+connected = Connected
+disconnected = Disconnected
+battery-care = Battery Care
+conservation-mode = Conservation Mode
+conservation-mode-description = Stop charging at around 60 % to extend battery lifespan
+manufacturer = Manufacturer
+model = Model
+technology = Technology
+status = Status
+charge = Charge
+cycle-count = Cycle Count
+battery-health = Health
+charge-limit = Charge Limit
+no-batteries = No batteries found
+operation-power = Changing battery settings…
+operation-power-profile = Changing power profile…
+power-profile = Power Profile
+profile-power-saver = Power Saver
+profile-balanced = Balanced
+profile-performance = Performance
+performance-degraded = Performance is limited: {$reason}
+change-charge-limit = Charge limit of {$battery}
+change-conservation-mode = Conservation mode
+change-power-profile = Power profile
+
+# Cameras Page
+no-cameras = No cameras found
+device-node = Device Node
+bus-info = Bus
+pixel-formats = Pixel Formats
+
+# Displays Page
+preferred-mode = Preferred Mode
+required-bandwidth = Required Bandwidth (uncompressed)
+link-bandwidth = Link Bandwidth
+link-usage = Link Usage (uncompressed)
+dsc = Display Stream Compression
+dsc-description = Modes that exceed the link bandwidth are only available with DSC
+
+# Thunderbolt Page
+no-thunderbolt = No Thunderbolt controllers found
+security-level = Security Level
+iommu-dma-protection = IOMMU DMA Protection
+host-controller = {$name} (Host)
+route = Route
+authorization = Authorization
+authorized = Authorized
+authorized-key = Authorized (secure key)
+unauthorized = Not authorized
+generation = Generation
+rx-speed = Receive Speed
+tx-speed = Transmit Speed
+
+# Audio Page
+pipewire-clock = PipeWire Clock
+sample-rate = Sample Rate
+allowed-rates = Allowed Rates
+quantum = Quantum
+min-quantum = Minimum Quantum
+max-quantum = Maximum Quantum
+force-quantum = Forced Quantum
+force-rate = Forced Rate
+latency = Latency per Quantum
+audio-nodes = Nodes
+driver-node = {$name} (Driver)
+node-timing = {$quantum} @ {$rate}
+xruns = { $count ->
+    [one] 1 xrun
+   *[other] {$count} xruns
+}
+xruns-description = Buffer under- or overruns, heard as clicks and dropouts
+
+# Wi-Fi Page
+no-wifi = No wireless adapters found
+mac-address = MAC Address
+wifi-capabilities = Bands, standards and link
+bands = Bands
+standards = Standards
+ssid = Network (SSID)
+bssid = Access Point (BSSID)
+frequency = Frequency
+signal = Signal Strength
+rx-bitrate = Receive Rate
+tx-bitrate = Transmit Rate
+
+# Modem Page
+no-modems = No modems found
+imei = IMEI
+operator = Operator
+registration = Registration
+sim = SIM
+sim-missing = No SIM card
+sim-locked = Locked, PIN required
+sim-ready = Ready
+sim-ready-operator = Ready ({$operator})
+
+# Game Controllers Page
+no-controllers = No game controllers found
+vendor-product-id = Vendor and Product ID
+axes = Axes
+buttons = Buttons
+force-feedback = Force Feedback
+
+# Security Devices Page
+fingerprint-readers = Fingerprint Readers
+fingerprint-reader = Fingerprint Reader
+enrolled-fingers = {$count ->
+    [one] 1 finger enrolled
+   *[other] {$count} fingers enrolled
+}
+scan-type = {$kind ->
+    [swipe] Swipe sensor
+   *[press] Press sensor
+}
+smartcard-readers = Smart-Card Readers
+smartcard-reader = Smart-Card Reader
+card = Card
+card-present = Inserted
+card-absent = Not inserted
+
+# TPM Page
+no-tpm = No TPM found
+tpm-kind = Type
+tpm-firmware = Firmware TPM
+tpm-discrete = Discrete TPM
+tpm-version = TPM Version
+pcr-banks = Active PCR Banks
+ima = Integrity Measurement Architecture
+ima-policy = Policy
+
+# BMC Page
+read-as-administrator = Read as Administrator
+operation-bmc = Reading the BMC…
+operation-dmi = Reading the DMI tables…
+operation-disk-health = Reading disk health…
+operation-collect = Collecting system data…
+operation-probe = Submitting a hardware probe…
+probe-menu = Submit Hardware Probe…
+probe-title = Submit a Hardware Probe?
+probe-privacy = hw-probe will collect the list of devices in this computer along with system logs, and upload them to linux-hardware.org, where they become public to help others find out which hardware works with Linux. Serial numbers, MAC and IP addresses and host names are removed or replaced by hashes before the upload, but the logs may still contain other details about this computer. Collecting needs administrator rights.
+probe-submit = Submit
+probe-privacy-details = Privacy Details
+probe-submitted = Probe submitted: { $url }
+probe-failed = Submitting the probe failed: { $error }
+ipmi-version = IPMI Version
+sensors = Sensors
+fru = FRU Inventory
+
+# Motherboard Page
+no-board-info = No motherboard information found
+system = System
+product-name = Product Name
+product-version = Version
+chassis-type = Chassis Type
+chassis-vendor = Chassis Manufacturer
+product-family = Family
+product-sku = SKU
+bios = BIOS
+bios-version = Version
+bios-date = Release Date
+bios-release = Release
+device-tree = Device Tree
+compatible = Compatible
+serial-number = Serial Number
+soc = System on Chip
+soc-family = Family
+soc-machine = Machine
+soc-id = SoC ID
+soc-revision = Revision
+
+# Date & Time Page
+timezone = Time Zone
+network-time = Network Time
+clock-synchronized = Clock Synchronized
+time-sync-daemon = Time Synchronization ({$daemon})
+ntp-servers = NTP Servers
+current-server = Current Server
+stratum = Stratum
+clock-offset = Clock Offset
+
+# Overview Page
+kernel = Kernel
+primary-disk = Primary Disk
+battery = Battery
+battery-summary = {$capacity} charged, {$health} health
+uptime = Uptime
+uptime-days = {$days}d {$hours}h {$minutes}m
+uptime-hours = {$hours}h {$minutes}m
+boot-time = Booted
+load-average = Load Average (1, 5, 15 min)
+fetch-view = Fetch View
+fetch-view-description = Show the summary terminal style, in the distribution's color, for sharing screenshots
+
+# Desktop & Session Page
+desktop-environment = Desktop Environment
+session-type = Session Type
+session-wayland = Wayland
+session-x11 = X11
+compositor = Compositor
+xwayland = Xwayland
+wayland-display = Wayland Display
+x-display = X11 Display
+session-id = Session ID
+locale = Locale
+runtime-dir = Runtime Directory
+
+# Boot Performance Page
+boot-phases = Boot Phases
+boot-firmware = Firmware
+boot-loader = Boot Loader
+boot-initrd = Initial RAM Disk
+boot-userspace = Userspace
+boot-total = Total
+slowest-units = Slowest Units
+
+# System Identity Page
+hostname = Hostname
+static-hostname = Static Hostname
+pretty-hostname = Pretty Hostname
+machine-id = Machine ID
+deployment = Deployment
+location = Location
+
+# Thermal Page
+no-thermal-zones = No thermal zones found
+temperature = Temperature
+trip-point = Trip Point ({$kind})
+cooling-device = Cooling device {$name}
+cooling-devices = Cooling Devices
+cooling-state = State {$current} of {$max}
+
+# Processor Page
+cpu-model-name = Model Name
+cpu-vendor = Vendor
+architecture = Architecture
+source-build-architecture = The architecture Examine was built for
+cpu-family-model = Family, Model and Stepping
+cpu-family-model-value = { $family }, { $model }, { $stepping }
+cpus-online = { $total } ({ $online } online)
+sockets = Sockets
+cores-per-socket = Cores per Socket
+threads-per-core = Threads per Core
+frequency-range = Frequency Range
+mhz-range = { $min }–{ $max } MHz
+virtualization = Virtualization
+bogomips = BogoMIPS
+cpu-vulnerabilities = CPU Vulnerabilities
+cpu-flags = Flags
+processor-overview = Overview
+processor-topology = Topology
+processor-state = Processor State
+microcode = Microcode Revision
+smt = Simultaneous Multithreading
+smt-force-off = Disabled on the kernel command line
+scaling-driver = Frequency Driver
+governor = Governor
+boost = Boost
+cpu-caches = Cache Hierarchy
+cache-level = Level
+cache-size = Size
+cache-ways = Ways
+cache-line = Line Size
+cache-shared-by = Shared By
+logical-cpus = Logical CPUs
+cpu-id = CPU
+cpu-core = Core
+cpu-socket = Socket
+cpu-node = Node
+cpu-min-mhz = Min MHz
+cpu-max-mhz = Max MHz
+offline = offline
+cache-cpus = { $count ->
+    [one] 1 CPU
+   *[other] { $count } CPUs
+}
+search-flags = Search flags, e.g. avx2 or virtualization
+not-affected = Not affected
+mitigated = Mitigated
+vulnerable = Vulnerable
+
+# PCI Page
+iommu = IOMMU
+iommu-disabled = Disabled
+iommu-enabled = Enabled, {$groups} groups
+group-by-iommu = Group by IOMMU Group
+iommu-group = IOMMU Group {$group}
+iommu-no-group = No IOMMU Group
+
+# Page Help
+help-source = Where does this come from?
+help-fields = What do the fields mean?
+help-problems = When is something wrong?
+help-privileged = Some details on this page are read as administrator, after you authenticate.
+help-distribution-source = The os-release file provided by your distribution, at /etc/os-release or /usr/lib/os-release.
+help-distribution-fields = Pretty Name is the name shown to users. ID and ID Like identify the distribution and the ones it is based on, which packaging tools use to pick compatible software. Support End is when the release stops receiving updates.
+help-distribution-problems = If Support End lies in the past, the system no longer receives security updates and should be upgraded.
+help-processor-source = /proc/cpuinfo for the model and flags, the topology, cache, cpufreq, microcode and SMT files in /sys/devices/system/cpu, and the vulnerability reports in /sys/devices/system/cpu/vulnerabilities.
+help-processor-fields = Model Name identifies the processor. Logical CPUs counts logical processors, which is sockets multiplied by cores per socket and threads per core. Virtualization shows whether VT-x or AMD-V is available to virtual machines. Processor State shows the loaded microcode revision, whether SMT (hyperthreading) is on, the frequency scaling driver and governor, and whether boost clocks are allowed. Cache Hierarchy shows each cache level with the size of one instance, how many instances exist, its associativity and how many logical CPUs share it. Logical CPUs shows which core, socket and NUMA node each logical CPU belongs to, so CPUs sharing a core are hyperthread siblings, along with its frequency limits. Flags lists the instruction set extensions the processor supports; hover a flag for a description or search to check for a specific one. CPU Vulnerabilities lists the hardware flaws the kernel knows about and how each is handled on this processor.
+help-processor-problems = Fewer CPUs than expected can mean cores were disabled in the firmware setup or on the kernel command line. A vulnerable entry usually means a microcode update is missing or mitigations were turned off with mitigations=off; compare the microcode revision with the latest your vendor ships. Boost disabled or a powersave governor can explain lower than expected performance.
+help-pci-source = /sys/bus/pci/devices, with names from the pci.ids database, candidate modules from the kernel's modules.alias and IOMMU groups from /sys/kernel/iommu_groups. An opened device also shows the output of lspci -vvv when lspci is installed.
+help-pci-fields = Each row is a device on the PCI bus, such as graphics cards, network adapters and storage controllers. The slot address identifies where it sits on the bus. Below each name is the kernel driver in use and the modules able to drive the device. Open a device to see the kernel driver bound to it, the modules that can drive it, its interrupt, memory regions and PCI capabilities such as the PCI Express link speed and width.
+help-pci-problems = A device without a kernel driver is not usable. This usually means the driver or its firmware is not installed. For passthrough to a virtual machine, every device in an IOMMU group must be passed through together; if the IOMMU is disabled, enable VT-d or AMD-Vi in the firmware setup.
+help-usb-source = The descriptors, interfaces and power management state in /sys/bus/usb/devices, with names from the usb.ids database.
+help-usb-fields = Each row is a USB device, including the root hubs of each controller, with its bus and device number and its vendor and product IDs. Runtime PM shows whether the device is active or suspended to save power. Open a device to see its class, serial number, negotiated speed, maximum power draw and each interface with the driver bound to it.
+help-usb-problems = A warning next to a device means autosuspend is enabled for an input or audio device, which is a common cause of lagging mice and crackling audio. An interface without a driver is not usable until a matching kernel module is loaded, and a device running at 480 Mbit/s or less on a faster port may be using a USB 2 cable.
+help-memory-source = /proc/meminfo for memory usage and hugepages, the dmidecode memory device records for the slot layout, the EDAC error counters in /sys/devices/system/edac and, once the DMI tables have been read, the dmidecode memory array for ECC, /sys/kernel/mm/transparent_hugepage for the THP modes, /sys/devices/system/node for the NUMA layout and /proc/pressure for pressure stall information.
+help-memory-fields = Available is the memory that can be used without swapping. Memory Slots shows every DIMM slot grouped by channel, with the size, type and speed of the installed module; hover a module for its manufacturer and part number. Error Correction shows whether ECC memory is active and, for each memory controller and module, how many errors it has corrected and how many it could not. Reserved Hugepages are set aside up front, usually for databases or virtual machines, and cannot be used for anything else; Transparent Hugepages shows whether the kernel uses large pages automatically (always), only where applications ask (madvise) or never. NUMA Nodes appears on systems with more than one node and shows each node's CPUs and local memory; Node Distances gives the relative cost of reaching one node's memory from another, where 10 means local. Pressure shows the share of time tasks were stalled waiting for CPU, memory or I/O.
+help-memory-problems = Sustained memory pressure above a few percent means the system is short on memory and is likely swapping. A node with no memory, or much less than its siblings, usually means DIMMs are missing from one socket's channels. Modules populated in only some channels, or in the wrong slots of a channel, run with less bandwidth; the motherboard manual lists which slots to fill first. A growing corrected error count on one module is an early sign that it is failing; any uncorrected error means data was lost and the module should be replaced. ECC shown as unknown usually means no EDAC driver is loaded for this memory controller.
+help-filesystems-source = /proc/self/mounts for mounted filesystems, /sys/block for disks and partitions, and smartctl or nvme smart-log, run as administrator, for disk health.
+help-filesystems-fields = Each mount shows its device, filesystem type, options and usage. Each disk lists its partitions with their offsets and types. Health shows the drive's own assessment once read as administrator, with attributes such as reallocated sectors, wear and power-on hours.
+help-filesystems-problems = Filesystems that are nearly full, degraded RAID arrays and misaligned partitions all deserve attention. A failing health assessment, or a growing count of reallocated or pending sectors, means the drive should be backed up and replaced.
+help-firmware-source = The fwupd daemon, over D-Bus.
+help-firmware-fields = Each device managed by fwupd with its current firmware version and whether a newer version is available from the configured remotes.
+help-firmware-problems = An available update may fix bugs or security issues. Install it with the updater.
+help-uefi-source = /sys/firmware/efi, the UEFI variables in /sys/firmware/efi/efivars and /sys/class/dmi/id.
+help-uefi-fields = Boot Mode tells whether the system started through UEFI or legacy BIOS. Secure Boot only allows signed boot loaders and kernels. Setup Mode means no platform key is enrolled.
+help-uefi-problems = Secure Boot being disabled or Setup Mode being enabled means the boot chain is not verified.
+help-graphics-source = The output of vulkaninfo, glxinfo, vainfo and vdpauinfo, the VBIOS version from /sys/class/drm or nvidia-smi, and the render nodes in /dev/dri.
+help-graphics-fields = The VBIOS version of each GPU, often asked for in driver bug reports, the API versions and drivers in use for 3D rendering, and the video codecs that can be decoded or encoded in hardware.
+help-graphics-problems = A software renderer such as llvmpipe or a missing decode profile means hardware acceleration is not working. If a render node is not accessible to you, add your user to its group; containers and Flatpaks need the node passed through to them.
+help-power-source = /sys/class/power_supply, vendor specific driver settings and power-profiles-daemon.
+help-power-fields = The power profile trades performance for battery life and can be switched here. Health compares the current full capacity to the design capacity. Charge Limit and Conservation Mode stop charging early to prolong battery life.
+help-power-problems = A health well below 80 % means the battery is worn and holds noticeably less charge. If performance is limited, the firmware has throttled the performance profile, for example because the laptop is on your lap or too hot.
+help-cameras-source = /sys/class/video4linux and the output of v4l2-ctl.
+help-cameras-fields = Each capture device with its kernel driver and the pixel formats and resolutions it supports.
+help-cameras-problems = A camera without any pixel formats is usually claimed by another application or needs a different driver.
+help-displays-source = /sys/class/drm, the EDID of each monitor and, for administrators, the driver's debugfs entries.
+help-displays-fields = The preferred mode and refresh rate of each monitor, the bandwidth the mode needs uncompressed and whether Display Stream Compression is in use.
+help-displays-problems = If the required bandwidth exceeds the link bandwidth, that mode is only available with DSC or a better cable.
+help-thunderbolt-source = /sys/bus/thunderbolt.
+help-thunderbolt-fields = The security level decides which devices connect automatically. Authorization shows whether a device is allowed to use PCIe tunnels.
+help-thunderbolt-problems = A dock that is not authorized only provides limited functionality until it is approved.
+help-audio-source = The PipeWire settings metadata and pw-top.
+help-audio-fields = The quantum is the buffer size in samples. Together with the sample rate it determines the latency. Xruns count buffer under- and overruns.
+help-audio-problems = A rising xrun count means the quantum is too small for the current load.
+help-wifi-source = The output of iw, which talks to the kernel's nl80211 interface.
+help-wifi-fields = The bands and Wi-Fi standards the adapter supports, and for the current connection the frequency, signal strength and negotiated rates.
+help-wifi-problems = A signal weaker than about -70 dBm or rates far below what the adapter supports point to interference or distance from the access point.
+help-modem-source = ModemManager, over D-Bus.
+help-modem-fields = The modem's identity and firmware, the state of the SIM card, the network it is registered with and the signal quality.
+help-modem-problems = A registration state of denied usually means the SIM card is not activated or not allowed on that network.
+help-controllers-source = The evdev capabilities of each input device in /sys/class/input.
+help-controllers-fields = Each joystick or gamepad with its USB or Bluetooth IDs, device nodes, the number of axes and buttons and whether it supports rumble.
+help-controllers-problems = A controller that is missing here is not recognized by the kernel; one with fewer buttons than expected may need a specific driver such as xpad or hid-playstation.
+help-security-devices-source = The fingerprint readers known to fprintd and the smart-card readers known to pcscd, read with opensc-tool, or else USB devices with a smart-card interface.
+help-security-devices-fields = Each fingerprint reader's driver, whether it is a press or swipe sensor and how many fingers are enrolled for you, and each smart-card reader with whether a card or security key is inserted.
+help-security-devices-problems = If fprintd is not installed or your reader is missing, check whether libfprint supports it; readers without a driver will not appear. A smart-card reader whose card state is unknown was found on USB, but pcscd is not running.
+help-tpm-source = The TPM in /sys/class/tpm, its vendor from tpm2_getcap and the Integrity Measurement Architecture in /sys/kernel/security/ima.
+help-tpm-fields = Whether the TPM is built into the processor's firmware or is a separate chip, its version and driver, the hash algorithms of its active PCR banks and whether IMA is measuring files.
+help-tpm-problems = Tools that enroll disk encryption keys usually need the SHA-256 PCR bank. If it is missing, enable it in the firmware setup. A firmware TPM's keys can be lost when the firmware is updated.
+help-bmc-source = The baseboard management controller behind /dev/ipmi0, read with ipmitool.
+help-bmc-fields = The BMC's vendor, firmware and IPMI version, every sensor record with its reading and state, and the field replaceable unit inventory with board and chassis serial numbers.
+help-bmc-problems = Sensors marked critical or non-recoverable need attention. /dev/ipmi0 is usually only readable by root, so use Read as Administrator if the page shows a permission error.
+help-motherboard-source = The DMI tables in /sys/class/dmi/id, readable without administrator access, and in full from dmidecode when read as administrator, on PCs, or /proc/device-tree and /sys/devices/soc0 on ARM and RISC-V boards such as the Raspberry Pi.
+help-motherboard-fields = The maker and model of the computer and its motherboard, and the version and date of the BIOS or UEFI firmware. Serial numbers are only shown after reading the DMI tables as administrator. On device tree systems, the board model and its compatible strings, most specific first, which decide the drivers the kernel loads. DMI Tables lists every record the firmware provides, such as BIOS, Base Board and Chassis; expand a record to see its fields.
+help-motherboard-problems = Placeholder strings mean the vendor did not fill in the DMI tables. A generic compatible string on an SBC can mean the distribution's kernel lacks support for the exact board.
+help-time-source = The output of timedatectl, and of chronyc or timedatectl timesync-status depending on which time daemon is running.
+help-time-fields = The time zone, whether network time is enabled and the clock synchronized, the configured NTP servers, the server in use, its stratum (distance from a reference clock) and how far the system clock is off.
+help-time-problems = A clock that is not synchronized or an offset of more than a few hundred milliseconds can break TLS certificates and Kerberos logins. Check that the NTP servers are reachable.
+help-overview-source = The same data as the detailed pages, plus the kernel release from /proc/sys/kernel/osrelease and the uptime and load averages from /proc/uptime and /proc/loadavg, refreshed every few seconds.
+help-overview-fields = The distribution, kernel, how long the system has been running, the load averages, processor model, installed memory, main GPU, the disk holding the root file system and the first battery's charge and health.
+help-overview-problems = A software renderer such as llvmpipe listed as the GPU means hardware acceleration is not working; see the Graphics page. A load average that stays above the number of logical CPUs means processes are waiting for CPU time or disk I/O.
+help-session-source = The environment variables set by the login manager, such as XDG_SESSION_TYPE and XDG_CURRENT_DESKTOP, and the running processes.
+help-session-fields = The desktop, whether the session uses Wayland or X11, the compositor drawing the screen and whether Xwayland is available for X11 applications, the display sockets, the locale and the XDG runtime directory.
+help-session-problems = Screen sharing, global shortcuts and input methods behave differently under Wayland and X11, so mention the session type when asking for help. A missing runtime directory breaks many applications.
+help-boot-source = The output of systemd-analyze and systemd-analyze blame for the current boot.
+help-boot-fields = How long the firmware, boot loader, kernel, initial RAM disk and userspace took, and the units that took longest to start.
+help-boot-problems = Units start in parallel, so the slowest unit is not always what delays the desktop. NetworkManager-wait-online and similar wait units often look slow without holding anything up. A long firmware phase can only be improved in the firmware setup.
+help-identity-source = The properties of systemd-hostnamed and /etc/machine-id.
+help-identity-fields = The hostname in use, the static hostname stored in /etc/hostname and the descriptive pretty hostname, the kind of machine, the unique machine ID, and the deployment environment and location set with hostnamectl.
+help-identity-problems = A hostname that differs from the static hostname was usually assigned by DHCP. Cloned installations sharing a machine ID get the same DHCP leases and confuse management tools; regenerate it with systemd-machine-id-setup.
+help-thermal-source = The thermal zones and cooling devices in /sys/class/thermal, provided by ACPI and platform drivers.
+help-thermal-fields = Each zone's current temperature and its trip points: passive trip points throttle the processor, active ones turn on fans, and the critical one shuts the system down. Cooling devices show their current and highest state, such as a fan's speed step or how far the processor is throttled.
+help-thermal-problems = A temperature at or above a passive trip point means the system is throttling. Zones reading far off or fixed values usually come from buggy firmware tables and can be ignored.
@@ -1,19 +1,66 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::config::Config;
-use crate::fl;
+use crate::config::{ByteUnits, Config, SnapshotSchedule, StatusPalette};
+use crate::pages::{self, AnyProvider, AnySection, Page};
+use crate::{
+    baseline, diff, export, fl, host, hotplug, hw_probe, ipmi, load, power, power_profiles, smart, snapshot, storage, tools,
+};
 use cosmic::app::{Core, Task};
-use cosmic::cosmic_config::{self, CosmicConfigEntry};
-use cosmic::iced::{stream, Subscription, alignment, Alignment, Length};
+use cosmic::cosmic_config;
+use cosmic::iced::widget::scrollable::{self, AbsoluteOffset, RelativeOffset};
+use cosmic::iced::keyboard::{self, key::Named, Key, Modifiers};
+use cosmic::iced::{clipboard, stream, task, Subscription, alignment, Alignment, Color, Length};
 use cosmic::widget::{self, icon, list_column, menu, nav_bar, row, settings};
 use cosmic::{cosmic_theme, theme, Application, ApplicationExt, Apply, Element};
-use etc_os_release::OsRelease;
 use futures_util::SinkExt;
 use itertools::Itertools;
-use std::{collections::HashMap, fs, path::PathBuf, str::FromStr};
-use log::{error, warn};
+use std::{borrow::Cow, collections::{HashMap, HashSet}, fs, path::{Path, PathBuf}, sync::LazyLock, time::Duration};
+use log::{error, info, warn};
+use serde_json::{json, Value};
+
+/// Shared by every page, so that its offset can be saved and restored on navigation.
+pub(crate) static PAGE_SCROLLABLE: LazyLock<widget::Id> = LazyLock::new(|| widget::Id::new("page-scrollable"));
+static GLOBAL_SEARCH_INPUT: LazyLock<widget::Id> = LazyLock::new(|| widget::Id::new("global-search-input"));
+
+/// How many matches of each page the global search lists.
+const GLOBAL_SEARCH_MATCHES: usize = 10;
 
 const REPOSITORY: &str = "https://github.com/cosmic-utils/examine";
+
+/// How long a notice, such as a device being plugged in, stays in the header.
+const NOTICE_DURATION: Duration = Duration::from_secs(5);
+
+/// Auto refresh intervals offered in the View menu, in seconds.
+const AUTO_REFRESH_INTERVALS: &[u64] = &[0, 2, 5, 10, 30];
+
+/// Command timeouts offered in the settings, in seconds.
+const COMMAND_TIMEOUTS: &[u64] = &[5, 10, 30, 60, 120];
+
+/// The labels of the settings' dropdowns, in the order of their values.
+static AUTO_REFRESH_LABELS: LazyLock<Vec<String>> =
+    LazyLock::new(|| AUTO_REFRESH_INTERVALS.iter().map(|&seconds| auto_refresh_label(seconds)).collect());
+static COMMAND_TIMEOUT_LABELS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    COMMAND_TIMEOUTS
+        .iter()
+        .map(|&seconds| fl!("settings-seconds", seconds = seconds))
+        .collect()
+});
+static BYTE_UNIT_LABELS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    ByteUnits::ALL
+        .iter()
+        .map(|units| match units {
+            ByteUnits::Binary => fl!("byte-units-binary"),
+            ByteUnits::Decimal => fl!("byte-units-decimal"),
+        })
+        .collect()
+});
+static SNAPSHOT_SCHEDULE_LABELS: LazyLock<Vec<String>> =
+    LazyLock::new(|| SnapshotSchedule::ALL.iter().map(|&schedule| snapshot_schedule_label(schedule)).collect());
+
+/// How often uptime and load averages are refreshed on the overview.
+const LOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+const UPDATER: &str = "cosmic-store";
 const APP_ICON: &[u8] =
     include_bytes!("../res/icons/hicolor/scalable/apps/io.github.cosmic_utils.Examine.svg");
 
@@ -22,24 +69,252 @@ pub struct AppModel {
     context_page: ContextPage,
     nav: nav_bar::Model,
     key_binds: HashMap<menu::KeyBind, MenuAction>,
+    config_handler: Option<cosmic_config::Config>,
     config: Config,
-    lscpu: Option<String>,
-    lspci: Option<String>,
-    lsusb: Option<String>,
+    pub(crate) processor: pages::processor::State,
+    pub(crate) pci: pages::pci::State,
+    pub(crate) usb: pages::usb::State,
+    /// The data collected for each page, or the snapshot's while one is shown.
+    pub(crate) sections: pages::Sections,
+    /// The pages whose data is being collected, so that each is collected once at a time.
+    loading: HashSet<Page>,
+    /// Whether the overview is drawn neofetch style, for screenshots.
+    pub(crate) fetch_view: bool,
+    operations: Vec<Operation>,
+    next_operation_id: u64,
+    changes: Vec<Change>,
+    pub(crate) scroll_offsets: HashMap<Page, AbsoluteOffset>,
+    /// Progress of collecting every page at once, shown in the header.
+    collection: Option<Collection>,
+    /// The format of the full report to save once the running collection ends.
+    report_pending: Option<export::Format>,
+    /// Briefly shown in the header, e.g. after a device was plugged in or removed.
+    notice: Option<String>,
+    /// Counts notices, so that only the latest notice's timer clears it.
+    notice_sequence: u64,
+    /// Collapsible sections the user expanded this session, by page and section id.
+    expanded_sections: HashSet<(Page, String)>,
+    /// The snapshot shown instead of this system's data, read-only.
+    snapshot: Option<OpenSnapshot>,
+    /// Whether to save a snapshot once the running collection ends.
+    snapshot_pending: bool,
+    /// The changes since the snapshot last compared with, shown in the context drawer.
+    comparison: Option<Comparison>,
+    /// The snapshot to compare with once the running collection ends.
+    comparison_pending: Option<(String, Box<snapshot::Snapshot>)>,
+    /// The snapshots recorded on schedule, newest first.
+    history: Vec<snapshot::Entry>,
+    /// Whether to record a snapshot into the history once the running collection ends.
+    record_pending: bool,
+    /// Whether the privacy confirmation before submitting a hardware probe is shown.
+    probe_dialog: bool,
+    /// The results of the baseline last checked against, shown in the context drawer.
+    compliance: Option<Compliance>,
+    /// The baseline to check against once the running collection ends.
+    baseline_pending: Option<baseline::Baseline>,
+    /// The text the page shown is filtered by, cleared when switching pages.
+    search: String,
+    /// The rows of the page shown matching [`search`](Self::search), as label,
+    /// value and source, found again when the search or the page's data changes.
+    search_matches: Vec<(String, String, &'static str)>,
+    /// The label of the row chosen in the global search, highlighted among
+    /// the rows of the page matching it.
+    search_match: Option<String>,
+    /// The text searched for across every page.
+    global_search: String,
+    /// The rows matching the global search, by page.
+    global_matches: Vec<(Page, Vec<(String, String, &'static str)>)>,
+}
+
+/// The results of checking the data shown against a baseline.
+struct Compliance {
+    name: String,
+    checks: Vec<baseline::Check>,
+}
+
+/// The changes from a snapshot to the data shown.
+struct Comparison {
+    name: String,
+    taken: String,
+    changes: Vec<diff::Change>,
+}
+
+/// A snapshot opened from a file.
+struct OpenSnapshot {
+    name: String,
+    taken: String,
+    /// The pages that applied to the system the snapshot was taken on.
+    pages: Vec<Page>,
+}
+
+/// A system setting changed during this session, kept so it can be undone.
+struct Change {
+    label: String,
+    setting: Setting,
+    /// The value before the first change this session.
+    original: String,
+}
+
+/// A system setting the Power page changes.
+#[derive(Clone, Debug, PartialEq)]
+enum Setting {
+    /// The charge limit of the named battery, written by the privileged helper.
+    ChargeLimit(String),
+    /// The IdeaPad conservation mode at this sysfs path, written by the privileged helper.
+    ConservationMode(PathBuf),
+    /// The active profile of power-profiles-daemon.
+    PowerProfile,
+}
+
+impl Setting {
+    fn write(&self, value: &str) -> Result<(), String> {
+        match self {
+            Setting::ChargeLimit(battery) => power::set_charge_limit(battery, value).map_err(|e| e.to_string()),
+            Setting::ConservationMode(path) => power::set_conservation_mode(path, value).map_err(|e| e.to_string()),
+            Setting::PowerProfile => power_profiles::set(value).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Collection of every page's data, running as a single operation.
+struct Collection {
+    operation: u64,
+    /// Pages whose data has not arrived yet.
+    pending: HashSet<Page>,
+    total: usize,
+}
+
+/// A long-running background operation shown in the header with a cancel button.
+///
+/// Cancelling aborts the task and discards its result; work that has already
+/// been handed to another process, such as a polkit prompt, runs to completion.
+struct Operation {
+    id: u64,
+    label: String,
+    handle: task::Handle,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
+    AutoRefresh,
+    /// The baseline to check against, or `None` when the user cancelled.
+    BaselineOpened(Result<Option<baseline::Baseline>, host::Error>),
+    CancelOperation(u64),
+    CancelProbe,
+    CancelTools,
+    ChangesUndone(Result<(), String>),
+    CheckBaseline,
+    CloseSnapshot,
+    CompareHistory(PathBuf),
+    CompareSnapshot,
+    ConfirmProbe,
+    /// The file name and contents of the snapshot to compare with, or `None` when the user cancelled.
+    ComparisonOpened(Result<Option<(String, Box<snapshot::Snapshot>)>, host::Error>),
+    CopyPage,
+    CopySummary,
+    CopyValue(String),
+    DataLoaded(Page, Result<Box<dyn AnySection>, host::Error>),
+    DiskHealthRead(String, Result<smart::Health, host::Error>),
+    Export(export::Format),
+    /// The path the page was exported to, or `None` when the user cancelled.
+    Exported(Result<Option<PathBuf>, String>),
+    GenerateReport(export::Format),
+    IpmiRead(Result<ipmi::Bmc, host::Error>),
+    Hotplug(hotplug::Event),
+    Key(Modifiers, Key),
+    LaunchUpdater,
     LaunchUrl(String),
+    /// Moves a page up or down the nav bar.
+    MovePage(Page, bool),
+    Motherboard(pages::motherboard::Message),
+    NoticeExpired(u64),
+    OpenGlobalSearch,
+    OpenHistory(PathBuf),
+    OpenSnapshot,
+    OperationFinished(u64),
+    PageScrolled(AbsoluteOffset),
+    Pci(pages::pci::Message),
+    PowerProfileSet(Result<(), String>),
+    PowerWritten(Result<(), String>),
+    Processor(pages::processor::Message),
+    /// The address of the probe's page on linux-hardware.org.
+    ProbeSubmitted(Result<String, String>),
+    /// Collects a page's data again, or every page's when `None`.
+    Refresh(Option<Page>),
+    RefreshLoad,
+    ReadDiskHealth(String),
+    ReadIpmiPrivileged,
+    ResetPageOrder,
+    SaveSnapshot,
+    Search(String),
+    SearchAll(String),
+    SetAutoRefresh(u64),
+    SetByteUnits(ByteUnits),
+    SetChargeLimit(String, u8),
+    SetCommandTimeout(u64),
+    SetConservationMode(bool),
+    SetPageVisible(Page, bool),
+    SetPowerProfile(String),
+    SetSnapshotSchedule(SnapshotSchedule),
+    /// Opens a page filtered by the global search, on the row of the label
+    /// given or else at the top.
+    ShowMatch(Page, Option<String>),
+    SubmitProbe,
+    /// Where a scheduled snapshot was recorded.
+    SnapshotRecorded(Result<PathBuf, String>),
+    /// The file name and contents of the snapshot opened, or `None` when the user cancelled.
+    SnapshotOpened(Result<Option<(String, Box<snapshot::Snapshot>)>, host::Error>),
+    ToggleSection(Page, String),
+    /// The installed tools are known, see [`tools::detect`].
+    ToolsDetected,
     SubscriptionChannel,
+    ToggleColorBlindPalette,
     ToggleContextPage(ContextPage),
+    ToggleExportRawOutput,
+    ToggleFetchView(bool),
+    ToggleRedactExports(bool),
+    UndoChanges,
     UpdateConfig(Config),
+    /// The updater exited, or could not be started.
+    UpdaterClosed(Result<(), String>),
+    Usb(pages::usb::Message),
+}
+
+impl Message {
+    /// Whether the message reads or changes this system, which must not
+    /// happen while a snapshot is shown.
+    fn is_live(&self) -> bool {
+        matches!(
+            self,
+            Message::AutoRefresh
+                | Message::ChangesUndone(_)
+                | Message::DataLoaded(..)
+                | Message::DiskHealthRead(..)
+                | Message::Motherboard(_)
+                | Message::Hotplug(_)
+                | Message::IpmiRead(_)
+                | Message::Pci(pages::pci::Message::DetailsRead(..))
+                | Message::PowerProfileSet(_)
+                | Message::PowerWritten(_)
+                | Message::Refresh(_)
+                | Message::RefreshLoad
+                | Message::ReadDiskHealth(_)
+                | Message::ReadIpmiPrivileged
+                | Message::SetChargeLimit(..)
+                | Message::SetConservationMode(_)
+                | Message::SetPowerProfile(_)
+                | Message::SubmitProbe
+                | Message::ConfirmProbe
+                | Message::UndoChanges
+        )
+    }
 }
 
 impl Application for AppModel {
     type Executor = cosmic::executor::Default;
 
-    type Flags = ();
+    /// The page to open on, from `--open`.
+    type Flags = Option<Page>;
 
     type Message = Message;
 
@@ -53,91 +328,215 @@ impl Application for AppModel {
         &mut self.core
     }
 
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
-        let mut nav = nav_bar::Model::default();
-
-        nav.insert()
-            .text(fl!("distribution"))
-            .data::<Page>(Page::Distribution)
-            .icon(icon::from_name("applications-system-symbolic"))
-            .activate();
+    fn init(core: Core, open: Self::Flags) -> (Self, Task<Self::Message>) {
+        let mut app = Self::new(core, open);
+        app.history = snapshot::history();
 
-        nav.insert()
-            .text(fl!("processor"))
-            .data::<Page>(Page::Processor)
-            .icon(icon::from_name("system-run-symbolic"));
-
-        nav.insert()
-            .text(fl!("pci-devices"))
-            .data::<Page>(Page::PCIs)
-            .icon(icon::from_name("drive-harddisk-usb-symbolic"));
-
-        nav.insert()
-            .text(fl!("usb-devices"))
-            .data::<Page>(Page::USBs)
-            .icon(icon::from_name("media-removable-symbolic"));
-
-        let mut app = AppModel {
-            core,
-            context_page: ContextPage::default(),
-            nav,
-            key_binds: HashMap::new(),
-            config: cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
-                .map(|context| match Config::get_entry(&context) {
-                    Ok(config) => config,
-                    Err((_errors, config)) => config,
-                })
-                .unwrap_or_default(),
-            lscpu: None,
-            lspci: None,
-            lsusb: None,
+        let load = if !host::is_demo() && snapshot::is_due(app.config.snapshot_schedule, &app.history) {
+            // Every page is collected, then recorded into the history.
+            app.record_pending = true;
+            app.collect_all()
+        } else {
+            match app.nav.data::<Page>(app.nav.active()).copied() {
+                Some(page) => app.load_page(page),
+                None => Task::none(),
+            }
         };
-
-        let lscpu_cmd = std::process::Command::new("lscpu").output();
-        if lscpu_cmd.is_ok() {
-            app.lscpu = Some(String::from_utf8(lscpu_cmd.unwrap().stdout).unwrap());
-        } else if let Err(e) = lscpu_cmd {
-            app.lscpu = Some(fl!("error-occurred-with-msg", error = e.to_string()));
-            error!("lscpu command failed: {}", e);
-        }
-
-        let lspci_cmd = std::process::Command::new("lspci").output();
-        if lspci_cmd.is_ok() {
-            app.lspci = Some(String::from_utf8(lspci_cmd.unwrap().stdout).unwrap());
-        } else if let Err(e) = lspci_cmd {
-            app.lspci = Some(fl!("error-occurred-with-msg", error = e.to_string()));
-            error!("lspci command failed: {}", e);
-        }
-
-        let lsusb_cmd = std::process::Command::new("lsusb").output();
-        if lsusb_cmd.is_ok() {
-            app.lsusb = Some(String::from_utf8(lsusb_cmd.unwrap().stdout).unwrap());
-        } else if let Err(e) = lsusb_cmd {
-            app.lsusb = Some(fl!("error-occurred-with-msg", error = e.to_string()));
-            error!("lsusb command failed: {}", e);
-        }
-
-        let command = app.update_title();
+        let command = Task::batch([load, detect_tools(), app.update_title()]);
 
         (app, command)
     }
 
     fn header_start(&self) -> Vec<Element<Self::Message>> {
-        let menu_bar = menu::bar(vec![menu::Tree::with_children(
+        let file = menu::Tree::with_children(
+            menu::root(fl!("file")),
+            menu::items(
+                &self.key_binds,
+                vec![
+                    menu::Item::Button(fl!("copy-page"), MenuAction::CopyPage),
+                    menu::Item::Button(fl!("copy-summary"), MenuAction::CopySummary),
+                    menu::Item::Folder(
+                        fl!("export-page"),
+                        export::Format::ALL
+                            .iter()
+                            .map(|&format| menu::Item::Button(format.label(), MenuAction::Export(format)))
+                            .collect(),
+                    ),
+                    menu::Item::Folder(
+                        fl!("generate-report"),
+                        export::Format::ALL
+                            .iter()
+                            .map(|&format| menu::Item::Button(format.label(), MenuAction::GenerateReport(format)))
+                            .collect(),
+                    ),
+                    menu::Item::Divider,
+                    menu::Item::Button(fl!("save-snapshot"), MenuAction::SaveSnapshot),
+                    menu::Item::Button(fl!("open-snapshot"), MenuAction::OpenSnapshot),
+                    menu::Item::Button(fl!("compare-snapshot"), MenuAction::CompareSnapshot),
+                    menu::Item::Button(fl!("check-baseline"), MenuAction::CheckBaseline),
+                    menu::Item::Folder(
+                        fl!("snapshot-schedule"),
+                        SnapshotSchedule::ALL
+                            .iter()
+                            .map(|&schedule| {
+                                menu::Item::CheckBox(
+                                    snapshot_schedule_label(schedule),
+                                    self.config.snapshot_schedule == schedule,
+                                    MenuAction::SetSnapshotSchedule(schedule),
+                                )
+                            })
+                            .collect(),
+                    ),
+                ]
+                .into_iter()
+                .chain(
+                    self.snapshot
+                        .is_some()
+                        .then(|| menu::Item::Button(fl!("close-snapshot"), MenuAction::CloseSnapshot)),
+                )
+                .chain([
+                    menu::Item::Divider,
+                    menu::Item::Button(fl!("probe-menu"), MenuAction::SubmitProbe),
+                    menu::Item::Divider,
+                    menu::Item::CheckBox(
+                        fl!("export-raw-output"),
+                        self.config.export_raw_output,
+                        MenuAction::ToggleExportRawOutput,
+                    ),
+                ])
+                .collect(),
+            ),
+        );
+
+        let view = menu::Tree::with_children(
             menu::root(fl!("view")),
             menu::items(
                 &self.key_binds,
-                vec![menu::Item::Button(fl!("about"), MenuAction::About)],
+                vec![
+                    menu::Item::Button(fl!("page-help"), MenuAction::Help),
+                    menu::Item::Button(fl!("search-all"), MenuAction::SearchAll),
+                    menu::Item::Button(fl!("refresh-all"), MenuAction::RefreshAll),
+                    menu::Item::Folder(
+                        fl!("auto-refresh"),
+                        AUTO_REFRESH_INTERVALS
+                            .iter()
+                            .map(|&seconds| {
+                                menu::Item::CheckBox(
+                                    auto_refresh_label(seconds),
+                                    self.config.auto_refresh == seconds,
+                                    MenuAction::SetAutoRefresh(seconds),
+                                )
+                            })
+                            .collect(),
+                    ),
+                    menu::Item::Divider,
+                    menu::Item::CheckBox(
+                        fl!("color-blind-palette"),
+                        self.config.status_palette == StatusPalette::ColorBlind,
+                        MenuAction::ToggleColorBlindPalette,
+                    ),
+                    menu::Item::Divider,
+                    menu::Item::Button(fl!("snapshot-history"), MenuAction::History),
+                    menu::Item::Button(fl!("settings"), MenuAction::Settings),
+                    menu::Item::Button(fl!("diagnostics"), MenuAction::Diagnostics),
+                    menu::Item::Button(fl!("about"), MenuAction::About),
+                ],
             ),
-        )]);
+        );
+
+        let menu_bar = menu::bar(vec![file, view]);
 
         vec![menu_bar.into()]
     }
 
+    fn header_end(&self) -> Vec<Element<Self::Message>> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let undo = (!self.changes.is_empty()).then(|| {
+            let changes = self.changes.iter().map(|change| change.label.as_str()).join("\n");
+            widget::tooltip(
+                widget::button::standard(fl!("undo-changes")).on_press(Message::UndoChanges),
+                widget::text::body(changes),
+                widget::tooltip::Position::Bottom,
+            )
+            .into()
+        });
+
+        self.operations
+            .iter()
+            .map(|operation| {
+                let progress = self
+                    .collection
+                    .as_ref()
+                    .filter(|collection| collection.operation == operation.id)
+                    .map(|collection| {
+                        let done = collection.total - collection.pending.len();
+                        widget::progress_bar(0.0..=collection.total as f32, done as f32)
+                            .width(Length::Fixed(120.0))
+                            .height(Length::Fixed(6.0))
+                    });
+                row::with_capacity(3)
+                    .push(widget::text::body(operation.label.clone()))
+                    .push_maybe(progress)
+                    .push(
+                        widget::button::icon(icon::from_name("process-stop-symbolic"))
+                            .tooltip(fl!("cancel"))
+                            .on_press(Message::CancelOperation(operation.id)),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs)
+                    .into()
+            })
+            .chain(undo)
+            .chain(self.snapshot.as_ref().map(|snapshot| {
+                widget::text::caption(fl!("snapshot-shown", name = snapshot.name.as_str(), time = snapshot.taken.as_str()))
+                    .into()
+            }))
+            .chain(
+                self.notice
+                    .as_ref()
+                    .map(|notice| widget::text::caption(notice.clone()).into()),
+            )
+            .chain([widget::button::icon(icon::from_name("view-refresh-symbolic"))
+                .tooltip(fl!("refresh"))
+                .on_press_maybe(
+                    self.nav
+                        .data::<Page>(self.nav.active())
+                        .filter(|_| self.snapshot.is_none())
+                        .map(|page| Message::Refresh(Some(*page))),
+                )
+                .into()])
+            .collect()
+    }
+
     fn nav_model(&self) -> Option<&nav_bar::Model> {
         Some(&self.nav)
     }
 
+    fn dialog(&self) -> Option<Element<Self::Message>> {
+        if !self.probe_dialog {
+            return None;
+        }
+
+        let installed = tools::is_available("hw-probe");
+        let hint = (!installed).then(|| widget::text::body(fl!("error-install-hint", package = host::package("hw-probe"))));
+        let mut dialog = widget::dialog()
+            .title(fl!("probe-title"))
+            .body(fl!("probe-privacy"))
+            .primary_action(
+                widget::button::suggested(fl!("probe-submit")).on_press_maybe(installed.then_some(Message::ConfirmProbe)),
+            )
+            .secondary_action(widget::button::standard(fl!("cancel")).on_press(Message::CancelProbe))
+            .tertiary_action(
+                widget::button::link(fl!("probe-privacy-details"))
+                    .on_press(Message::LaunchUrl(hw_probe::PRIVACY_URL.to_string())),
+            );
+        if let Some(hint) = hint {
+            dialog = dialog.control(hint);
+        }
+        Some(dialog.into())
+    }
+
     fn context_drawer(&self) -> Option<Element<Self::Message>> {
         if !self.core.window.show_context {
             return None;
@@ -145,301 +544,40 @@ impl Application for AppModel {
 
         Some(match self.context_page {
             ContextPage::About => self.about(),
+            ContextPage::Help => self.help(),
+            ContextPage::Diagnostics => self.diagnostics(),
+            ContextPage::PciDevice => self.pci_device(),
+            ContextPage::UsbDevice => self.usb_device(),
+            ContextPage::Comparison => self.comparison(),
+            ContextPage::History => self.history(),
+            ContextPage::Compliance => self.compliance(),
+            ContextPage::Search => self.global_search(),
+            ContextPage::Settings => self.settings(),
         })
     }
 
     fn view(&self) -> Element<Self::Message> {
         let page = self.nav.data::<Page>(self.nav.active());
-        let is_flatpak = PathBuf::from("/.flatpak-info").exists();
-        let spacing = theme::active().cosmic().spacing;
-
-        let content: Element<Self::Message> = match page {
-            Some(Page::Distribution) => {
-                let osrelease = if is_flatpak {
-                    OsRelease::from_str(&fs::read_to_string("/run/host/os-release").unwrap())
-                        .unwrap()
-                } else {
-                    OsRelease::open().unwrap()
-                };
-
-                let mut list = list_column();
-
-                list = list.add(settings::item(
-                    fl!("pretty-name"),
-                    widget::text::body(osrelease.pretty_name().to_string()),
-                ));
-                list = list.add(settings::item(
-                    fl!("name"),
-                    widget::text::body(osrelease.name().to_string()),
-                ));
-                if let Some(version) = osrelease.version() {
-                    list = list.add(settings::item(
-                        fl!("version"),
-                        widget::text::body(version.to_string()),
-                    ));
-                }
-                if let Some(version_id) = osrelease.version_id() {
-                    list = list.add(settings::item(
-                        fl!("version-id"),
-                        widget::text::body(version_id.to_string()),
-                    ));
-                }
-                list = list.add(settings::item(
-                    fl!("id"),
-                    widget::text::body(osrelease.id().to_string()),
-                ));
-                if let Some(mut id_like) = osrelease.id_like() {
-                    list = list.add(settings::item(
-                        fl!("id-like"),
-                        widget::text::body(id_like.join(", ")),
-                    ));
-                }
-                if let Some(version_codename) = osrelease.version_codename() {
-                    // Fedora (and possibly other distros) set VERSION_CODENAME to a blank string, so check if it is empty
-                    if !version_codename.to_string().is_empty() {
-                        list = list.add(settings::item(
-                            fl!("version-codename"),
-                            widget::text::body(version_codename.to_string()),
-                        ));
-                    }
-                }
-                if let Some(build_id) = osrelease.build_id() {
-                    list = list.add(settings::item(
-                        fl!("build-id"),
-                        widget::text::body(build_id.to_string()),
-                    ));
-                }
-                if let Some(image_id) = osrelease.image_id() {
-                    list = list.add(settings::item(
-                        fl!("image-id"),
-                        widget::text::body(image_id.to_string()),
-                    ));
-                }
-                if let Some(image_version) = osrelease.image_version() {
-                    list = list.add(settings::item(
-                        fl!("image-version"),
-                        widget::text::body(image_version.to_string()),
-                    ));
-                }
-                if let Some(vendor_name) = osrelease.vendor_name() {
-                    list = list.add(settings::item(
-                        fl!("vendor-name"),
-                        widget::text::body(vendor_name.to_string()),
-                    ));
-                }
-                if let Some(ansi_color) = osrelease.ansi_color() {
-                    list = list.add(settings::item(
-                        fl!("ansi-color"),
-                        widget::text::body(ansi_color.to_string()),
-                    ));
-                }
-                if let Some(logo) = osrelease.logo() {
-                    list = list.add(settings::item(
-                        fl!("logo"),
-                        row::with_capacity(2)
-                            .push(icon::from_name(logo.to_string()))
-                            .push(widget::text::body(logo.to_string()))
-                            .align_y(Alignment::Center)
-                            .spacing(spacing.space_xxxs),
-                    ));
-                }
-                if let Some(cpe_name) = osrelease.cpe_name() {
-                    list = list.add(settings::item(
-                        fl!("cpe-name"),
-                        widget::text::body(cpe_name.to_string()),
-                    ));
-                }
-                if let Ok(Some(home_url)) = osrelease.home_url() {
-                    list = list.add(settings::item(
-                        fl!("home-url"),
-                        widget::button::link(home_url.to_string()).on_press(Message::LaunchUrl(home_url.to_string())),
-                    ));
-                }
-                if let Ok(Some(support_url)) = osrelease.support_url() {
-                    list = list.add(settings::item(
-                        fl!("vendor-url"),
-                        widget::button::link(support_url.to_string()).on_press(Message::LaunchUrl(support_url.to_string())),
-                    ));
-                }
-                if let Ok(Some(documentation_url)) = osrelease.documentation_url() {
-                    list = list.add(settings::item(
-                        fl!("doc-url"),
-                        widget::button::link(documentation_url.to_string()).on_press(Message::LaunchUrl(documentation_url.to_string())),
-                    ));
-                }
-                if let Ok(Some(support_url)) = osrelease.support_url() {
-                    list = list.add(settings::item(
-                        fl!("support-url"),
-                        widget::button::link(support_url.to_string()).on_press(Message::LaunchUrl(support_url.to_string())),
-                    ));
-                }
-                if let Ok(Some(bug_report_url)) = osrelease.bug_report_url() {
-                    list = list.add(settings::item(
-                        fl!("bug-report-url"),
-                        widget::button::link(bug_report_url.to_string()).on_press(Message::LaunchUrl(bug_report_url.to_string())),
-                    ));
-                }
-                if let Ok(Some(privacy_policy_url)) = osrelease.privacy_policy_url() {
-                    list = list.add(settings::item(
-                        fl!("privacy-policy-url"),
-                        widget::button::link(privacy_policy_url.to_string()).on_press(Message::LaunchUrl(privacy_policy_url.to_string())),
-                    ));
-                }
-                if let Some(support_end) = osrelease.support_end().unwrap_or_default().take() {
-                    list = list.add(settings::item(
-                        fl!("support-end"),
-                        widget::text::body(support_end.to_string()),
-                    ));
-                }
-                if let Some(variant) = osrelease.variant() {
-                    list = list.add(settings::item(
-                        fl!("variant"),
-                        widget::text::body(variant.to_string()),
-                    ));
-                }
-                if let Some(variant_id) = osrelease.variant_id() {
-                    list = list.add(settings::item(
-                        fl!("variant-id"),
-                        widget::text::body(variant_id.to_string()),
-                    ));
-                }
-                if let Some(default_hostname) = osrelease.default_hostname() {
-                    list = list.add(settings::item(
-                        fl!("default-hostname"),
-                        widget::text::body(default_hostname.to_string()),
-                    ));
-                }
-                if let Some(architecture) = osrelease.architecture() {
-                    list = list.add(settings::item(
-                        fl!("arch"),
-                        widget::text::body(architecture.to_string()),
-                    ));
-                }
-                if let Some(sysext_level) = osrelease.sysext_level() {
-                    list = list.add(settings::item(
-                        "SYSEXT_LEVEL",
-                        widget::text::body(sysext_level.to_string()),
-                    ));
-                }
-                if let Some(mut sysext_scope) = osrelease.sysext_scope() {
-                    list = list.add(settings::item(
-                        "SYSEXT_SCOPE",
-                        widget::text::body(sysext_scope.join(", ")),
-                    ));
-                }
-                if let Some(confext_level) = osrelease.confext_level() {
-                    list = list.add(settings::item(
-                        "CONFEXT_LEVEL",
-                        widget::text::body(confext_level.to_string()),
-                    ));
-                }
-                if let Some(mut confext_scope) = osrelease.confext_scope() {
-                    list = list.add(settings::item(
-                        "CONFEXT_SCOPE",
-                        widget::text::body(confext_scope.join(", ")),
-                    ));
-                }
-                if let Some(mut portable_prefixes) = osrelease.portable_prefixes() {
-                    list = list.add(settings::item(
-                        fl!("portable-prefixes"),
-                        widget::text::body(portable_prefixes.join(", ")),
-                    ));
-                }
-
-                widget::column::with_capacity(2)
-                    .spacing(spacing.space_xxs)
-                    .push(list)
-                    .apply(widget::container)
-                    .height(Length::Shrink)
-                    .apply(widget::scrollable)
-                    .height(Length::Fill)
-                    .into()
-            }
-            Some(Page::Processor) => {
-                let Some(lscpu) = &self.lscpu else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                };
-
-                if let Some(lscpu_str) = &self.lscpu {
-                    if lscpu_str.starts_with(fl!("error-occurred").as_str()) {
-                        return widget::text::title1(lscpu_str).into();
-                    } else {
-                        let lscpu = lscpu
-                            .lines()
-                            .map(|line: &str| {
-                                let (prefix, suffix) = line.split_once(':').unwrap();
-                                settings::item(prefix, widget::text::body(suffix)).into()
-                            })
-                            .collect::<Vec<Element<Message>>>();
-
-                        let mut section = list_column();
-                        for item in lscpu {
-                            section = section.add(item);
-                        }
-                        return section.apply(widget::scrollable).into()
-                    }
-                } else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                }
-            }
-            Some(Page::PCIs) => {
-                let Some(lspci) = &self.lspci else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                };
-
-                if let Some(lspci_str) = &self.lspci {
-                    if lspci_str.starts_with(fl!("error-occurred").as_str()) {
-                        return widget::text::title1(lspci_str).into();
-                    } else {
-                        let lspci = lspci
-                            .lines()
-                            .map(|line: &str| {
-                                let (prefix, suffix) = line.split_once(": ").unwrap();
-                                settings::item(suffix, widget::text::body(prefix)).into()
-                            })
-                            .collect::<Vec<Element<Message>>>();
-
-                        let mut section = list_column();
-                        for item in lspci {
-                            section = section.add(item);
-                        }
-                        return section.apply(widget::scrollable).into()
-                    }
-                } else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                }
-            }
-            Some(Page::USBs) => {
-                let Some(lsusb) = &self.lsusb else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                };
-
-                if let Some(lsusb_str) = &self.lsusb {
-                    if lsusb_str.starts_with(fl!("error-occurred").as_str()) {
-                        return widget::text::title1(lsusb_str).into();
-                    } else {
-                        let lsusb = lsusb
-                            .lines()
-                            .map(|line: &str| {
-                                let (prefix, suffix) = line.split_once(": ").unwrap();
-                                settings::item(suffix, widget::text::body(prefix)).into()
-                            })
-                            .collect::<Vec<Element<Message>>>();
 
-                        let mut section = list_column();
-                        for item in lsusb {
-                            section = section.add(item);
-                        }
-                        return section.apply(widget::scrollable).into()
-                    }
-                } else {
-                    return widget::text::title1(fl!("error-occurred")).into();
-                }
-            }
+        let content: Element<Self::Message> = match page.map(|page| pages::provider(*page)) {
+            Some(provider) => match self.sections.result(provider.page()) {
+                Some(Err(e)) => failed_page(provider.page(), e),
+                _ if !self.search.trim().is_empty() => self.search_results(),
+                Some(Ok(section)) => provider.view(self, section.as_ref()),
+                None => loading_page(),
+            },
             None => widget::text::title1(fl!("no-page")).into(),
         };
 
-        widget::container(content)
+        let spacing = theme::active().cosmic().spacing;
+        widget::column::with_capacity(2)
+            .push(
+                widget::search_input(fl!("search-page"), &self.search)
+                    .on_input(Message::Search)
+                    .on_clear(Message::Search(String::new())),
+            )
+            .push(content)
+            .spacing(spacing.space_s)
             .width(Length::Fill)
             .height(Length::Fill)
             .into()
@@ -448,7 +586,26 @@ impl Application for AppModel {
     fn subscription(&self) -> Subscription<Self::Message> {
         struct MySubscription;
 
+        // Uptime and load only need refreshing while the overview shows them.
+        let load = (self.nav.data::<Page>(self.nav.active()) == Some(&Page::Overview))
+            .then(|| cosmic::iced::time::every(LOAD_INTERVAL).map(|_| Message::RefreshLoad));
+
+        // Only pages whose data changes are refreshed, never static ones that
+        // would run tools such as dmidecode for nothing.
+        let auto_refresh = (self.config.auto_refresh > 0
+            && self
+                .nav
+                .data::<Page>(self.nav.active())
+                .is_some_and(|page| pages::provider(*page).is_dynamic()))
+        .then(|| {
+            cosmic::iced::time::every(Duration::from_secs(self.config.auto_refresh)).map(|_| Message::AutoRefresh)
+        });
+
         Subscription::batch(vec![
+            load.unwrap_or_else(Subscription::none),
+            auto_refresh.unwrap_or_else(Subscription::none),
+            keyboard::on_key_press(|key, modifiers| Some(Message::Key(modifiers, key))),
+            Subscription::run(hotplug::events).map(Message::Hotplug),
             Subscription::run_with_id(
                 std::any::TypeId::of::<MySubscription>(),
                 stream::channel(4, move |mut channel| async move {
@@ -464,6 +621,11 @@ impl Application for AppModel {
     }
 
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
+        // A snapshot is read-only: nothing reads or changes this system while it is shown.
+        if self.snapshot.is_some() && message.is_live() {
+            return Task::none();
+        }
+
         match message {
             Message::LaunchUrl(url) => match open::that_detached(&url) {
                 Ok(()) => {}
@@ -472,113 +634,1995 @@ impl Application for AppModel {
                 }
             }
 
-            Message::SubscriptionChannel => {
-                // For example purposes only.
+            Message::CancelOperation(id) => {
+                if let Some(index) = self.operations.iter().position(|operation| operation.id == id) {
+                    self.operations.remove(index).handle.abort();
+                }
+                if self.collection.as_ref().is_some_and(|collection| collection.operation == id) {
+                    host::cancel_running();
+                }
+                return self.finish_collection(id);
             }
 
-            Message::ToggleContextPage(context_page) => {
-                if self.context_page == context_page {
-                    self.core.window.show_context = !self.core.window.show_context;
+            Message::CancelTools => host::cancel_running(),
+
+            Message::SaveSnapshot => {
+                if self.snapshot.is_some() {
+                    return self.save_snapshot();
+                }
+                // Every page is collected afresh, then saved when the collection ends.
+                self.snapshot_pending = true;
+                return self.refresh(None);
+            }
+
+            Message::OpenSnapshot => {
+                return Task::perform(snapshot::open(), |result| {
+                    cosmic::app::Message::App(Message::SnapshotOpened(result))
+                });
+            }
+
+            Message::SnapshotOpened(result) => match result {
+                Ok(Some((name, snapshot))) => {
+                    self.open_snapshot(name, *snapshot);
+                    return self.update_title();
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!("failed to open the snapshot: {}", e);
+                    return self.show_notice(fl!("snapshot-open-failed", error = error_explanation(&e)));
+                }
+            },
+
+            Message::SubmitProbe => self.probe_dialog = true,
+
+            Message::CancelProbe => self.probe_dialog = false,
+
+            Message::ConfirmProbe => {
+                self.probe_dialog = false;
+                let task = Task::perform(
+                    async {
+                        tokio::task::spawn_blocking(hw_probe::submit)
+                            .await
+                            .map_err(|e| e.to_string())?
+                            .map_err(|e| e.to_string())
+                    },
+                    |result| cosmic::app::Message::App(Message::ProbeSubmitted(result)),
+                );
+                return self.start_operation(fl!("operation-probe"), task);
+            }
+
+            Message::ProbeSubmitted(result) => match result {
+                Ok(url) => {
+                    info!("submitted a hardware probe: {}", url);
+                    if let Err(e) = open::that_detached(&url) {
+                        warn!("failed to open {:?}: {}", url, e);
+                    }
+                    return self.show_notice(fl!("probe-submitted", url = url));
+                }
+                Err(e) => {
+                    error!("failed to submit a hardware probe: {}", e);
+                    return self.show_notice(fl!("probe-failed", error = e));
+                }
+            },
+
+            Message::OpenHistory(path) => {
+                return Task::perform(snapshot::read(path), |result| {
+                    cosmic::app::Message::App(Message::SnapshotOpened(result.map(Some)))
+                });
+            }
+
+            Message::CompareHistory(path) => {
+                return Task::perform(snapshot::read(path), |result| {
+                    cosmic::app::Message::App(Message::ComparisonOpened(result.map(Some)))
+                });
+            }
+
+            Message::SnapshotRecorded(result) => match result {
+                Ok(path) => {
+                    info!("recorded a snapshot to {}", path.display());
+                    self.history = snapshot::history();
+                }
+                Err(e) => error!("failed to record a snapshot: {}", e),
+            },
+
+            Message::SetSnapshotSchedule(schedule) => {
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_snapshot_schedule(handler, schedule) {
+                        error!("failed to save the snapshot schedule: {}", e);
+                    }
                 } else {
-                    self.context_page = context_page;
-                    self.core.window.show_context = true;
+                    self.config.snapshot_schedule = schedule;
                 }
+            }
 
-                self.set_context_title(context_page.title());
+            Message::CheckBaseline => {
+                return Task::perform(baseline::open(), |result| {
+                    cosmic::app::Message::App(Message::BaselineOpened(result))
+                });
             }
 
-            Message::UpdateConfig(config) => {
-                self.config = config;
+            Message::BaselineOpened(result) => match result {
+                Ok(Some(baseline)) => {
+                    if self.snapshot.is_some() {
+                        self.show_compliance(&baseline);
+                        return Task::none();
+                    }
+                    // Every page is collected afresh, then checked when the collection ends.
+                    self.baseline_pending = Some(baseline);
+                    return self.refresh(None);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!("failed to open the baseline: {}", e);
+                    return self.show_notice(fl!("baseline-open-failed", error = error_explanation(&e)));
+                }
+            },
+
+            Message::CompareSnapshot => {
+                return Task::perform(snapshot::open(), |result| {
+                    cosmic::app::Message::App(Message::ComparisonOpened(result))
+                });
             }
-        }
-        Task::none()
-    }
 
-    fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
-        self.nav.activate(id);
-        self.update_title()
-    }
-}
+            Message::ComparisonOpened(result) => match result {
+                Ok(Some((name, snapshot))) => {
+                    if self.snapshot.is_some() {
+                        self.show_comparison(name, &snapshot);
+                        return Task::none();
+                    }
+                    // Every page is collected afresh, then compared when the collection ends.
+                    self.comparison_pending = Some((name, snapshot));
+                    return self.refresh(None);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!("failed to open the snapshot: {}", e);
+                    return self.show_notice(fl!("snapshot-open-failed", error = error_explanation(&e)));
+                }
+            },
 
-impl AppModel {
-    /// The about page for this app.
-    pub fn about(&self) -> Element<Message> {
-        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
-        let icon = widget::svg(widget::svg::Handle::from_memory(APP_ICON));
-        let title = widget::text::title3(fl!("app-title"));
-        let hash = env!("VERGEN_GIT_SHA");
-        let short_hash: String = hash.chars().take(7).collect();
-        let date = env!("VERGEN_GIT_COMMIT_DATE");
+            Message::CloseSnapshot => return self.close_snapshot(),
 
-        let repo = widget::button::link(REPOSITORY)
-            .on_press(Message::LaunchUrl(REPOSITORY.to_string()))
-            .padding(0);
+            Message::CopyPage => {
+                let Some(page) = self.nav.data::<Page>(self.nav.active()).copied() else {
+                    return Task::none();
+                };
+                let text = export::page_text(&self.shared_data(page));
+                return Task::batch([clipboard::write(text), self.show_notice(fl!("copied"))]);
+            }
 
-        let commit = widget::button::link(fl!(
-                "git-description",
-                hash = short_hash.as_str(),
-                date = date
-            ))
-            .on_press(Message::LaunchUrl(format!("{REPOSITORY}/commits/{hash}")))
-            .padding(0);
+            Message::CopySummary => {
+                return Task::batch([clipboard::write(self.summary()), self.show_notice(fl!("copied"))]);
+            }
 
-        widget::column()
-            .push(icon)
-            .push(title)
-            .push(repo)
-            .push(commit)
-            .align_x(alignment::Horizontal::Center)
-            .spacing(space_xxs)
-            .into()
-    }
+            Message::CopyValue(value) => {
+                return Task::batch([clipboard::write(value), self.show_notice(fl!("copied"))]);
+            }
 
-    /// Updates the header and window titles.
-    pub fn update_title(&mut self) -> Task<Message> {
-        let mut window_title = fl!("app-title");
+            Message::Search(text) => {
+                self.search = text;
+                self.search_match = None;
+                self.search_page();
+            }
 
-        if let Some(page) = self.nav.text(self.nav.active()) {
-            window_title.push_str(" — ");
-            window_title.push_str(page);
-        }
+            Message::OpenGlobalSearch => {
+                self.context_page = ContextPage::Search;
+                self.core.window.show_context = true;
+                self.set_context_title(ContextPage::Search.title());
+                self.search_all();
+                // Pages not visited yet are collected so that they can be searched too.
+                let unloaded = pages::PAGES
+                    .iter()
+                    .map(|provider| provider.page())
+                    .filter(|&page| !self.sections.contains(page) && !self.loading.contains(&page))
+                    .filter(|&page| self.is_available(page) && !self.config.is_hidden(page))
+                    .collect::<Vec<_>>();
+                let focus = widget::text_input::focus(GLOBAL_SEARCH_INPUT.clone());
+                if self.snapshot.is_some() || unloaded.is_empty() {
+                    return focus;
+                }
+                return Task::batch([focus, self.collect(unloaded)]);
+            }
 
-        self.set_window_title(window_title)
-    }
-}
+            Message::SearchAll(text) => {
+                self.global_search = text;
+                self.search_all();
+            }
 
-/// The page to display in the application.
-pub enum Page {
-    Distribution,
-    Processor,
-    PCIs,
-    USBs,
-}
+            Message::ShowMatch(page, label) => {
+                // The page opens filtered by the same text, leaving only the
+                // matching rows, scrolled to the one chosen.
+                let Some(id) = self.nav.iter().find(|&id| self.nav.data::<Page>(id) == Some(&page)) else {
+                    return Task::none();
+                };
+                let task = self.on_nav_select(id);
+                self.search = self.global_search.clone();
+                self.search_page();
+                let matches = &self.search_matches;
+                let position = label
+                    .as_ref()
+                    .and_then(|label| matches.iter().position(|(other, _, _)| other == label));
+                let y = match position {
+                    Some(index) if matches.len() > 1 => index as f32 / (matches.len() - 1) as f32,
+                    _ => 0.0,
+                };
+                self.search_match = label;
+                return task.chain(scrollable::snap_to(PAGE_SCROLLABLE.clone(), RelativeOffset { x: 0.0, y }));
+            }
 
-/// The context page to display in the context drawer.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-pub enum ContextPage {
-    #[default]
-    About,
-}
+            Message::Key(modifiers, key) => {
+                if let Some(action) = self
+                    .key_binds
+                    .iter()
+                    .find_map(|(key_bind, action)| key_bind.matches(modifiers, &key).then_some(*action))
+                {
+                    return self.update(action.message());
+                }
+                // Refreshing the page on screen has no menu entry to carry its binding.
+                let refresh = match &key {
+                    Key::Named(Named::F5) => modifiers.is_empty(),
+                    Key::Character(c) => c.as_str() == "r" && modifiers == Modifiers::CTRL,
+                    _ => false,
+                };
+                // Dispatched as a message so a snapshot on screen is not replaced.
+                if let Some(page) = self.nav.data::<Page>(self.nav.active()).copied().filter(|_| refresh) {
+                    return self.update(Message::Refresh(Some(page)));
+                }
+            }
 
-impl ContextPage {
-    fn title(&self) -> String {
-        match self {
-            Self::About => fl!("about"),
-        }
-    }
-}
+            Message::Refresh(page) => {
+                return self.refresh(page);
+            }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum MenuAction {
-    About,
-}
+            Message::Hotplug(event) => {
+                let Some(page) = event.page() else {
+                    return Task::none();
+                };
+                let name = pages::provider(page).name();
+                let clear = self.show_notice(match event.action {
+                    hotplug::Action::Added => fl!("hotplug-added", page = name),
+                    hotplug::Action::Removed => fl!("hotplug-removed", page = name),
+                });
 
-impl menu::action::MenuAction for MenuAction {
-    type Message = Message;
+                // Pages not collected yet read the devices present when they are first shown.
+                if !self.sections.contains(page) {
+                    return clear;
+                }
+                return Task::batch([self.refresh(Some(page)), clear]);
+            }
 
-    fn message(&self) -> Self::Message {
-        match self {
-            MenuAction::About => Message::ToggleContextPage(ContextPage::About),
-        }
+            Message::NoticeExpired(sequence) => {
+                if sequence == self.notice_sequence {
+                    self.notice = None;
+                }
+            }
+
+            Message::AutoRefresh => {
+                let page = self.nav.data::<Page>(self.nav.active()).copied();
+                if page.is_some_and(|page| pages::provider(page).is_dynamic()) {
+                    return self.refresh(page);
+                }
+            }
+
+            Message::SetAutoRefresh(seconds) => {
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_auto_refresh(handler, seconds) {
+                        error!("failed to save auto refresh interval: {}", e);
+                    }
+                } else {
+                    self.config.auto_refresh = seconds;
+                }
+            }
+
+            Message::DataLoaded(page, result) => {
+                if let Some(collection) = &mut self.collection {
+                    collection.pending.remove(&page);
+                }
+                self.data_loaded(page, result);
+                self.search_page();
+                // The global search covers the page as soon as its data arrives.
+                if !self.global_search.trim().is_empty() {
+                    self.search_all();
+                }
+            }
+
+            Message::DiskHealthRead(disk, result) => {
+                if let Err(e) = &result {
+                    error!("failed to read the health of {}: {}", disk, e);
+                }
+                if let Some(section) = self.sections.get_mut::<pages::filesystems::Provider>() {
+                    section.health.insert(disk, result);
+                }
+                self.search_page();
+            }
+
+            Message::IpmiRead(result) => {
+                if let Err(e) = &result {
+                    error!("failed to read the BMC: {}", e);
+                }
+                self.sections.set::<pages::ipmi::Provider>(result);
+                self.search_page();
+            }
+
+            Message::ToggleSection(page, id) => {
+                let key = (page, id);
+                if !self.expanded_sections.remove(&key) {
+                    self.expanded_sections.insert(key);
+                }
+            }
+
+            Message::RefreshLoad => {
+                if let Some(section) = self.sections.get_mut::<pages::overview::Provider>() {
+                    section.load = load::collect();
+                }
+                self.search_page();
+            }
+
+            Message::ReadDiskHealth(disk) => {
+                let task = Task::perform(
+                    async move {
+                        let name = disk.clone();
+                        let result = tokio::task::spawn_blocking(move || smart::collect(&name))
+                            .await
+                            .map_err(|e| host::Error::Failed(e.to_string()))
+                            .and_then(|result| result);
+                        (disk, result)
+                    },
+                    |(disk, result)| cosmic::app::Message::App(Message::DiskHealthRead(disk, result)),
+                );
+                return self.start_operation(fl!("operation-disk-health"), task);
+            }
+
+            Message::ReadIpmiPrivileged => {
+                let task = Task::perform(
+                    async {
+                        tokio::task::spawn_blocking(|| ipmi::collect(true))
+                            .await
+                            .map_err(|e| host::Error::Failed(e.to_string()))?
+                    },
+                    |result| cosmic::app::Message::App(Message::IpmiRead(result)),
+                );
+                return self.start_operation(fl!("operation-bmc"), task);
+            }
+
+            Message::OperationFinished(id) => {
+                self.operations.retain(|operation| operation.id != id);
+                return self.finish_collection(id);
+            }
+
+            Message::LaunchUpdater => {
+                // Runs on the host from inside a Flatpak, and is waited on so
+                // that it does not linger as a zombie once closed.
+                return Task::perform(
+                    async {
+                        tokio::task::spawn_blocking(|| host::command(UPDATER).status())
+                            .await
+                            .map_err(|e| e.to_string())?
+                            .map(drop)
+                            .map_err(|e| e.to_string())
+                    },
+                    |result| cosmic::app::Message::App(Message::UpdaterClosed(result)),
+                );
+            }
+
+            Message::UpdaterClosed(result) => {
+                if let Err(e) = result {
+                    warn!("failed to launch {}: {}", UPDATER, e);
+                    return self.show_notice(fl!("updater-failed", error = e));
+                }
+            }
+
+            Message::PageScrolled(offset) => {
+                if let Some(page) = self.nav.data::<Page>(self.nav.active()) {
+                    self.scroll_offsets.insert(*page, offset);
+                }
+            }
+
+            Message::PowerWritten(result) => {
+                if let Err(e) = result {
+                    error!("failed to change battery setting: {}", e);
+                }
+                return self.refresh(Some(Page::Power));
+            }
+
+            Message::SetChargeLimit(battery, limit) => {
+                let setting = Setting::ChargeLimit(battery.clone());
+                self.record_change(fl!("change-charge-limit", battery = battery), &setting);
+                return self.start_operation(
+                    fl!("operation-power"),
+                    write_settings(vec![(setting, limit.to_string())], Message::PowerWritten),
+                );
+            }
+
+            Message::SetPowerProfile(profile) => {
+                self.record_change(fl!("change-power-profile"), &Setting::PowerProfile);
+                return self.start_operation(
+                    fl!("operation-power-profile"),
+                    write_settings(vec![(Setting::PowerProfile, profile)], Message::PowerProfileSet),
+                );
+            }
+
+            Message::PowerProfileSet(result) => {
+                if let Err(e) = result {
+                    error!("failed to change power profile: {}", e);
+                }
+                // Read back in the background, as the daemon may refuse the profile.
+                return self.refresh(Some(Page::Power));
+            }
+
+            Message::SetConservationMode(enabled) => {
+                let mode = self
+                    .sections
+                    .get::<pages::power::Provider>()
+                    .and_then(|section| section.power.conservation_mode.as_ref());
+                if let Some(mode) = mode {
+                    let setting = Setting::ConservationMode(mode.path.clone());
+                    let value = if enabled { "1" } else { "0" };
+                    self.record_change(fl!("change-conservation-mode"), &setting);
+                    return self.start_operation(
+                        fl!("operation-power"),
+                        write_settings(vec![(setting, value.to_string())], Message::PowerWritten),
+                    );
+                }
+            }
+
+            Message::UndoChanges => {
+                // Restore in reverse so that dependent settings unwind in order.
+                let writes = self
+                    .changes
+                    .iter()
+                    .rev()
+                    .map(|change| (change.setting.clone(), change.original.clone()))
+                    .collect();
+                return self.start_operation(
+                    fl!("operation-undo"),
+                    write_settings(writes, Message::ChangesUndone),
+                );
+            }
+
+            Message::ChangesUndone(result) => {
+                match result {
+                    Ok(()) => self.changes.clear(),
+                    Err(e) => error!("failed to undo changes: {}", e),
+                }
+                return self.refresh(Some(Page::Power));
+            }
+
+            Message::Motherboard(message) => return self.update_motherboard(message),
+
+            Message::Pci(message) => return self.update_pci(message),
+
+            Message::Processor(message) => return self.update_processor(message),
+
+            Message::Usb(message) => return self.update_usb(message),
+
+            Message::ToolsDetected => {
+                // Pages and the diagnostics draw what the tools found allow on their next view.
+            }
+
+            Message::SubscriptionChannel => {
+                // For example purposes only.
+            }
+
+            Message::ToggleColorBlindPalette => {
+                let palette = match self.config.status_palette {
+                    StatusPalette::Standard => StatusPalette::ColorBlind,
+                    StatusPalette::ColorBlind => StatusPalette::Standard,
+                };
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_status_palette(handler, palette) {
+                        error!("failed to save status palette: {}", e);
+                    }
+                } else {
+                    self.config.status_palette = palette;
+                }
+            }
+
+            Message::ToggleExportRawOutput => {
+                let enabled = !self.config.export_raw_output;
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_export_raw_output(handler, enabled) {
+                        error!("failed to save export setting: {}", e);
+                    }
+                } else {
+                    self.config.export_raw_output = enabled;
+                }
+            }
+
+            Message::Export(format) => {
+                let Some(page) = self.nav.data::<Page>(self.nav.active()).copied() else {
+                    return Task::none();
+                };
+                let raw_output = self.raw_output().then(|| export::raw_output(page));
+                let contents = export::document(format, &[self.shared_data(page)], raw_output);
+                return Task::perform(
+                    export::save(fl!("export-page"), export::file_name(page, format), contents),
+                    |result| cosmic::app::Message::App(Message::Exported(result)),
+                );
+            }
+
+            Message::GenerateReport(format) => {
+                if self.snapshot.is_some() {
+                    return self.save_report(format);
+                }
+                // Every page is collected afresh, then saved when the collection ends.
+                self.report_pending = Some(format);
+                return self.refresh(None);
+            }
+
+            Message::Exported(result) => match result {
+                Ok(Some(path)) => {
+                    return self.show_notice(fl!("exported", path = path.display().to_string()));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!("failed to export the page: {}", e);
+                    return self.show_notice(fl!("export-failed", error = e));
+                }
+            },
+
+            Message::ToggleContextPage(context_page) => {
+                if self.context_page == context_page {
+                    self.core.window.show_context = !self.core.window.show_context;
+                } else {
+                    self.context_page = context_page;
+                    self.core.window.show_context = true;
+                }
+                if context_page == ContextPage::History {
+                    self.history = snapshot::history();
+                }
+
+                self.set_context_title(context_page.title());
+            }
+
+            Message::ToggleFetchView(enabled) => {
+                self.fetch_view = enabled;
+            }
+
+            Message::UpdateConfig(config) => {
+                host::set_timeout(Duration::from_secs(config.command_timeout));
+                storage::set_units(config.byte_units);
+                let nav_changed =
+                    config.hidden_pages != self.config.hidden_pages || config.page_order != self.config.page_order;
+                self.config = config;
+                if nav_changed {
+                    return self.rebuild_nav();
+                }
+            }
+
+            Message::SetCommandTimeout(seconds) => {
+                host::set_timeout(Duration::from_secs(seconds));
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_command_timeout(handler, seconds) {
+                        error!("failed to save the command timeout: {}", e);
+                    }
+                } else {
+                    self.config.command_timeout = seconds;
+                }
+            }
+
+            Message::SetByteUnits(units) => {
+                storage::set_units(units);
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_byte_units(handler, units) {
+                        error!("failed to save the byte units: {}", e);
+                    }
+                } else {
+                    self.config.byte_units = units;
+                }
+            }
+
+            Message::ToggleRedactExports(enabled) => {
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_redact_exports(handler, enabled) {
+                        error!("failed to save the redaction setting: {}", e);
+                    }
+                } else {
+                    self.config.redact_exports = enabled;
+                }
+            }
+
+            Message::SetPageVisible(page, visible) => {
+                let mut hidden = self.config.hidden_pages.clone();
+                hidden.retain(|id| Page::from_id(id) != Some(page));
+                if !visible {
+                    hidden.push(page.id().to_string());
+                }
+                if let Some(handler) = &self.config_handler {
+                    if let Err(e) = self.config.set_hidden_pages(handler, hidden) {
+                        error!("failed to save the hidden pages: {}", e);
+                    }
+                } else {
+                    self.config.hidden_pages = hidden;
+                }
+                return self.rebuild_nav();
+            }
+
+            Message::MovePage(page, up) => {
+                // Swaps with the nearest page listed for this system, skipping
+                // those that only apply to others.
+                let mut order = self.config.ordered_pages();
+                let Some(index) = order.iter().position(|&other| other == page) else {
+                    return Task::none();
+                };
+                let neighbour = if up {
+                    order[..index].iter().rposition(|&other| self.is_available(other))
+                } else {
+                    order[index + 1..]
+                        .iter()
+                        .position(|&other| self.is_available(other))
+                        .map(|offset| index + 1 + offset)
+                };
+                let Some(neighbour) = neighbour else {
+                    return Task::none();
+                };
+                order.swap(index, neighbour);
+                return self.set_page_order(order.into_iter().map(|page| page.id().to_string()).collect());
+            }
+
+            Message::ResetPageOrder => return self.set_page_order(Vec::new()),
+        }
+        Task::none()
+    }
+
+    fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
+        self.nav.activate(id);
+        self.search.clear();
+        self.search_matches.clear();
+        self.search_match = None;
+
+        let page = self.nav.data::<Page>(id).copied();
+        let offset = page
+            .and_then(|page| self.scroll_offsets.get(&page))
+            .copied()
+            .unwrap_or_default();
+        let load = match page {
+            Some(page) => self.load_page(page),
+            None => Task::none(),
+        };
+
+        Task::batch([
+            load,
+            self.update_title(),
+            scrollable::scroll_to(PAGE_SCROLLABLE.clone(), offset),
+        ])
+    }
+}
+
+impl AppModel {
+    /// The about page for this app.
+    pub fn about(&self) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+        let icon = widget::svg(widget::svg::Handle::from_memory(APP_ICON));
+        let title = widget::text::title3(fl!("app-title"));
+        let hash = env!("VERGEN_GIT_SHA");
+        let short_hash: String = hash.chars().take(7).collect();
+        let date = env!("VERGEN_GIT_COMMIT_DATE");
+
+        let repo = widget::button::link(REPOSITORY)
+            .on_press(Message::LaunchUrl(REPOSITORY.to_string()))
+            .padding(0);
+
+        let commit = widget::button::link(fl!(
+                "git-description",
+                hash = short_hash.as_str(),
+                date = date
+            ))
+            .on_press(Message::LaunchUrl(format!("{REPOSITORY}/commits/{hash}")))
+            .padding(0);
+
+        widget::column()
+            .push(icon)
+            .push(title)
+            .push(repo)
+            .push(commit)
+            .align_x(alignment::Horizontal::Center)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    /// Explains the data shown on the active page.
+    pub fn help(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Some(page) = self.nav.data::<Page>(self.nav.active()) else {
+            return widget::text::body(fl!("no-page")).into();
+        };
+
+        let provider = pages::provider(*page);
+        let (source, fields, problems) = provider.help();
+
+        widget::column::with_capacity(7)
+            .push(widget::text::heading(fl!("help-source")))
+            .push(widget::text::body(source))
+            .push_maybe(provider.requires_privilege().then(|| widget::text::caption(fl!("help-privileged"))))
+            .push(widget::text::heading(fl!("help-fields")))
+            .push(widget::text::body(fields))
+            .push(widget::text::heading(fl!("help-problems")))
+            .push(widget::text::body(problems))
+            .spacing(spacing.space_xs)
+            .into()
+    }
+
+    /// Lists which external tools are installed and the pages missing out without them.
+    pub fn diagnostics(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let environment = settings::section().title(fl!("environment")).add(value_item(
+            fl!("flatpak-sandbox"),
+            if host::is_sandboxed() { fl!("yes") } else { fl!("no") },
+            "/.flatpak-info",
+        ));
+
+        let mut tools_section = settings::section().title(fl!("tools"));
+        for program in tools::all() {
+            let names = tools::used_by(program).map(|page| pages::provider(page).name()).join(", ");
+            let (status, severity) = if !tools::is_detected() {
+                (fl!("tool-detecting"), Severity::Info)
+            } else if tools::is_available(program) {
+                (fl!("installed"), Severity::Good)
+            } else {
+                (fl!("tool-package", package = host::package(program)), Severity::Warning)
+            };
+            tools_section = tools_section.add(
+                settings::item::builder(program).description(names).control(
+                    row::with_capacity(2)
+                        .push(widget::text::body(status))
+                        .push(self.status_indicator(severity))
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs),
+                ),
+            );
+        }
+
+        widget::column::with_capacity(2)
+            .push(environment)
+            .push(tools_section)
+            .spacing(spacing.space_m)
+            .into()
+    }
+
+    /// The changes since a snapshot: devices added and removed, then changed values.
+    pub fn comparison(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Some(comparison) = &self.comparison else {
+            return widget::text::body(fl!("diff-none")).into();
+        };
+
+        let mut column = widget::column::with_capacity(4).spacing(spacing.space_m).push(widget::text::caption(fl!(
+            "diff-since",
+            name = comparison.name.as_str(),
+            time = comparison.taken.as_str()
+        )));
+        if comparison.changes.is_empty() {
+            return column.push(widget::text::body(fl!("diff-none"))).into();
+        }
+
+        let mut added = settings::section().title(fl!("diff-added"));
+        let mut removed = settings::section().title(fl!("diff-removed"));
+        let mut changed = settings::section().title(fl!("diff-changed"));
+        let (mut any_added, mut any_removed, mut any_changed) = (false, false, false);
+        for change in &comparison.changes {
+            let (category, text, severity) = match change {
+                diff::Change::Added(category, device) => (category, device.clone(), Severity::Good),
+                diff::Change::Removed(category, device) => (category, device.clone(), Severity::Warning),
+                diff::Change::Changed(label, old, new) => (label, format!("{old} → {new}"), Severity::Info),
+            };
+            let item = settings::item(
+                category.as_str(),
+                row::with_capacity(2)
+                    .push(self.status_indicator(severity))
+                    .push(widget::text::body(text))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs),
+            );
+            match change {
+                diff::Change::Added(..) => {
+                    added = added.add(item);
+                    any_added = true;
+                }
+                diff::Change::Removed(..) => {
+                    removed = removed.add(item);
+                    any_removed = true;
+                }
+                diff::Change::Changed(..) => {
+                    changed = changed.add(item);
+                    any_changed = true;
+                }
+            }
+        }
+
+        column = column
+            .push_maybe(any_added.then_some(added))
+            .push_maybe(any_removed.then_some(removed))
+            .push_maybe(any_changed.then_some(changed));
+        column.into()
+    }
+
+    /// The rows of the page shown matching the search, in place of the page.
+    fn search_results(&self) -> Element<Message> {
+        if self.search_matches.is_empty() {
+            return widget::text::body(fl!("search-no-results"))
+                .apply(widget::container)
+                .center(Length::Fill)
+                .into();
+        }
+        self.search_matches
+            .iter()
+            .fold(settings::section(), |section, (label, value, source)| {
+                let item = value_item(label.as_str(), value.clone(), *source);
+                if self.search_match.as_ref() == Some(label) {
+                    section.add(widget::container(item).class(theme::Container::Primary))
+                } else {
+                    section.add(item)
+                }
+            })
+            .apply(page_scrollable)
+    }
+
+    /// The app's options, saved in its config as soon as they change.
+    pub fn settings(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let general = settings::section()
+            .title(fl!("settings-general"))
+            .add(settings::item(
+                fl!("auto-refresh"),
+                widget::dropdown(
+                    AUTO_REFRESH_LABELS.as_slice(),
+                    AUTO_REFRESH_INTERVALS.iter().position(|&seconds| seconds == self.config.auto_refresh),
+                    |index| Message::SetAutoRefresh(AUTO_REFRESH_INTERVALS[index]),
+                ),
+            ))
+            .add(
+                settings::item::builder(fl!("command-timeout"))
+                    .description(fl!("command-timeout-description"))
+                    .control(widget::dropdown(
+                        COMMAND_TIMEOUT_LABELS.as_slice(),
+                        COMMAND_TIMEOUTS.iter().position(|&seconds| seconds == self.config.command_timeout),
+                        |index| Message::SetCommandTimeout(COMMAND_TIMEOUTS[index]),
+                    )),
+            )
+            .add(settings::item(
+                fl!("byte-units"),
+                widget::dropdown(
+                    BYTE_UNIT_LABELS.as_slice(),
+                    ByteUnits::ALL.iter().position(|&units| units == self.config.byte_units),
+                    |index| Message::SetByteUnits(ByteUnits::ALL[index]),
+                ),
+            ))
+            .add(settings::item::builder(fl!("color-blind-palette")).toggler(
+                self.config.status_palette == StatusPalette::ColorBlind,
+                |_| Message::ToggleColorBlindPalette,
+            ))
+            .add(settings::item(
+                fl!("snapshot-schedule"),
+                widget::dropdown(
+                    SNAPSHOT_SCHEDULE_LABELS.as_slice(),
+                    SnapshotSchedule::ALL
+                        .iter()
+                        .position(|&schedule| schedule == self.config.snapshot_schedule),
+                    |index| Message::SetSnapshotSchedule(SnapshotSchedule::ALL[index]),
+                ),
+            ));
+
+        let privacy = settings::section()
+            .title(fl!("settings-privacy"))
+            .add(
+                settings::item::builder(fl!("redact-exports"))
+                    .description(fl!("redact-exports-description"))
+                    .toggler(self.config.redact_exports, Message::ToggleRedactExports),
+            )
+            .add(
+                settings::item::builder(fl!("export-raw-output"))
+                    .description(fl!("export-raw-output-description"))
+                    .control(
+                        widget::toggler(self.config.export_raw_output && !self.config.redact_exports).on_toggle_maybe(
+                            (!self.config.redact_exports).then_some(|_| Message::ToggleExportRawOutput),
+                        ),
+                    ),
+            );
+
+        // The page shown last cannot be hidden, leaving an empty window.
+        let available = self
+            .config
+            .ordered_pages()
+            .into_iter()
+            .filter(|&page| self.is_available(page))
+            .map(pages::provider)
+            .collect::<Vec<_>>();
+        let shown = available.iter().filter(|provider| !self.config.is_hidden(provider.page())).count();
+        let mut visible = settings::section().title(fl!("settings-pages"));
+        for (index, provider) in available.iter().enumerate() {
+            let page = provider.page();
+            let hidden = self.config.is_hidden(page);
+            let on_toggle = (hidden || shown > 1).then_some(move |visible| Message::SetPageVisible(page, visible));
+            visible = visible.add(settings::item(
+                provider.name(),
+                row::with_capacity(3)
+                    .push(
+                        widget::button::icon(icon::from_name("go-up-symbolic"))
+                            .tooltip(fl!("move-page-up"))
+                            .on_press_maybe((index > 0).then_some(Message::MovePage(page, true))),
+                    )
+                    .push(
+                        widget::button::icon(icon::from_name("go-down-symbolic"))
+                            .tooltip(fl!("move-page-down"))
+                            .on_press_maybe((index + 1 < available.len()).then_some(Message::MovePage(page, false))),
+                    )
+                    .push(widget::toggler(!hidden).on_toggle_maybe(on_toggle))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs),
+            ));
+        }
+        visible = visible.add(settings::item(
+            fl!("page-order"),
+            widget::button::standard(fl!("reset-page-order"))
+                .on_press_maybe((!self.config.page_order.is_empty()).then_some(Message::ResetPageOrder)),
+        ));
+
+        widget::column::with_capacity(3)
+            .spacing(spacing.space_m)
+            .push(general)
+            .push(privacy)
+            .push(visible)
+            .into()
+    }
+
+    /// Searches every page for the global search, listing up to
+    /// [`GLOBAL_SEARCH_MATCHES`] matches of each and a button opening it.
+    pub fn global_search(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let mut column = widget::column::with_capacity(2 + self.global_matches.len())
+            .spacing(spacing.space_m)
+            .push(
+                widget::search_input(fl!("search-all-placeholder"), &self.global_search)
+                    .id(GLOBAL_SEARCH_INPUT.clone())
+                    .on_input(Message::SearchAll)
+                    .on_clear(Message::SearchAll(String::new())),
+            );
+        if self.global_search.trim().is_empty() {
+            return column.push(widget::text::caption(fl!("search-all-description"))).into();
+        }
+        if self.global_matches.is_empty() {
+            return column.push(widget::text::body(fl!("search-no-results"))).into();
+        }
+
+        for (page, matches) in &self.global_matches {
+            let name = pages::provider(*page).name();
+            let mut section = settings::section().title(name);
+            for (label, value, _) in matches.iter().take(GLOBAL_SEARCH_MATCHES) {
+                section = section.add(settings::item(
+                    label.as_str(),
+                    row::with_capacity(2)
+                        .push(widget::text::body(value.as_str()))
+                        .push(
+                            widget::button::icon(icon::from_name("go-next-symbolic"))
+                                .tooltip(fl!("search-show"))
+                                .on_press(Message::ShowMatch(*page, Some(label.clone()))),
+                        )
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs),
+                ));
+            }
+            if matches.len() > GLOBAL_SEARCH_MATCHES {
+                section = section.add(settings::item(
+                    fl!("search-more", count = matches.len() - GLOBAL_SEARCH_MATCHES),
+                    widget::button::standard(fl!("search-show")).on_press(Message::ShowMatch(*page, None)),
+                ));
+            }
+            column = column.push(section);
+        }
+        column.into()
+    }
+
+    /// The results of the baseline last checked against, failures first.
+    pub fn compliance(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Some(compliance) = self.compliance.as_ref().filter(|compliance| !compliance.checks.is_empty()) else {
+            return widget::text::body(fl!("baseline-empty")).into();
+        };
+
+        let failed = compliance
+            .checks
+            .iter()
+            .filter(|check| check.outcome == baseline::Outcome::Fail)
+            .count();
+        let unknown = compliance
+            .checks
+            .iter()
+            .filter(|check| check.outcome == baseline::Outcome::Unknown)
+            .count();
+        let (summary, severity) = if failed > 0 {
+            (fl!("baseline-failed", count = failed), Severity::Critical)
+        } else if unknown > 0 {
+            (fl!("baseline-incomplete", count = unknown), Severity::Warning)
+        } else {
+            (fl!("baseline-passed"), Severity::Good)
+        };
+
+        let mut section = settings::section().title(compliance.name.as_str());
+        let mut checks = compliance.checks.iter().collect::<Vec<_>>();
+        checks.sort_by_key(|check| match check.outcome {
+            baseline::Outcome::Fail => 0,
+            baseline::Outcome::Unknown => 1,
+            baseline::Outcome::Pass => 2,
+        });
+        for check in checks {
+            let severity = match check.outcome {
+                baseline::Outcome::Pass => Severity::Good,
+                baseline::Outcome::Fail => Severity::Critical,
+                baseline::Outcome::Unknown => Severity::Warning,
+            };
+            let actual = check.actual.clone().unwrap_or_else(|| fl!("unknown"));
+            section = section.add(settings::item(
+                check.label.as_str(),
+                row::with_capacity(2)
+                    .push(self.status_indicator(severity))
+                    .push(widget::text::body(fl!(
+                        "baseline-result",
+                        actual = actual,
+                        expected = check.expected.as_str()
+                    )))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs),
+            ));
+        }
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(
+                row::with_capacity(2)
+                    .push(self.status_indicator(severity))
+                    .push(widget::text::heading(summary))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs),
+            )
+            .push(section)
+            .into()
+    }
+
+    /// The snapshots recorded on schedule, each to open or compare with.
+    pub fn history(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let column = widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(widget::text::caption(fl!("history-description")));
+        if self.history.is_empty() {
+            return column.push(widget::text::body(fl!("history-empty"))).into();
+        }
+
+        let mut section = settings::section();
+        for entry in &self.history {
+            section = section.add(settings::item(
+                fl!("history-taken", time = entry.taken.as_str()),
+                row::with_capacity(2)
+                    .push(widget::button::standard(fl!("compare")).on_press(Message::CompareHistory(entry.path.clone())))
+                    .push(widget::button::standard(fl!("open")).on_press(Message::OpenHistory(entry.path.clone())))
+                    .spacing(spacing.space_xxs),
+            ));
+        }
+        column.push(section).into()
+    }
+
+    /// The theme's status icon for `severity`, or with the color-blind
+    /// palette a colored shape that tells the severities apart by form.
+    pub(crate) fn status_indicator<'a>(&self, severity: Severity) -> Element<'a, Message> {
+        if self.config.status_palette == StatusPalette::Standard {
+            let name = match severity {
+                Severity::Good => "emblem-ok-symbolic",
+                Severity::Info => "dialog-information-symbolic",
+                Severity::Warning => "dialog-warning-symbolic",
+                Severity::Critical => "dialog-error-symbolic",
+            };
+            return icon::from_name(name).size(16).into();
+        }
+
+        let (glyph, color) = match severity {
+            Severity::Good => ("✔", Color::from_rgb8(0x00, 0x9e, 0x73)),
+            Severity::Info => ("■", Color::from_rgb8(0x56, 0xb4, 0xe9)),
+            Severity::Warning => ("▲", Color::from_rgb8(0xe6, 0x9f, 0x00)),
+            Severity::Critical => ("✖", Color::from_rgb8(0xd5, 0x5e, 0x00)),
+        };
+
+        widget::text::body(glyph)
+            .class(theme::Text::Color(color))
+            .into()
+    }
+
+    /// Shows `notice` in the header for a few seconds.
+    fn show_notice(&mut self, notice: String) -> Task<Message> {
+        self.notice = Some(notice);
+        self.notice_sequence += 1;
+        let sequence = self.notice_sequence;
+        Task::perform(tokio::time::sleep(NOTICE_DURATION), move |()| {
+            cosmic::app::Message::App(Message::NoticeExpired(sequence))
+        })
+    }
+
+    /// Remembers the current value of a setting before it is first changed.
+    fn record_change(&mut self, label: String, setting: &Setting) {
+        if self.changes.iter().any(|change| change.setting == *setting) {
+            return;
+        }
+        let original = match setting {
+            Setting::ChargeLimit(battery) => read_setting(&power::charge_limit_path(battery)),
+            Setting::ConservationMode(path) => read_setting(path),
+            // The profile shown is the one the daemon reported last.
+            Setting::PowerProfile => match self.sections.get::<pages::power::Provider>().map(|section| &section.profiles) {
+                Some(Ok(profiles)) => Ok(profiles.active.clone()),
+                _ => Err("power profile not read".to_string()),
+            },
+        };
+        match original {
+            Ok(original) => self.changes.push(Change {
+                label,
+                setting: setting.clone(),
+                original,
+            }),
+            Err(e) => warn!("cannot record original value: {}", e),
+        }
+    }
+
+    /// Starts collecting the data of `page` the first time it is shown, along
+    /// with the pages it shows data of too. Later visits reuse the result,
+    /// and nothing is collected while a snapshot is shown.
+    fn load_page(&mut self, page: Page) -> Task<Message> {
+        if self.snapshot.is_some() {
+            return Task::none();
+        }
+        let loads = with_sources(page)
+            .filter(|&page| !self.sections.contains(page) && self.loading.insert(page))
+            .map(|page| load_task(pages::provider(page)))
+            .collect::<Vec<_>>();
+        Task::batch(loads)
+    }
+
+    /// Collects the data of `page` again, or of every page when `None`. The
+    /// old data stays on screen until the new data arrives.
+    fn refresh(&mut self, page: Option<Page>) -> Task<Message> {
+        let Some(page) = page else {
+            return self.collect_all();
+        };
+
+        let loads = with_sources(page)
+            .filter(|&page| self.loading.insert(page))
+            .map(|page| load_task(pages::provider(page)))
+            .collect::<Vec<_>>();
+        Task::batch(loads)
+    }
+
+    /// Collects the data of every page concurrently, replacing what was
+    /// collected before, as one cancellable operation with its progress shown.
+    fn collect_all(&mut self) -> Task<Message> {
+        let available = pages::PAGES
+            .iter()
+            .filter(|provider| provider.is_available())
+            .map(|provider| provider.page())
+            .collect();
+        self.collect(available)
+    }
+
+    /// Collects the data of `pages` like [`Self::collect_all`].
+    fn collect(&mut self, pages: Vec<Page>) -> Task<Message> {
+        if self.collection.is_some() {
+            return Task::none();
+        }
+
+        let providers = pages.into_iter().map(pages::provider).collect::<Vec<_>>();
+        let pending = providers.iter().map(|provider| provider.page()).collect::<HashSet<_>>();
+        self.loading.extend(pending.iter().copied());
+        self.collection = Some(Collection {
+            operation: self.next_operation_id,
+            total: pending.len(),
+            pending,
+        });
+
+        let task = Task::batch(providers.into_iter().map(load_task));
+        self.start_operation(fl!("operation-collect"), task)
+    }
+
+    /// Forgets the progress of a collection once its operation ends. Pages
+    /// left pending by a cancellation load again when they are next shown,
+    /// right away for the page on screen.
+    fn finish_collection(&mut self, operation: u64) -> Task<Message> {
+        let Some(collection) = self.collection.take_if(|collection| collection.operation == operation) else {
+            return Task::none();
+        };
+        for page in &collection.pending {
+            self.loading.remove(page);
+        }
+        // A cancelled collection cancels the report or snapshot waiting on it too.
+        let report = self
+            .report_pending
+            .take()
+            .filter(|_| collection.pending.is_empty())
+            .map(|format| self.save_report(format));
+        let snapshot = (std::mem::take(&mut self.snapshot_pending) && collection.pending.is_empty())
+            .then(|| self.save_snapshot());
+        if let Some((name, old)) = self.comparison_pending.take().filter(|_| collection.pending.is_empty()) {
+            self.show_comparison(name, &old);
+        }
+        if let Some(baseline) = self.baseline_pending.take().filter(|_| collection.pending.is_empty()) {
+            self.show_compliance(&baseline);
+        }
+        let record = (std::mem::take(&mut self.record_pending) && collection.pending.is_empty())
+            .then(|| self.record_snapshot());
+        let load = match self.nav.data::<Page>(self.nav.active()).copied() {
+            Some(page) if collection.pending.contains(&page) => self.load_page(page),
+            _ => Task::none(),
+        };
+        Task::batch(report.into_iter().chain(snapshot).chain(record).chain([load]))
+    }
+
+    /// Asks where to save the report of every page on this system and saves it.
+    fn save_report(&self, format: export::Format) -> Task<Message> {
+        let pages = pages::PAGES
+            .iter()
+            .filter(|provider| self.is_available(provider.page()))
+            .map(|provider| self.shared_data(provider.page()))
+            .collect::<Vec<_>>();
+        let contents = export::document(format, &pages, self.raw_output().then(host::raw_output));
+        Task::perform(
+            export::save(fl!("report-title"), export::report_file_name(format), contents),
+            |result| cosmic::app::Message::App(Message::Exported(result)),
+        )
+    }
+
+    /// Whether exports include the raw output of the tools, which only
+    /// exists for this system, not for a snapshot, and cannot be redacted.
+    fn raw_output(&self) -> bool {
+        self.config.export_raw_output && !self.config.redact_exports && self.snapshot.is_none()
+    }
+
+    /// Whether a snapshot is shown instead of this system's data.
+    pub(crate) fn shows_snapshot(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Opens `context_page` in the context drawer, such as a device's details.
+    pub(crate) fn show_context_page(&mut self, context_page: ContextPage) {
+        self.context_page = context_page;
+        self.core.window.show_context = true;
+        self.set_context_title(context_page.title());
+    }
+
+    /// Whether `page` applies to the system shown, this one or the snapshot's.
+    fn is_available(&self, page: Page) -> bool {
+        match &self.snapshot {
+            Some(snapshot) => snapshot.pages.contains(&page),
+            None => pages::provider(page).is_available(),
+        }
+    }
+
+    /// Asks where to save a snapshot of every value collected and saves it.
+    fn save_snapshot(&self) -> Task<Message> {
+        let contents = serde_json::to_string_pretty(&self.take_snapshot()).unwrap_or_default();
+        Task::perform(
+            export::save(fl!("save-snapshot"), snapshot::file_name(), contents),
+            |result| cosmic::app::Message::App(Message::Exported(result)),
+        )
+    }
+
+    /// Records a snapshot of every value collected into the history.
+    fn record_snapshot(&self) -> Task<Message> {
+        let snapshot = self.take_snapshot();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || snapshot::record(&snapshot))
+                    .await
+                    .map_err(|e| e.to_string())?
+            },
+            |result| cosmic::app::Message::App(Message::SnapshotRecorded(result)),
+        )
+    }
+
+    /// Every value collected, as saved in a snapshot.
+    fn take_snapshot(&self) -> snapshot::Snapshot {
+        snapshot::Snapshot {
+            version: snapshot::VERSION,
+            taken: export::timestamp(),
+            pages: pages::PAGES
+                .iter()
+                .filter(|provider| self.is_available(provider.page()))
+                .map(|provider| provider.id().to_string())
+                .collect(),
+            sections: self.sections.clone(),
+        }
+    }
+
+    /// Compares the data shown with the older snapshot `old`, named `name`,
+    /// and shows the changes in the context drawer.
+    fn show_comparison(&mut self, name: String, old: &snapshot::Snapshot) {
+        self.comparison = Some(Comparison {
+            name,
+            taken: old.taken.clone(),
+            changes: diff::compare(old, &self.take_snapshot()),
+        });
+        self.context_page = ContextPage::Comparison;
+        self.core.window.show_context = true;
+        self.set_context_title(ContextPage::Comparison.title());
+    }
+
+    /// Searches the data of the page shown for the page's search.
+    fn search_page(&mut self) {
+        self.search_matches = match self.nav.data::<Page>(self.nav.active()) {
+            Some(&page) if !self.search.trim().is_empty() => export::search(&self.export_data(page), &self.search),
+            _ => Vec::new(),
+        };
+    }
+
+    /// Searches the data of every page listed for the global search. Pages
+    /// still being collected hold no data yet, and are searched again once
+    /// their data arrives.
+    fn search_all(&mut self) {
+        self.global_matches = if self.global_search.trim().is_empty() {
+            Vec::new()
+        } else {
+            pages::PAGES
+                .iter()
+                .map(|provider| provider.page())
+                .filter(|&page| self.is_available(page) && !self.config.is_hidden(page))
+                .map(|page| (page, export::search(&self.export_data(page), &self.global_search)))
+                .filter(|(_, matches)| !matches.is_empty())
+                .collect()
+        };
+    }
+
+    /// Checks the data shown against `baseline` and shows the results in the
+    /// context drawer.
+    fn show_compliance(&mut self, baseline: &baseline::Baseline) {
+        self.compliance = Some(Compliance {
+            name: baseline.name.clone().unwrap_or_default(),
+            checks: baseline::check(baseline, &self.take_snapshot()),
+        });
+        self.context_page = ContextPage::Compliance;
+        self.core.window.show_context = true;
+        self.set_context_title(ContextPage::Compliance.title());
+    }
+
+    /// Saves the nav bar's page order as `order`, page ids, and applies it.
+    fn set_page_order(&mut self, order: Vec<String>) -> Task<Message> {
+        if let Some(handler) = &self.config_handler {
+            if let Err(e) = self.config.set_page_order(handler, order) {
+                error!("failed to save the page order: {}", e);
+            }
+        } else {
+            self.config.page_order = order;
+        }
+        self.rebuild_nav()
+    }
+
+    /// Lists the pages in the nav bar again after some were hidden, shown or
+    /// moved, staying on the page shown if it is still listed.
+    fn rebuild_nav(&mut self) -> Task<Message> {
+        let active = self.nav.data::<Page>(self.nav.active()).copied();
+        let nav = page_nav(
+            &self.config.ordered_pages(),
+            |page| self.is_available(page) && !self.config.is_hidden(page),
+            active,
+        );
+        self.nav = nav;
+        let page = self.nav.data::<Page>(self.nav.active()).copied();
+        if page == active {
+            return Task::none();
+        }
+        self.search.clear();
+        self.search_matches.clear();
+        self.search_match = None;
+        let load = page.map_or_else(Task::none, |page| self.load_page(page));
+        Task::batch([load, self.update_title()])
+    }
+
+    /// Shows `snapshot` in place of this system's data until it is closed.
+    fn open_snapshot(&mut self, name: String, snapshot: snapshot::Snapshot) {
+        // A collection still running would go on reading this system, and
+        // what waits on it would act on the snapshot instead.
+        if let Some(collection) = self.collection.take() {
+            if let Some(index) = self.operations.iter().position(|operation| operation.id == collection.operation) {
+                self.operations.remove(index).handle.abort();
+            }
+            host::cancel_running();
+            self.report_pending = None;
+            self.snapshot_pending = false;
+            self.comparison_pending = None;
+            self.baseline_pending = None;
+            self.record_pending = false;
+        }
+
+        let pages = snapshot.pages.iter().filter_map(|id| Page::from_id(id)).collect::<Vec<_>>();
+        let active = self.nav.data::<Page>(self.nav.active()).copied();
+        self.nav = page_nav(
+            &self.config.ordered_pages(),
+            |page| pages.contains(&page) && !self.config.is_hidden(page),
+            active,
+        );
+
+        // Nothing is loaded from this system while the snapshot is shown.
+        self.sections = snapshot.sections;
+        self.loading.clear();
+        self.pci.selected = None;
+        self.pci.details = None;
+        self.usb.selected = None;
+        self.search_page();
+        self.snapshot = Some(OpenSnapshot {
+            name,
+            taken: snapshot.taken,
+            pages,
+        });
+    }
+
+    /// Goes back from the snapshot shown to this system's data, on the same
+    /// page if it applies here. Only the pages and their data are replaced;
+    /// the changes that can be undone and the running operations are kept.
+    fn close_snapshot(&mut self) -> Task<Message> {
+        if self.snapshot.take().is_none() {
+            return Task::none();
+        }
+        let active = self.nav.data::<Page>(self.nav.active()).copied();
+        let nav = page_nav(
+            &self.config.ordered_pages(),
+            |page| self.is_available(page) && !self.config.is_hidden(page),
+            active,
+        );
+        self.nav = nav;
+
+        // The snapshot's data is dropped, and this system's collected again.
+        self.sections = pages::Sections::default();
+        self.loading.clear();
+        self.pci.selected = None;
+        self.pci.details = None;
+        self.usb.selected = None;
+        self.search_page();
+        let load = match self.nav.data::<Page>(self.nav.active()).copied() {
+            Some(page) => self.load_page(page),
+            None => Task::none(),
+        };
+        Task::batch([load, self.update_title()])
+    }
+
+    /// Stores the data of `page` collected in the background, keeping what
+    /// the old data held that collecting does not read again.
+    fn data_loaded(&mut self, page: Page, mut result: Result<Box<dyn AnySection>, host::Error>) {
+        self.loading.remove(&page);
+        let provider = pages::provider(page);
+        match &mut result {
+            Ok(new) => {
+                if let Some(Ok(old)) = self.sections.remove(page) {
+                    provider.carry_over(old, new);
+                }
+            }
+            Err(e) => error!("failed to collect {}: {}", provider.id(), e),
+        }
+        self.sections.insert(page, result);
+    }
+
+    /// Runs `task` as a cancellable operation listed in the header.
+    pub(crate) fn start_operation(&mut self, label: String, task: Task<Message>) -> Task<Message> {
+        let id = self.next_operation_id;
+        self.next_operation_id += 1;
+
+        let finished = Task::done(cosmic::app::Message::App(Message::OperationFinished(id)));
+        let (task, handle) = task.chain(finished).abortable();
+        self.operations.push(Operation { id, label, handle });
+        task
+    }
+
+    /// A section that shows only its title until expanded. Expansion is
+    /// remembered per page while the application runs.
+    pub(crate) fn collapsible<'a>(
+        &self,
+        page: Page,
+        id: String,
+        title: String,
+        content: impl Into<Element<'a, Message>>,
+    ) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let expanded = self.expanded_sections.contains(&(page, id.clone()));
+        let header = widget::button::custom(
+            row::with_capacity(2)
+                .push(icon::from_name(if expanded { "go-down-symbolic" } else { "go-next-symbolic" }).size(16))
+                .push(widget::text::body(title))
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xs),
+        )
+        .class(theme::Button::Text)
+        .width(Length::Fill)
+        .on_press(Message::ToggleSection(page, id));
+
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_xxs).push(header);
+        if expanded {
+            column = column.push(content);
+        }
+        column.into()
+    }
+
+    /// The overview's key facts on one line, for pasting into a support
+    /// channel, e.g. `Distribution: Fedora Linux 40 · Kernel: 6.9.4 · …`.
+    /// Facts not collected yet are left out.
+    fn summary(&self) -> String {
+        use pages::overview::OverviewRow;
+        const ROWS: &[OverviewRow] = &[
+            OverviewRow::Distribution,
+            OverviewRow::Kernel,
+            OverviewRow::Processor,
+            OverviewRow::Graphics,
+            OverviewRow::Memory,
+            OverviewRow::PrimaryDisk,
+        ];
+        self.overview_rows(self.sections.get::<pages::overview::Provider>())
+            .into_iter()
+            .filter(|(row, ..)| ROWS.contains(row))
+            .filter_map(|(_, label, value)| Some(format!("{label}: {}", value?)))
+            .join(" · ")
+    }
+
+    /// The model with its config applied and `open` selected, before any
+    /// page is collected or the snapshot history is read.
+    fn new(core: Core, open: Option<Page>) -> Self {
+        let (config_handler, config) = Config::load(Self::APP_ID);
+        host::set_timeout(Duration::from_secs(config.command_timeout));
+        storage::set_units(config.byte_units);
+        let nav = page_nav(
+            &config.ordered_pages(),
+            |page| pages::provider(page).is_available() && !config.is_hidden(page),
+            open,
+        );
+
+        AppModel {
+            core,
+            context_page: ContextPage::default(),
+            nav,
+            key_binds: HashMap::from([
+                (
+                    menu::KeyBind {
+                        modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
+                        key: Key::Character("r".into()),
+                    },
+                    MenuAction::RefreshAll,
+                ),
+                (
+                    menu::KeyBind {
+                        modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
+                        key: Key::Character("c".into()),
+                    },
+                    MenuAction::CopyPage,
+                ),
+                (
+                    menu::KeyBind {
+                        modifiers: vec![menu::key_bind::Modifier::Ctrl],
+                        key: Key::Character("f".into()),
+                    },
+                    MenuAction::SearchAll,
+                ),
+            ]),
+            config_handler,
+            config,
+            processor: pages::processor::State::default(),
+            pci: pages::pci::State::default(),
+            usb: pages::usb::State::default(),
+            sections: pages::Sections::default(),
+            loading: HashSet::new(),
+            fetch_view: false,
+            operations: Vec::new(),
+            next_operation_id: 0,
+            changes: Vec::new(),
+            scroll_offsets: HashMap::new(),
+            collection: None,
+            report_pending: None,
+            notice: None,
+            notice_sequence: 0,
+            expanded_sections: HashSet::new(),
+            snapshot: None,
+            snapshot_pending: false,
+            comparison: None,
+            comparison_pending: None,
+            history: Vec::new(),
+            record_pending: false,
+            probe_dialog: false,
+            compliance: None,
+            baseline_pending: None,
+            search: String::new(),
+            search_matches: Vec::new(),
+            search_match: None,
+            global_search: String::new(),
+            global_matches: Vec::new(),
+        }
+    }
+
+    /// Collects `page` in place rather than in the background, for the
+    /// command line. Data only readable through the privileged helper is
+    /// left out, so that printing a page never asks for a password.
+    pub(crate) fn headless(page: Page) -> Self {
+        let mut app = Self::new(Core::default(), None);
+        tools::detect();
+        for provider in with_sources(page).map(pages::provider) {
+            app.data_loaded(provider.page(), provider.collect());
+        }
+        app
+    }
+
+    /// `page` with its name and data, ready for an exporter. Data that has
+    /// not been collected yet is `null`, and a failed collection holds its error.
+    pub(crate) fn export_data(&self, page: Page) -> export::PageData {
+        let provider = pages::provider(page);
+        let data = match self.sections.result(page) {
+            Some(Ok(section)) => provider.export(self, section.as_ref()),
+            Some(Err(e)) => json!({ "error": e.to_string() }),
+            None => Value::Null,
+        };
+        export::PageData {
+            page,
+            title: provider.name(),
+            data,
+        }
+    }
+
+    /// [`Self::export_data`] as shared with others through exports, reports
+    /// and the clipboard, without identifying values if the user asked so.
+    fn shared_data(&self, page: Page) -> export::PageData {
+        let mut data = self.export_data(page);
+        if self.config.redact_exports {
+            export::redact(&mut data.data);
+        }
+        data
+    }
+
+    /// Updates the header and window titles.
+    pub fn update_title(&mut self) -> Task<Message> {
+        let mut window_title = fl!("app-title");
+
+        if let Some(page) = self.nav.text(self.nav.active()) {
+            window_title.push_str(" — ");
+            window_title.push_str(page);
+        }
+
+        if let Some(snapshot) = &self.snapshot {
+            window_title.push_str(" — ");
+            window_title.push_str(&snapshot.name);
+        }
+
+        self.set_window_title(window_title)
+    }
+}
+
+/// The label of an auto refresh interval of `seconds`, 0 being off.
+fn auto_refresh_label(seconds: u64) -> String {
+    match seconds {
+        0 => fl!("auto-refresh-off"),
+        seconds => fl!("auto-refresh-every", seconds = seconds),
+    }
+}
+
+fn snapshot_schedule_label(schedule: SnapshotSchedule) -> String {
+    match schedule {
+        SnapshotSchedule::Off => fl!("snapshot-schedule-off"),
+        SnapshotSchedule::Launch => fl!("snapshot-schedule-launch"),
+        SnapshotSchedule::Daily => fl!("snapshot-schedule-daily"),
+    }
+}
+
+/// Wraps page content in the shared page scrollable, reporting its offset.
+pub(crate) fn page_scrollable<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    widget::scrollable(content)
+        .id(PAGE_SCROLLABLE.clone())
+        .on_scroll(|viewport| Message::PageScrolled(viewport.absolute_offset()))
+        .height(Length::Fill)
+        .into()
+}
+
+/// The nav bar listing the pages of `order` for which `available` holds,
+/// starting on `open` if it is listed and on the first page otherwise. A
+/// divider separates neighbouring pages of different categories.
+fn page_nav(order: &[Page], available: impl Fn(Page) -> bool, open: Option<Page>) -> nav_bar::Model {
+    let mut nav = nav_bar::Model::default();
+    let mut category = None;
+    let open = open.filter(|&page| available(page));
+    for provider in order.iter().filter(|&&page| available(page)).map(|&page| pages::provider(page)) {
+        let entry = nav
+            .insert()
+            .text(provider.name())
+            .data::<Page>(provider.page())
+            .icon(icon::from_name(provider.icon()))
+            .divider_above(category.is_some_and(|category| category != provider.category()));
+        if open.map_or(category.is_none(), |page| page == provider.page()) {
+            entry.activate();
+        }
+        category = Some(provider.category());
+    }
+    nav
+}
+
+/// Probes for the installed tools on the blocking thread pool, since it
+/// runs a shell on the host, which is slow through `flatpak-spawn`.
+fn detect_tools() -> Task<Message> {
+    Task::perform(tokio::task::spawn_blocking(tools::detect), |_| {
+        cosmic::app::Message::App(Message::ToolsDetected)
+    })
+}
+
+/// `page` followed by the pages it shows data of too, those that apply to this system.
+fn with_sources(page: Page) -> impl Iterator<Item = Page> {
+    std::iter::once(page)
+        .chain(pages::provider(page).sources().iter().copied())
+        .filter(|&page| pages::provider(page).is_available())
+}
+
+/// Collects the data of a page on the blocking thread pool.
+fn load_task(provider: &'static dyn AnyProvider) -> Task<Message> {
+    let page = provider.page();
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || provider.collect())
+                .await
+                .map_err(|e| host::Error::Failed(e.to_string()))
+                .and_then(|result| result)
+        },
+        move |result| cosmic::app::Message::App(Message::DataLoaded(page, result)),
+    )
+}
+
+/// Shown in place of a page whose data is still being collected. Cancelling
+/// stops the tools still running, and the page shows what they had not read.
+pub(crate) fn loading_page<'a>() -> Element<'a, Message> {
+    let spacing = theme::active().cosmic().spacing;
+    widget::column::with_capacity(3)
+        .push(icon::from_name("process-working-symbolic").size(32))
+        .push(widget::text::body(fl!("loading")))
+        .push(widget::button::standard(fl!("cancel")).on_press(Message::CancelTools))
+        .spacing(spacing.space_xs)
+        .align_x(Alignment::Center)
+        .apply(widget::container)
+        .center(Length::Fill)
+        .into()
+}
+
+/// Explains why `error` kept a tool or service from providing its data.
+pub(crate) fn error_explanation(error: &host::Error) -> String {
+    match error {
+        host::Error::Missing(program) => fl!("error-missing", program = program.as_str()),
+        host::Error::PermissionDenied(program) => fl!("error-permission-denied", program = program.as_str()),
+        host::Error::TimedOut(program) => fl!("error-timed-out", program = program.as_str()),
+        host::Error::Cancelled(program) => fl!("error-cancelled", program = program.as_str()),
+        host::Error::Failed(message) => message.clone(),
+    }
+}
+
+/// Suggests the package providing the tool `error` found missing.
+pub(crate) fn install_hint(error: &host::Error) -> Option<String> {
+    match error {
+        host::Error::Missing(program) => Some(fl!("error-install-hint", package = host::package(program))),
+        _ => None,
+    }
+}
+
+/// Explains why a page's data could not be read, suggesting the package
+/// that provides a missing tool, with a button to read it again.
+pub(crate) fn failed_page<'a>(page: Page, error: &host::Error) -> Element<'a, Message> {
+    let spacing = theme::active().cosmic().spacing;
+    widget::column::with_capacity(5)
+        .push(icon::from_name("dialog-error-symbolic").size(32))
+        .push(widget::text::title3(fl!("error-occurred")))
+        .push(widget::text::body(error_explanation(error)))
+        .push_maybe(install_hint(error).map(|hint| widget::text::caption(hint)))
+        .push(widget::button::standard(fl!("retry")).on_press(Message::Refresh(Some(page))))
+        .spacing(spacing.space_xs)
+        .align_x(Alignment::Center)
+        .apply(widget::container)
+        .center(Length::Fill)
+        .into()
+}
+
+/// A row showing `value` with a button copying it to the clipboard, so that
+/// serial numbers and device IDs need not be retyped. Its tooltip names
+/// where the value was read from, such as `/sys/class/dmi/id/board_vendor`
+/// or `dmidecode: Serial Number`, so that it can be checked by hand or on
+/// another machine.
+pub(crate) fn value_item<'a>(
+    title: impl Into<Cow<'a, str>>,
+    value: impl Into<String>,
+    source: impl Into<String>,
+) -> Element<'a, Message> {
+    let spacing = theme::active().cosmic().spacing;
+    let value = value.into();
+    let item = settings::item(
+        title,
+        row::with_capacity(2)
+            .push(widget::text::body(value.clone()))
+            .push(
+                widget::button::icon(icon::from_name("edit-copy-symbolic"))
+                    .tooltip(fl!("copy"))
+                    .on_press(Message::CopyValue(value)),
+            )
+            .align_y(Alignment::Center)
+            .spacing(spacing.space_xxs),
+    );
+    widget::tooltip(item, widget::text::caption(source.into()), widget::tooltip::Position::Bottom).into()
+}
+
+/// Stands in for data that `program`, one of [`tools::all`], would provide
+/// but is not installed.
+pub(crate) fn missing_tool<'a>(title: String, program: &str) -> Element<'a, Message> {
+    settings::item(
+        title,
+        widget::text::caption(fl!("tool-missing", program = program, package = host::package(program))),
+    )
+    .into()
+}
+
+/// A table header with equally wide columns.
+pub(crate) fn table_header<'a>(titles: impl IntoIterator<Item = String>) -> Element<'a, Message> {
+    titles
+        .into_iter()
+        .fold(row::new(), |row, title| {
+            row.push(widget::container(widget::text::heading(title)).width(Length::FillPortion(1)))
+        })
+        .into()
+}
+
+/// A table row with equally wide columns, matching [`table_header`].
+pub(crate) fn table_row<'a>(values: impl IntoIterator<Item = String>) -> Element<'a, Message> {
+    values
+        .into_iter()
+        .fold(row::new(), |row, value| {
+            row.push(widget::container(widget::text::body(value)).width(Length::FillPortion(1)))
+        })
+        .into()
+}
+
+/// A labelled bar showing how much of `total` is used.
+pub(crate) fn usage_gauge<'a>(used: f32, total: f32, label: String) -> Element<'a, Message> {
+    let spacing = theme::active().cosmic().spacing;
+
+    widget::column::with_capacity(2)
+        .push(widget::text::body(label))
+        .push(widget::progress_bar(0.0..=total.max(1.0), used).height(Length::Fixed(6.0)))
+        .spacing(spacing.space_xxxs)
+        .align_x(Alignment::End)
+        .width(Length::Fixed(240.0))
+        .into()
+}
+
+/// Reads the current value of a sysfs setting, to restore on undo.
+fn read_setting(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map(|original| original.trim().to_string())
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Applies system settings in order without blocking the UI, authorizing sysfs
+/// writes through polkit.
+fn write_settings(
+    writes: Vec<(Setting, String)>,
+    on_done: fn(Result<(), String>) -> Message,
+) -> Task<Message> {
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                writes.iter().try_for_each(|(setting, value)| setting.write(value))
+            })
+            .await
+            .map_err(|e| e.to_string())?
+        },
+        move |result| cosmic::app::Message::App(on_done(result)),
+    )
+}
+
+/// How serious a status indicator is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Good,
+    Info,
+    Warning,
+    Critical,
+}
+
+/// The context page to display in the context drawer.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ContextPage {
+    #[default]
+    About,
+    Help,
+    Diagnostics,
+    PciDevice,
+    UsbDevice,
+    Comparison,
+    History,
+    Compliance,
+    Search,
+    Settings,
+}
+
+impl ContextPage {
+    fn title(&self) -> String {
+        match self {
+            Self::About => fl!("about"),
+            Self::Help => fl!("page-help"),
+            Self::Diagnostics => fl!("diagnostics"),
+            Self::PciDevice | Self::UsbDevice => fl!("device-details"),
+            Self::Comparison => fl!("diff-title"),
+            Self::History => fl!("snapshot-history"),
+            Self::Compliance => fl!("baseline-title"),
+            Self::Search => fl!("search-all"),
+            Self::Settings => fl!("settings"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MenuAction {
+    About,
+    Help,
+    Diagnostics,
+    CopyPage,
+    CopySummary,
+    SaveSnapshot,
+    OpenSnapshot,
+    CloseSnapshot,
+    CompareSnapshot,
+    CheckBaseline,
+    SetSnapshotSchedule(SnapshotSchedule),
+    History,
+    SearchAll,
+    Settings,
+    SubmitProbe,
+    RefreshAll,
+    SetAutoRefresh(u64),
+    Export(export::Format),
+    GenerateReport(export::Format),
+    ToggleColorBlindPalette,
+    ToggleExportRawOutput,
+}
+
+impl menu::action::MenuAction for MenuAction {
+    type Message = Message;
+
+    fn message(&self) -> Self::Message {
+        match self {
+            MenuAction::About => Message::ToggleContextPage(ContextPage::About),
+            MenuAction::Help => Message::ToggleContextPage(ContextPage::Help),
+            MenuAction::Diagnostics => Message::ToggleContextPage(ContextPage::Diagnostics),
+            MenuAction::CopyPage => Message::CopyPage,
+            MenuAction::CopySummary => Message::CopySummary,
+            MenuAction::SaveSnapshot => Message::SaveSnapshot,
+            MenuAction::OpenSnapshot => Message::OpenSnapshot,
+            MenuAction::CloseSnapshot => Message::CloseSnapshot,
+            MenuAction::CompareSnapshot => Message::CompareSnapshot,
+            MenuAction::CheckBaseline => Message::CheckBaseline,
+            MenuAction::SetSnapshotSchedule(schedule) => Message::SetSnapshotSchedule(schedule),
+            MenuAction::History => Message::ToggleContextPage(ContextPage::History),
+            MenuAction::SearchAll => Message::OpenGlobalSearch,
+            MenuAction::Settings => Message::ToggleContextPage(ContextPage::Settings),
+            MenuAction::SubmitProbe => Message::SubmitProbe,
+            MenuAction::RefreshAll => Message::Refresh(None),
+            MenuAction::SetAutoRefresh(seconds) => Message::SetAutoRefresh(seconds),
+            MenuAction::Export(format) => Message::Export(format),
+            MenuAction::GenerateReport(format) => Message::GenerateReport(format),
+            MenuAction::ToggleColorBlindPalette => Message::ToggleColorBlindPalette,
+            MenuAction::ToggleExportRawOutput => Message::ToggleExportRawOutput,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmic::cosmic_config::CosmicConfigEntry;
+
+    #[test]
+    fn headless_leaves_history_alone() {
+        let home = std::env::temp_dir().join(format!("examine-headless-{}", std::process::id()));
+        std::env::set_var("XDG_CONFIG_HOME", home.join("config"));
+        std::env::set_var("XDG_DATA_HOME", home.join("data"));
+        let handler = cosmic_config::Config::new(AppModel::APP_ID, Config::VERSION).unwrap();
+        Config::default().set_snapshot_schedule(&handler, SnapshotSchedule::Launch).unwrap();
+
+        let app = AppModel::headless(Page::Distribution);
+        let export = app.export_data(Page::Distribution);
+
+        assert_eq!(app.config.snapshot_schedule, SnapshotSchedule::Launch);
+        assert!(!export.data.is_null());
+        assert!(!app.record_pending && app.collection.is_none());
+        assert!(snapshot::history().is_empty());
+        assert!(!home.join("data").exists());
+        fs::remove_dir_all(&home).unwrap();
     }
 }
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads the PipeWire clock settings with `pw-metadata` and per-node xrun
+//! counters with `pw-top`.
+
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Clock {
+    pub rate: Option<String>,
+    pub allowed_rates: Option<String>,
+    pub quantum: Option<String>,
+    pub min_quantum: Option<String>,
+    pub max_quantum: Option<String>,
+    /// Non-zero when a quantum or rate is being forced, e.g. by a pro-audio app.
+    pub force_quantum: Option<String>,
+    pub force_rate: Option<String>,
+}
+
+impl Clock {
+    /// Latency of one quantum at the current rate, in milliseconds.
+    pub fn latency_ms(&self) -> Option<f64> {
+        let quantum = self.quantum.as_deref()?.parse::<f64>().ok()?;
+        let rate = self.rate.as_deref()?.parse::<f64>().ok()?;
+        (rate > 0.0).then(|| quantum / rate * 1000.0)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Node {
+    pub id: u32,
+    pub name: String,
+    /// Whether this node drives the graph clock.
+    pub driver: bool,
+    pub quantum: String,
+    pub rate: String,
+    /// Xruns and other errors counted by PipeWire.
+    pub errors: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Audio {
+    pub clock: Result<Clock, String>,
+    pub nodes: Result<Vec<Node>, String>,
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = host::output(host::command(program).args(args))
+        .map_err(|e| host::spawn_error(program, &e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses lines such as `update: id:0 key:'clock.rate' value:'48000' type:''`.
+fn parse_metadata(output: &str) -> Clock {
+    let mut clock = Clock::default();
+    for line in output.lines() {
+        let field = |name: &str| {
+            let start = line.find(&format!("{name}:'"))? + name.len() + 2;
+            let end = start + line[start..].find('\'')?;
+            Some(line[start..end].to_string())
+        };
+        let (Some(key), value) = (field("key"), field("value")) else {
+            continue;
+        };
+        match key.as_str() {
+            "clock.rate" => clock.rate = value,
+            "clock.allowed-rates" => clock.allowed_rates = value,
+            "clock.quantum" => clock.quantum = value,
+            "clock.min-quantum" => clock.min_quantum = value,
+            "clock.max-quantum" => clock.max_quantum = value,
+            "clock.force-quantum" => clock.force_quantum = value,
+            "clock.force-rate" => clock.force_rate = value,
+            _ => {}
+        }
+    }
+    clock
+}
+
+/// Parses the last iteration of `pw-top --batch-mode`. Columns are
+/// `S ID QUANT RATE WAIT BUSY W/Q B/Q ERR FORMAT NAME`; followers are
+/// listed below their driver with their name prefixed by ` + `.
+fn parse_top(output: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    for line in output.lines() {
+        let columns = line.split_whitespace().collect::<Vec<_>>();
+        if columns.get(1) == Some(&"ID") {
+            nodes.clear();
+            continue;
+        }
+        if columns.len() < 10 {
+            continue;
+        }
+        let (Ok(id), Ok(errors)) = (columns[1].parse(), columns[8].parse()) else {
+            continue;
+        };
+        // FORMAT is empty, `-`, or a sample format followed by channels and rate.
+        let mut rest = &columns[9..];
+        let is_format = |column: &str| {
+            column
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        };
+        if rest.len() > 1 && is_format(rest[0]) {
+            rest = &rest[1..];
+            while rest.len() > 1 && rest[0].parse::<u32>().is_ok() {
+                rest = &rest[1..];
+            }
+        }
+        let driver = rest.first() != Some(&"+");
+        let name = rest.iter().skip_while(|column| **column == "+").join(" ");
+
+        nodes.push(Node {
+            id,
+            name,
+            driver,
+            quantum: columns[2].to_string(),
+            rate: columns[3].to_string(),
+            errors,
+        });
+    }
+    nodes
+}
+
+pub fn collect() -> Audio {
+    Audio {
+        clock: run("pw-metadata", &["-n", "settings"]).map(|output| parse_metadata(&output)),
+        // The first iteration has no timing data yet, so sample twice.
+        nodes: run("pw-top", &["--batch-mode", "--iterations", "2"]).map(|output| parse_top(&output)),
+    }
+}
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Checks the system against an "expected hardware" profile, such as the one
+//! an IT department requires of every machine it images, for a quick
+//! acceptance check. A profile is a JSON object in which every requirement
+//! is optional:
+//!
+//! ```json
+//! {
+//!     "name": "Engineering laptop",
+//!     "cpu_model": "Core(TM) i7",
+//!     "min_logical_cpus": 8,
+//!     "min_memory_gib": 16,
+//!     "min_disk_gb": 500,
+//!     "tpm": true,
+//!     "tpm_version": "2.0",
+//!     "secure_boot": true
+//! }
+//! ```
+
+use std::path::PathBuf;
+
+use cosmic::dialog::file_chooser;
+use serde::Deserialize;
+
+use crate::pages::{filesystems, memory, motherboard, processor, tpm, uefi};
+use crate::snapshot::Snapshot;
+use crate::{dmi, fl, host, storage};
+
+/// The requirements of a profile.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Baseline {
+    /// Shown as the heading of the results.
+    pub name: Option<String>,
+    /// Text the processor's model name must contain.
+    pub cpu_model: Option<String>,
+    pub min_logical_cpus: Option<usize>,
+    /// Installed memory in GiB.
+    pub min_memory_gib: Option<f64>,
+    /// Size of the largest fixed disk in GB of 10⁹ bytes, as disks are sold.
+    pub min_disk_gb: Option<f64>,
+    /// Whether a TPM must be present, or absent.
+    pub tpm: Option<bool>,
+    /// The TPM version, e.g. `2.0`.
+    pub tpm_version: Option<String>,
+    /// Whether Secure Boot must be enabled, or disabled.
+    pub secure_boot: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    /// The value could not be read, e.g. without the tool that reports it.
+    Unknown,
+}
+
+/// The result of one requirement.
+#[derive(Clone, Debug)]
+pub struct Check {
+    pub label: String,
+    pub expected: String,
+    /// The value found, if it could be read.
+    pub actual: Option<String>,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    fn new(label: String, expected: String, actual: Option<String>, passed: Option<bool>) -> Self {
+        let outcome = match passed {
+            Some(true) => Outcome::Pass,
+            Some(false) => Outcome::Fail,
+            None => Outcome::Unknown,
+        };
+        Self {
+            label,
+            expected,
+            actual,
+            outcome,
+        }
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value {
+        fl!("yes")
+    } else {
+        fl!("no")
+    }
+}
+
+/// The installed memory in GiB: the sum of the DIMM sizes when the DMI
+/// tables were read, otherwise the memory the kernel manages rounded up to
+/// a whole GiB, since the firmware and kernel reserve some of it.
+fn installed_memory_gib(snapshot: &Snapshot) -> Option<f64> {
+    let sections = &snapshot.sections;
+    let dmi = sections.get::<motherboard::Provider>().and_then(|section| section.dmi.as_ref());
+    let dimms = dmi.and_then(|dmi| dmi.as_ref().ok()).map(|records| {
+        dmi::memory_devices(records)
+            .iter()
+            .filter_map(|device| {
+                let (value, unit) = device.size.as_deref()?.split_once(' ')?;
+                let value = value.parse::<f64>().ok()?;
+                match unit {
+                    "kB" | "KB" => Some(value / (1024.0 * 1024.0)),
+                    "MB" => Some(value / 1024.0),
+                    "GB" => Some(value),
+                    "TB" => Some(value * 1024.0),
+                    _ => None,
+                }
+            })
+            .sum::<f64>()
+    });
+    dimms.filter(|&gib| gib > 0.0).or_else(|| {
+        let kib = sections.get::<memory::Provider>()?.memory.total()?;
+        Some((kib as f64 / (1024.0 * 1024.0)).ceil())
+    })
+}
+
+/// Checks `snapshot` against every requirement of `baseline`, in the order
+/// they are documented.
+pub fn check(baseline: &Baseline, snapshot: &Snapshot) -> Vec<Check> {
+    let sections = &snapshot.sections;
+    let processor = sections.get::<processor::Provider>();
+    let mut checks = Vec::new();
+
+    if let Some(model) = &baseline.cpu_model {
+        let actual = processor.and_then(|section| section.info.model_name.clone());
+        let passed = actual.as_ref().map(|actual| actual.contains(model.as_str()));
+        checks.push(Check::new(fl!("processor"), model.clone(), actual, passed));
+    }
+
+    if let Some(min) = baseline.min_logical_cpus {
+        let count = processor.map(|section| section.logical_cpus.len()).filter(|&count| count > 0);
+        checks.push(Check::new(
+            fl!("logical-cpus"),
+            fl!("baseline-at-least", value = min.to_string()),
+            count.map(|count| count.to_string()),
+            count.map(|count| count >= min),
+        ));
+    }
+
+    if let Some(min) = baseline.min_memory_gib {
+        let gib = installed_memory_gib(snapshot);
+        checks.push(Check::new(
+            fl!("memory"),
+            fl!("baseline-at-least", value = format!("{min} GiB")),
+            gib.map(|gib| format!("{gib} GiB")),
+            gib.map(|gib| gib >= min),
+        ));
+    }
+
+    if let Some(min) = baseline.min_disk_gb {
+        let disk = sections
+            .get::<filesystems::Provider>()
+            .map(|section| {
+                section
+                    .storage
+                    .disks
+                    .iter()
+                    .filter(|disk| !disk.removable)
+                    .map(|disk| disk.size)
+                    .max()
+                    .unwrap_or(0)
+            });
+        checks.push(Check::new(
+            fl!("baseline-disk"),
+            fl!("baseline-at-least", value = format!("{min} GB")),
+            disk.map(|bytes| format!("{:.0} GB ({})", bytes as f64 / 1e9, storage::format_bytes(bytes))),
+            disk.map(|bytes| bytes as f64 / 1e9 >= min),
+        ));
+    }
+
+    // `None` until the TPM page was collected, `Some(None)` without a TPM.
+    let tpm = sections.get::<tpm::Provider>().map(|section| section.tpm.as_ref());
+    if let Some(required) = baseline.tpm {
+        let present = tpm.map(|tpm| tpm.is_some());
+        checks.push(Check::new(
+            fl!("tpm"),
+            yes_no(required),
+            present.map(yes_no),
+            present.map(|present| present == required),
+        ));
+    }
+    if let Some(version) = &baseline.tpm_version {
+        let actual = tpm.map(|tpm| tpm.and_then(|tpm| tpm.version.clone()));
+        checks.push(Check::new(
+            fl!("baseline-tpm-version"),
+            version.clone(),
+            actual.clone().flatten(),
+            actual.map(|actual| actual.as_deref() == Some(version.as_str())),
+        ));
+    }
+
+    if let Some(required) = baseline.secure_boot {
+        let enabled = sections.get::<uefi::Provider>().and_then(|uefi| uefi.secure_boot);
+        checks.push(Check::new(
+            fl!("secure-boot"),
+            yes_no(required),
+            enabled.map(yes_no),
+            enabled.map(|enabled| enabled == required),
+        ));
+    }
+
+    checks
+}
+
+/// Asks for a profile through the file chooser portal and reads it.
+/// Returns `None` when the user cancelled.
+pub async fn open() -> Result<Option<Baseline>, host::Error> {
+    let response = file_chooser::open::Dialog::new()
+        .title(fl!("baseline-open"))
+        .filter(file_chooser::FileFilter::new("JSON").glob("*.json"))
+        .open_file()
+        .await;
+    let path: PathBuf = match response {
+        Ok(response) => match response.url().to_file_path() {
+            Ok(path) => path,
+            Err(()) => return Ok(None),
+        },
+        Err(file_chooser::Error::Cancelled) => return Ok(None),
+        Err(e) => return Err(host::Error::Failed(e.to_string())),
+    };
+    let contents = tokio::fs::read(&path).await.map_err(|e| host::read_error(&path, &e))?;
+    let mut baseline: Baseline = serde_json::from_slice(&contents)
+        .map_err(|e| host::Error::Failed(format!("{}: {e}", path.display())))?;
+    if baseline.name.is_none() {
+        baseline.name = path.file_stem().map(|stem| stem.to_string_lossy().into_owned());
+    }
+    Ok(Some(baseline))
+}
@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Privileged helper for Examine.
+//!
+//! The GUI runs this binary through `pkexec` whenever it needs data that only
+//! root can read, or to change a battery care setting. It deliberately exposes
+//! a small, fixed set of operations, which only write values they have
+//! validated to attributes they locate themselves, so that packagers can audit
+//! the entire privileged surface here. Writes are prefixed with `write`, which
+//! polkit maps to an action that asks for the password every time.
+//! Uploading hardware probes is left to `examine-probe`, which has a polkit
+//! action of its own.
+
+use std::fs;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::process::CommandExt;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+/// `PATH` used for every spawned tool, independent of the caller's environment.
+const SAFE_PATH: &str = "/usr/sbin:/usr/bin:/sbin:/bin";
+
+/// Seconds a tool may run before `timeout` stops it, so that a hung
+/// dmidecode or smartctl does not keep the GUI waiting forever.
+const TOOL_TIMEOUT: &str = "30";
+
+const USAGE: &str = "usage: examine-helper dmi | smart <block device> | nvme <block device> | ipmi mc|sdr|fru \
+                     | write charge-limit <battery> <percent> | write conservation-mode 0|1";
+
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+/// The driver of Lenovo IdeaPad laptops, whose device has `conservation_mode`.
+const IDEAPAD_DIR: &str = "/sys/bus/platform/drivers/ideapad_acpi";
+
+/// The operations this helper is willing to perform.
+enum Operation<'a> {
+    /// Dump the full DMI table.
+    Dmi,
+    /// Read SMART data from a block device under `/dev`.
+    Smart(&'a Path),
+    /// Read the SMART / health log of an NVMe device with nvme-cli.
+    Nvme(&'a Path),
+    /// Read the BMC's `mc info`, sensor records or FRU inventory.
+    Ipmi(&'static str),
+    /// Write a charge limit in percent to a battery's `charge_control_end_threshold`.
+    ChargeLimit(PathBuf, u8),
+    /// Turn the IdeaPad battery conservation mode on or off.
+    ConservationMode(PathBuf, bool),
+}
+
+impl<'a> Operation<'a> {
+    fn parse(args: &'a [String]) -> Option<Self> {
+        match args {
+            [op, args @ ..] if op == "write" => Self::parse_write(args),
+            [op] if op == "dmi" => Some(Self::Dmi),
+            [op, device] if op == "smart" && is_block_device(Path::new(device)) => {
+                Some(Self::Smart(Path::new(device)))
+            }
+            [op, device] if op == "nvme" && is_block_device(Path::new(device)) => {
+                Some(Self::Nvme(Path::new(device)))
+            }
+            [op, query] if op == "ipmi" => match query.as_str() {
+                "mc" => Some(Self::Ipmi("mc")),
+                "sdr" => Some(Self::Ipmi("sdr")),
+                "fru" => Some(Self::Ipmi("fru")),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn parse_write(args: &'a [String]) -> Option<Self> {
+        match args {
+            [op, battery, limit] if op == "charge-limit" => {
+                let limit = limit.parse().ok().filter(|limit| (1..=100).contains(limit))?;
+                Some(Self::ChargeLimit(charge_limit_path(battery)?, limit))
+            }
+            [op, enabled] if op == "conservation-mode" => {
+                let enabled = match enabled.as_str() {
+                    "0" => false,
+                    "1" => true,
+                    _ => return None,
+                };
+                Some(Self::ConservationMode(conservation_mode_path()?, enabled))
+            }
+            _ => None,
+        }
+    }
+
+    /// The sysfs attribute and value a write operation sets, `None` for the
+    /// operations that run a tool.
+    fn write(&self) -> Option<(&Path, String)> {
+        match self {
+            Self::ChargeLimit(path, limit) => Some((path, limit.to_string())),
+            Self::ConservationMode(path, enabled) => Some((path, u8::from(*enabled).to_string())),
+            _ => None,
+        }
+    }
+
+    fn command(&self) -> Command {
+        // Exits with 124 when the tool runs too long, and kills it outright
+        // if it ignores the first signal.
+        let mut command = Command::new("timeout");
+        command.args(["--kill-after=5", TOOL_TIMEOUT]);
+        match self {
+            Self::Dmi => command.arg("dmidecode"),
+            Self::Smart(device) => command.args(["smartctl", "--all"]).arg(device),
+            Self::Nvme(device) => command.args(["nvme", "smart-log"]).arg(device),
+            Self::Ipmi("mc") => command.args(["ipmitool", "mc", "info"]),
+            Self::Ipmi("fru") => command.args(["ipmitool", "fru", "print"]),
+            Self::Ipmi(query) => command.args(["ipmitool", *query]),
+            Self::ChargeLimit(..) | Self::ConservationMode(..) => unreachable!("written directly"),
+        };
+        command.env_clear().env("PATH", SAFE_PATH);
+        command
+    }
+}
+
+/// Only accept canonical paths to block devices in `/dev`, rejecting symlinks and `..`.
+fn is_block_device(path: &Path) -> bool {
+    let Ok(canonical) = path.canonicalize() else {
+        return false;
+    };
+
+    canonical.starts_with("/dev")
+        && canonical == path
+        && canonical
+            .metadata()
+            .is_ok_and(|metadata| metadata.file_type().is_block_device())
+}
+
+/// The charge limit attribute of the battery called `name`, which must be a
+/// plain directory name of an existing battery.
+fn charge_limit_path(name: &str) -> Option<PathBuf> {
+    let mut components = Path::new(name).components();
+    if !matches!((components.next(), components.next()), (Some(Component::Normal(_)), None)) {
+        return None;
+    }
+    let battery = Path::new(POWER_SUPPLY_DIR).join(name);
+    if fs::read_to_string(battery.join("type")).ok()?.trim() != "Battery" {
+        return None;
+    }
+    Some(battery.join("charge_control_end_threshold")).filter(|path| path.is_file())
+}
+
+/// The conservation mode attribute of the ideapad_acpi device, if there is one.
+fn conservation_mode_path() -> Option<PathBuf> {
+    fs::read_dir(IDEAPAD_DIR)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join("conservation_mode"))
+        .find(|path| path.is_file())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let Some(operation) = Operation::parse(&args) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    if let Some((path, value)) = operation.write() {
+        return match fs::write(path, value) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("examine-helper: {}: {err}", path.display());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    // `exec` only returns on failure.
+    let err = operation.command().exec();
+    eprintln!("examine-helper: {err}");
+    ExitCode::FAILURE
+}
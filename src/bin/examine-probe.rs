@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Hardware probe uploader for Examine.
+//!
+//! The GUI runs this binary through `pkexec` after the user confirmed that a
+//! hardware probe may be uploaded to linux-hardware.org. It is kept apart
+//! from the read-only `examine-helper` so that it has its own polkit action,
+//! which asks for authentication every time: an authorization cached from
+//! reading the DMI tables or disk health must never allow an upload.
+
+use std::os::unix::process::CommandExt;
+use std::process::{Command, ExitCode};
+
+/// `PATH` used for hw-probe, independent of the caller's environment.
+const SAFE_PATH: &str = "/usr/sbin:/usr/bin:/sbin:/bin";
+
+/// Seconds hw-probe may take to collect its logs and upload them.
+const PROBE_TIMEOUT: &str = "600";
+
+fn main() -> ExitCode {
+    if std::env::args().len() > 1 {
+        eprintln!("usage: examine-probe");
+        return ExitCode::from(2);
+    }
+
+    // Exits with 124 when hw-probe runs too long, and kills it outright if it
+    // ignores the first signal.
+    let err = Command::new("timeout")
+        .args(["--kill-after=5", PROBE_TIMEOUT, "hw-probe", "-all", "-upload"])
+        .env_clear()
+        .env("PATH", SAFE_PATH)
+        .exec();
+    eprintln!("examine-probe: {err}");
+    ExitCode::FAILURE
+}
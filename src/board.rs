@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Identifies the motherboard from the DMI tables in `/sys/class/dmi/id`, or
+//! from the device tree on ARM and RISC-V boards that have no DMI.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+pub const DMI_DIR: &str = "/sys/class/dmi/id";
+pub const DEVICE_TREE_DIR: &str = "/proc/device-tree";
+pub const SOC_DIR: &str = "/sys/devices/soc0";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Dmi {
+    pub system_vendor: Option<String>,
+    pub product_name: Option<String>,
+    pub product_version: Option<String>,
+    pub board_vendor: Option<String>,
+    pub board_name: Option<String>,
+    pub board_version: Option<String>,
+    pub chassis_vendor: Option<String>,
+    pub chassis_type: Option<String>,
+    pub product_family: Option<String>,
+    pub product_sku: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    pub bios_date: Option<String>,
+    /// The BIOS release as `major.minor`, when the firmware reports one.
+    pub bios_release: Option<String>,
+}
+
+/// The system-on-chip as described by the kernel's soc bus.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Soc {
+    pub family: Option<String>,
+    pub machine: Option<String>,
+    pub soc_id: Option<String>,
+    pub revision: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeviceTree {
+    pub model: Option<String>,
+    /// Most specific first, e.g. `raspberrypi,5-model-b` then `brcm,bcm2712`.
+    pub compatible: Vec<String>,
+    pub serial_number: Option<String>,
+    pub soc: Option<Soc>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Board {
+    pub dmi: Option<Dmi>,
+    pub device_tree: Option<DeviceTree>,
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        // Vendors fill unused DMI strings with placeholders.
+        .filter(|value| !value.is_empty() && value != "Default string" && value != "To Be Filled By O.E.M.")
+}
+
+/// Device tree properties are NUL terminated, and string lists NUL separated.
+fn read_strings(path: impl AsRef<Path>) -> Vec<String> {
+    fs::read(path)
+        .map(|bytes| {
+            bytes
+                .split(|byte| *byte == 0)
+                .filter(|value| !value.is_empty())
+                .map(|value| String::from_utf8_lossy(value).into_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Names for the SMBIOS chassis types that show up on desktops and laptops.
+fn chassis_name(chassis_type: &str) -> Option<String> {
+    let name = match chassis_type.parse::<u8>().ok()? {
+        3 => "Desktop",
+        4 => "Low Profile Desktop",
+        6 => "Mini Tower",
+        7 => "Tower",
+        8 => "Portable",
+        9 => "Laptop",
+        10 => "Notebook",
+        13 => "All in One",
+        14 => "Sub Notebook",
+        17 => "Main Server Chassis",
+        23 => "Rack Mount Chassis",
+        30 => "Tablet",
+        31 => "Convertible",
+        32 => "Detachable",
+        35 => "Mini PC",
+        36 => "Stick PC",
+        _ => return Some(chassis_type.to_string()),
+    };
+    Some(name.to_string())
+}
+
+fn collect_dmi() -> Option<Dmi> {
+    let dir = host::path(DMI_DIR);
+    if !dir.exists() {
+        return None;
+    }
+
+    Some(Dmi {
+        system_vendor: read_trimmed(dir.join("sys_vendor")),
+        product_name: read_trimmed(dir.join("product_name")),
+        product_version: read_trimmed(dir.join("product_version")),
+        board_vendor: read_trimmed(dir.join("board_vendor")),
+        board_name: read_trimmed(dir.join("board_name")),
+        board_version: read_trimmed(dir.join("board_version")),
+        chassis_vendor: read_trimmed(dir.join("chassis_vendor")),
+        chassis_type: read_trimmed(dir.join("chassis_type")).and_then(|value| chassis_name(&value)),
+        product_family: read_trimmed(dir.join("product_family")),
+        product_sku: read_trimmed(dir.join("product_sku")),
+        bios_vendor: read_trimmed(dir.join("bios_vendor")),
+        bios_version: read_trimmed(dir.join("bios_version")),
+        bios_date: read_trimmed(dir.join("bios_date")),
+        bios_release: read_trimmed(dir.join("bios_release")),
+    })
+}
+
+fn collect_device_tree() -> Option<DeviceTree> {
+    let dir = host::path(DEVICE_TREE_DIR);
+    if !dir.exists() {
+        return None;
+    }
+
+    let soc = host::path(SOC_DIR);
+    let soc = soc.exists().then(|| Soc {
+        family: read_trimmed(soc.join("family")),
+        machine: read_trimmed(soc.join("machine")),
+        soc_id: read_trimmed(soc.join("soc_id")),
+        revision: read_trimmed(soc.join("revision")),
+    });
+
+    Some(DeviceTree {
+        model: read_strings(dir.join("model")).into_iter().next(),
+        compatible: read_strings(dir.join("compatible")),
+        serial_number: read_strings(dir.join("serial-number")).into_iter().next(),
+        soc,
+    })
+}
+
+pub fn collect() -> Board {
+    Board {
+        dmi: collect_dmi(),
+        device_tree: collect_device_tree(),
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Parses boot timing from `systemd-analyze` and `systemd-analyze blame`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+/// How many of the slowest units to keep.
+const SLOWEST_UNITS: usize = 20;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Phase {
+    /// `firmware`, `loader`, `kernel`, `initrd` or `userspace`.
+    pub name: String,
+    pub duration: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Unit {
+    pub name: String,
+    pub duration: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Boot {
+    pub phases: Vec<Phase>,
+    pub total: Option<String>,
+    /// E.g. `graphical.target reached after 9.000s in userspace`.
+    pub target: Option<String>,
+    pub slowest: Vec<Unit>,
+}
+
+fn run(args: &[&str]) -> Result<String, host::Error> {
+    let output = host::output(host::command("systemd-analyze").args(args))
+        .map_err(|e| host::spawn_error("systemd-analyze", &e))?;
+    if !output.status.success() {
+        return Err(host::Error::Failed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses `Startup finished in 7.5s (firmware) + 3.1s (loader) + … = 24.0s`.
+fn parse_time(output: &str) -> (Vec<Phase>, Option<String>, Option<String>) {
+    let mut lines = output.lines();
+    let Some(summary) = lines.next().and_then(|line| line.strip_prefix("Startup finished in ")) else {
+        return (Vec::new(), None, None);
+    };
+
+    let (phases, total) = match summary.rsplit_once(" = ") {
+        Some((phases, total)) => (phases, Some(total.trim().to_string())),
+        None => (summary, None),
+    };
+    let phases = phases
+        .split(" + ")
+        .filter_map(|phase| {
+            let (duration, name) = phase.trim().rsplit_once(" (")?;
+            Some(Phase {
+                name: name.trim_end_matches(')').to_string(),
+                duration: duration.to_string(),
+            })
+        })
+        .collect();
+    let target = lines.next().map(|line| line.trim().trim_end_matches('.').to_string());
+
+    (phases, total, target)
+}
+
+/// Parses `systemd-analyze blame`, whose durations may span several words
+/// such as `1min 2.345s`.
+fn parse_blame(output: &str) -> Vec<Unit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (duration, name) = line.trim().rsplit_once(' ')?;
+            Some(Unit {
+                name: name.to_string(),
+                duration: duration.trim().to_string(),
+            })
+        })
+        .take(SLOWEST_UNITS)
+        .collect()
+}
+
+pub fn collect() -> Result<Boot, host::Error> {
+    let (phases, total, target) = parse_time(&run(&[])?);
+    let slowest = run(&["blame"]).map(|output| parse_blame(&output)).unwrap_or_default();
+
+    Ok(Boot {
+        phases,
+        total,
+        target,
+        slowest,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIME: &str = "Startup finished in 7.512s (firmware) + 3.105s (loader) + 1.870s (kernel) + 2.511s (initrd) + 9.005s (userspace) = 24.005s
+graphical.target reached after 9.000s in userspace.
+";
+
+    const BLAME: &str = "1min 2.345s plymouth-quit-wait.service
+     5.123s NetworkManager-wait-online.service
+      812ms systemd-udev-settle.service
+";
+
+    #[test]
+    fn parses_phases() {
+        let (phases, total, target) = parse_time(TIME);
+        let names = phases.iter().map(|phase| phase.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["firmware", "loader", "kernel", "initrd", "userspace"]);
+        assert_eq!(phases[0].duration, "7.512s");
+        assert_eq!(total.as_deref(), Some("24.005s"));
+        assert_eq!(target.as_deref(), Some("graphical.target reached after 9.000s in userspace"));
+    }
+
+    #[test]
+    fn parses_blame() {
+        let units = parse_blame(BLAME);
+        assert_eq!(units.len(), 3);
+        assert_eq!(units[0].name, "plymouth-quit-wait.service");
+        assert_eq!(units[0].duration, "1min 2.345s");
+        assert_eq!(units[2].duration, "812ms");
+
+        let many = "1s unit.service\n".repeat(SLOWEST_UNITS + 5);
+        assert_eq!(parse_blame(&many).len(), SLOWEST_UNITS);
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        let (phases, total, target) = parse_time("");
+        assert!(phases.is_empty() && total.is_none() && target.is_none());
+
+        // Boot is still in progress.
+        let (phases, total, _) = parse_time("Bootup is not yet finished.\n");
+        assert!(phases.is_empty() && total.is_none());
+
+        let (phases, total, target) = parse_time("Startup finished in 1.0s + 2.0s (kernel)");
+        assert_eq!(phases.len(), 1);
+        assert_eq!(total, None);
+        assert_eq!(target, None);
+
+        assert!(parse_blame("\nnospace\n").is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Enumerates V4L2 video devices from sysfs and queries their formats with `v4l2-ctl`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const VIDEO4LINUX_DIR: &str = "/sys/class/video4linux";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PixelFormat {
+    pub fourcc: String,
+    pub description: String,
+    pub resolutions: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Camera {
+    pub device: String,
+    pub name: String,
+    pub driver: Option<String>,
+    pub bus_info: Option<String>,
+    /// Whether the node can capture video, as opposed to e.g. a metadata node.
+    pub capture: bool,
+    pub formats: Result<Vec<PixelFormat>, String>,
+}
+
+#[derive(Default)]
+struct Query {
+    bus_info: Option<String>,
+    capture: bool,
+    formats: Vec<PixelFormat>,
+}
+
+/// Parses `v4l2-ctl --info --list-formats-ext`.
+fn parse(output: &str) -> Query {
+    let mut query = Query::default();
+    let mut in_device_caps = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some(caps) = trimmed.strip_prefix("Device Caps") {
+            in_device_caps = caps.trim_start().starts_with(':');
+            continue;
+        }
+        if in_device_caps {
+            if line.starts_with("\t\t") {
+                query.capture |= trimmed == "Video Capture" || trimmed == "Video Capture Multiplanar";
+                continue;
+            }
+            in_device_caps = false;
+        }
+
+        if let Some(bus_info) = trimmed.strip_prefix("Bus info") {
+            query.bus_info = Some(bus_info.trim_start_matches([' ', ':']).to_string());
+        } else if trimmed.starts_with('[') {
+            // [0]: 'MJPG' (Motion-JPEG, compressed)
+            let Some((_, format)) = trimmed.split_once(": ") else {
+                continue;
+            };
+            let (fourcc, description) = format.split_once(' ').unwrap_or((format, ""));
+            query.formats.push(PixelFormat {
+                fourcc: fourcc.trim_matches('\'').to_string(),
+                description: description.trim_matches(['(', ')']).to_string(),
+                resolutions: Vec::new(),
+            });
+        } else if let Some(size) = trimmed.strip_prefix("Size: ") {
+            if let Some(format) = query.formats.last_mut() {
+                let size = size.rsplit(' ').next().unwrap_or(size).to_string();
+                if !format.resolutions.contains(&size) {
+                    format.resolutions.push(size);
+                }
+            }
+        }
+    }
+
+    query
+}
+
+pub fn collect() -> Vec<Camera> {
+    let mut cameras = fs::read_dir(host::path(VIDEO4LINUX_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("video"))
+        .map(|entry| {
+            let path = entry.path();
+            let device = Path::new("/dev").join(entry.file_name()).display().to_string();
+            let driver = fs::read_link(path.join("device/driver"))
+                .ok()
+                .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned()));
+
+            let query = host::output(host::command("v4l2-ctl").args([
+                "--device",
+                &device,
+                "--info",
+                "--list-formats-ext",
+            ]))
+                .map_err(|e| host::spawn_error("v4l2-ctl", &e).to_string())
+                .map(|output| parse(&String::from_utf8_lossy(&output.stdout)));
+
+            let (bus_info, capture, formats) = match query {
+                Ok(query) => (query.bus_info, query.capture, Ok(query.formats)),
+                Err(e) => (None, true, Err(e)),
+            };
+
+            Camera {
+                name: fs::read_to_string(path.join("name"))
+                    .map(|name| name.trim().to_string())
+                    .unwrap_or_default(),
+                device,
+                driver,
+                bus_info,
+                capture,
+                formats,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    cameras.sort_by(|a, b| a.device.cmp(&b.device));
+    cameras
+}
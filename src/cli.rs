@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The command line mode: `examine --page processor --format json` prints the
+//! data of one page and exits. The data is collected by the same code as the
+//! app's and rendered by the same exporters.
+
+use crate::app::AppModel;
+use crate::export::{self, Format};
+use crate::fl;
+use crate::pages::{self, Page};
+
+/// Prints the page named by `--page` in the format named by `--format`,
+/// plain text by default.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut page = None;
+    let mut format = Format::Text;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--page" => {
+                let id = args.next().ok_or_else(|| fl!("cli-missing-value", option = "--page"))?;
+                let ids = pages::PAGES.iter().map(|provider| provider.id()).collect::<Vec<_>>();
+                page = Some(
+                    Page::from_id(id).ok_or_else(|| fl!("cli-unknown-page", page = id.as_str(), pages = ids.join(", ")))?,
+                );
+            }
+            "--format" => {
+                let name = args.next().ok_or_else(|| fl!("cli-missing-value", option = "--format"))?;
+                format = Format::from_name(name).ok_or_else(|| fl!("cli-unknown-format", format = name.as_str()))?;
+            }
+            _ => {}
+        }
+    }
+    let page = page.ok_or_else(|| fl!("cli-missing-value", option = "--page"))?;
+
+    let data = AppModel::headless(page).export_data(page);
+    println!("{}", export::document(format, &[data], None).trim_end());
+    Ok(())
+}
@@ -1,9 +1,131 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use log::error;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
+use crate::host;
+use crate::pages::{self, Page};
+
+#[derive(Debug, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
     demo: String,
+    pub status_palette: StatusPalette,
+    /// Seconds an external tool may run before it is stopped.
+    pub command_timeout: u64,
+    /// Seconds between automatic refreshes of pages with changing data, 0 to disable.
+    pub auto_refresh: u64,
+    /// Whether exports append the verbatim output of the tools behind the page.
+    pub export_raw_output: bool,
+    /// When to record a snapshot into the history.
+    pub snapshot_schedule: SnapshotSchedule,
+    /// Whether sizes are shown in powers of 1024 or of 1000.
+    pub byte_units: ByteUnits,
+    /// Whether exports, reports and copied pages leave out values that
+    /// identify the machine, such as serial numbers, so they can be shared.
+    pub redact_exports: bool,
+    /// The ids of the pages left out of the nav bar.
+    pub hidden_pages: Vec<String>,
+    /// The ids of the pages in the order the user arranged them in the nav
+    /// bar, empty for the default order.
+    pub page_order: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            demo: String::new(),
+            status_palette: StatusPalette::default(),
+            command_timeout: host::DEFAULT_TIMEOUT.as_secs(),
+            auto_refresh: 0,
+            export_raw_output: false,
+            snapshot_schedule: SnapshotSchedule::default(),
+            byte_units: ByteUnits::default(),
+            redact_exports: false,
+            hidden_pages: Vec::new(),
+            page_order: Vec::new(),
+        }
+    }
+}
+
+/// How severity indicators are drawn.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StatusPalette {
+    /// The icon theme's status icons.
+    #[default]
+    Standard,
+    /// Okabe–Ito colors that stay distinguishable with color vision
+    /// deficiencies, each paired with a distinct shape.
+    ColorBlind,
+}
+
+/// When snapshots are recorded into the history without being asked for.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SnapshotSchedule {
+    #[default]
+    Off,
+    /// On every launch.
+    Launch,
+    /// On the first launch of each day.
+    Daily,
+}
+
+impl SnapshotSchedule {
+    /// Every schedule, in menu order.
+    pub const ALL: &'static [SnapshotSchedule] = &[Self::Off, Self::Launch, Self::Daily];
+}
+
+/// The units sizes are shown in.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ByteUnits {
+    /// KiB, MiB and GiB, as the kernel and most tools count.
+    #[default]
+    Binary,
+    /// kB, MB and GB, as disks and memory sticks are sold.
+    Decimal,
+}
+
+impl ByteUnits {
+    /// Every unit system, in settings order.
+    pub const ALL: &'static [ByteUnits] = &[Self::Binary, Self::Decimal];
+}
+
+impl Config {
+    /// Whether the user hid `page` from the nav bar.
+    pub fn is_hidden(&self, page: Page) -> bool {
+        self.hidden_pages.iter().any(|id| Page::from_id(id) == Some(page))
+    }
+
+    /// Every page in the order the user arranged them. Pages missing from
+    /// [`Self::page_order`], such as those added by a newer version, follow
+    /// in registry order.
+    pub fn ordered_pages(&self) -> Vec<Page> {
+        let mut pages = pages::PAGES.iter().map(|provider| provider.page()).collect::<Vec<_>>();
+        pages.sort_by_key(|page| {
+            self.page_order
+                .iter()
+                .position(|id| Page::from_id(id) == Some(*page))
+                .unwrap_or(usize::MAX)
+        });
+        pages
+    }
+
+    /// Opens the config. Keys that cannot be read fall back to their defaults.
+    pub fn load(app_id: &str) -> (Option<cosmic_config::Config>, Self) {
+        let handler = match cosmic_config::Config::new(app_id, Self::VERSION) {
+            Ok(handler) => handler,
+            Err(e) => {
+                error!("failed to open config: {}", e);
+                return (None, Self::default());
+            }
+        };
+
+        let config = match Self::get_entry(&handler) {
+            Ok(config) => config,
+            Err((_errors, config)) => config,
+        };
+
+        (Some(handler), config)
+    }
 }
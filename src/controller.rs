@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Enumerates joysticks and gamepads from the evdev capabilities in `/sys/class/input`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const INPUT_DIR: &str = "/sys/class/input";
+
+/// `BTN_JOYSTICK` through the end of the `BTN_GAMEPAD` range.
+const JOYSTICK_BUTTONS: std::ops::Range<usize> = 0x120..0x140;
+/// `BTN_TRIGGER_HAPPY1` through `BTN_TRIGGER_HAPPY40`.
+const TRIGGER_HAPPY_BUTTONS: std::ops::Range<usize> = 0x2c0..0x2e8;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Controller {
+    pub name: String,
+    pub vendor_id: Option<String>,
+    pub product_id: Option<String>,
+    /// Event and joystick device nodes, e.g. `event12` and `js0`.
+    pub nodes: Vec<String>,
+    pub axes: usize,
+    pub buttons: usize,
+    pub force_feedback: bool,
+}
+
+/// Parses a sysfs capability bitmap: space separated hexadecimal words, most
+/// significant first, each `usize::BITS` wide.
+fn bitmap(value: &str) -> Vec<usize> {
+    value
+        .split_whitespace()
+        .rev()
+        .enumerate()
+        .flat_map(|(index, word)| {
+            let word = usize::from_str_radix(word, 16).unwrap_or_default();
+            (0..usize::BITS as usize)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| index * usize::BITS as usize + bit)
+        })
+        .collect()
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+pub fn collect() -> Vec<Controller> {
+    let mut controllers = fs::read_dir(host::path(INPUT_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("input"))
+        .filter_map(|entry| {
+            let path = entry.path();
+            let keys = bitmap(&read_trimmed(path.join("capabilities/key")).unwrap_or_default());
+            let buttons = keys
+                .iter()
+                .filter(|key| JOYSTICK_BUTTONS.contains(key) || TRIGGER_HAPPY_BUTTONS.contains(key))
+                .count();
+            let nodes = fs::read_dir(&path)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|child| child.file_name().to_string_lossy().into_owned())
+                .filter(|name| name.starts_with("event") || name.starts_with("js"))
+                .collect::<Vec<_>>();
+
+            // The kernel only creates a jsN node for joystick-like devices,
+            // but fall back to the button ranges for evdev-only setups.
+            if buttons == 0 && !nodes.iter().any(|node| node.starts_with("js")) {
+                return None;
+            }
+
+            let ff = read_trimmed(path.join("capabilities/ff")).unwrap_or_default();
+            Some(Controller {
+                name: read_trimmed(path.join("name")).unwrap_or_default(),
+                vendor_id: read_trimmed(path.join("id/vendor")),
+                product_id: read_trimmed(path.join("id/product")),
+                nodes,
+                axes: bitmap(&read_trimmed(path.join("capabilities/abs")).unwrap_or_default()).len(),
+                buttons,
+                force_feedback: !bitmap(&ff).is_empty(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    controllers.sort_by(|a, b| a.name.cmp(&b.name));
+    controllers
+}
@@ -0,0 +1,536 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads processor details from `/proc/cpuinfo` and `/sys/devices/system/cpu`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+pub const CPU_DIR: &str = "/sys/devices/system/cpu";
+const VULNERABILITIES_DIR: &str = "/sys/devices/system/cpu/vulnerabilities";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Exposure {
+    NotAffected,
+    Mitigated,
+    Vulnerable,
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Vulnerability {
+    /// The sysfs name, e.g. `spectre_v2`.
+    pub name: String,
+    /// The kernel's description, e.g. `Mitigation: Enhanced IBRS`.
+    pub status: String,
+    pub exposure: Exposure,
+}
+
+impl Vulnerability {
+    /// The name as it is usually written.
+    pub fn display_name(&self) -> String {
+        let name = match self.name.as_str() {
+            "meltdown" => "Meltdown",
+            "spectre_v1" => "Spectre v1",
+            "spectre_v2" => "Spectre v2",
+            "spec_store_bypass" => "Speculative Store Bypass",
+            "spec_rstack_overflow" => "Speculative Return Stack Overflow",
+            "l1tf" => "L1 Terminal Fault",
+            "mds" => "Microarchitectural Data Sampling",
+            "tsx_async_abort" => "TSX Asynchronous Abort",
+            "itlb_multihit" => "iTLB Multihit",
+            "srbds" => "Special Register Buffer Data Sampling",
+            "mmio_stale_data" => "MMIO Stale Data",
+            "retbleed" => "Retbleed",
+            "gather_data_sampling" => "Gather Data Sampling (Downfall)",
+            "reg_file_data_sampling" => "Register File Data Sampling",
+            "indirect_target_selection" => "Indirect Target Selection",
+            "tsa" => "Transient Scheduler Attacks",
+            "ghostwrite" => "GhostWrite",
+            other => return other.replace('_', " "),
+        };
+        name.to_string()
+    }
+}
+
+/// The processor model, read from the first entry of `/proc/cpuinfo`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Info {
+    pub model_name: Option<String>,
+    pub vendor: Option<String>,
+    pub family: Option<String>,
+    pub model: Option<String>,
+    pub stepping: Option<String>,
+    pub bogomips: Option<String>,
+    /// Instruction set extensions, `flags` on x86 and `Features` on ARM.
+    pub flags: Vec<String>,
+}
+
+impl Info {
+    /// The hardware virtualization extension, if the processor has one.
+    pub fn virtualization(&self) -> Option<&'static str> {
+        if self.flags.iter().any(|flag| flag == "vmx") {
+            Some("VT-x")
+        } else if self.flags.iter().any(|flag| flag == "svm") {
+            Some("AMD-V")
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the processor model from `/proc/cpuinfo`.
+pub fn info() -> Result<Info, host::Error> {
+    let cpuinfo =
+        fs::read_to_string(host::path("/proc/cpuinfo")).map_err(|e| host::read_error("/proc/cpuinfo", &e))?;
+    Ok(parse_info(&cpuinfo))
+}
+
+/// Parses `/proc/cpuinfo`. All processors in a system are normally
+/// identical, so only the first entry is used.
+fn parse_info(cpuinfo: &str) -> Info {
+    let mut info = Info::default();
+
+    for line in cpuinfo.lines() {
+        // Entries are separated by blank lines, but ARM lists shared fields
+        // such as `Hardware` after the last one, so keep reading unset fields.
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let field = match key.trim() {
+            "model name" | "Processor" | "cpu model" | "uarch" => &mut info.model_name,
+            "vendor_id" | "CPU implementer" | "mvendorid" => &mut info.vendor,
+            "cpu family" | "CPU architecture" => &mut info.family,
+            "model" | "CPU part" => &mut info.model,
+            "stepping" | "CPU revision" => &mut info.stepping,
+            "bogomips" | "BogoMIPS" => &mut info.bogomips,
+            "flags" | "Features" | "isa" if info.flags.is_empty() => {
+                info.flags = value.split_whitespace().map(str::to_string).collect();
+                continue;
+            }
+            _ => continue,
+        };
+        if field.is_none() && !value.is_empty() {
+            *field = Some(value.to_string());
+        }
+    }
+    info
+}
+
+/// One level of the cache hierarchy, merged across all CPUs.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Cache {
+    pub level: u8,
+    /// `Data`, `Instruction` or `Unified`.
+    pub kind: String,
+    /// Size of a single instance in KiB.
+    pub size_kib: Option<u64>,
+    pub ways: Option<u32>,
+    pub line_size: Option<u32>,
+    /// How many separate caches exist at this level.
+    pub instances: usize,
+    /// How many logical CPUs share each instance.
+    pub shared_by: usize,
+}
+
+impl Cache {
+    /// The conventional name, e.g. `L1d` or `L3`.
+    pub fn name(&self) -> String {
+        match self.kind.as_str() {
+            "Data" => format!("L{}d", self.level),
+            "Instruction" => format!("L{}i", self.level),
+            _ => format!("L{}", self.level),
+        }
+    }
+
+    /// The per-instance size, e.g. `48 KiB` or `32 MiB`.
+    pub fn size(&self) -> Option<String> {
+        self.size_kib.map(|kib| {
+            if kib >= 1024 && kib % 1024 == 0 {
+                format!("{} MiB", kib / 1024)
+            } else {
+                format!("{kib} KiB")
+            }
+        })
+    }
+}
+
+/// Counts the CPUs in a list such as `0-3,8-11`.
+fn count_cpus(list: &str) -> usize {
+    list.split(',')
+        .filter_map(|range| match range.split_once('-') {
+            Some((start, end)) => Some(end.parse::<usize>().ok()?.checked_sub(start.parse().ok()?)? + 1),
+            None => range.parse::<usize>().ok().map(|_| 1),
+        })
+        .sum()
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|value| value.trim().to_string())
+}
+
+/// Reads the cache hierarchy from the `cache/index*` directories of every CPU.
+pub fn caches() -> Vec<Cache> {
+    let mut levels = BTreeMap::<(u8, String), (Cache, BTreeSet<String>)>::new();
+
+    let cpus = fs::read_dir(host::path(CPU_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.strip_prefix("cpu").is_some_and(|id| id.parse::<u32>().is_ok())
+        });
+
+    for cpu in cpus {
+        let indices = fs::read_dir(cpu.path().join("cache")).into_iter().flatten().filter_map(Result::ok);
+        for index in indices {
+            let path = index.path();
+            let (Some(level), Some(kind), Some(shared)) = (
+                read_trimmed(&path.join("level")).and_then(|level| level.parse::<u8>().ok()),
+                read_trimmed(&path.join("type")),
+                read_trimmed(&path.join("shared_cpu_list")),
+            ) else {
+                continue;
+            };
+
+            let (_, instances) = levels.entry((level, kind.clone())).or_insert_with(|| {
+                let cache = Cache {
+                    level,
+                    size_kib: read_trimmed(&path.join("size"))
+                        .and_then(|size| size.trim_end_matches('K').parse().ok()),
+                    ways: read_trimmed(&path.join("ways_of_associativity")).and_then(|ways| ways.parse().ok()),
+                    line_size: read_trimmed(&path.join("coherency_line_size")).and_then(|size| size.parse().ok()),
+                    instances: 0,
+                    shared_by: count_cpus(&shared),
+                    kind,
+                };
+                (cache, BTreeSet::new())
+            });
+            instances.insert(shared);
+        }
+    }
+
+    levels
+        .into_values()
+        .map(|(mut cache, instances)| {
+            cache.instances = instances.len();
+            cache
+        })
+        .collect()
+}
+
+/// Runtime processor state that affects mitigations and performance.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct State {
+    /// The loaded microcode revision, e.g. `0xb4`.
+    pub microcode: Option<String>,
+    /// The SMT control state: `on`, `off`, `forceoff`, `notsupported` or `notimplemented`.
+    pub smt_control: Option<String>,
+    pub smt_active: Option<bool>,
+    pub scaling_driver: Option<String>,
+    pub governor: Option<String>,
+    pub boost: Option<bool>,
+}
+
+/// Reads the microcode revision, SMT control, frequency governor and boost state.
+pub fn state() -> State {
+    let cpu0 = host::path(CPU_DIR).join("cpu0");
+    let microcode = read_trimmed(&cpu0.join("microcode/version")).or_else(|| {
+        fs::read_to_string(host::path("/proc/cpuinfo")).ok()?.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "microcode").then(|| value.trim().to_string())
+        })
+    });
+
+    // Generic cpufreq drivers expose `boost`; intel_pstate inverts it as `no_turbo`.
+    let boost = read_trimmed(&host::path(CPU_DIR).join("cpufreq/boost"))
+        .map(|boost| boost == "1")
+        .or_else(|| read_trimmed(&host::path(CPU_DIR).join("intel_pstate/no_turbo")).map(|no_turbo| no_turbo == "0"));
+
+    State {
+        microcode,
+        smt_control: read_trimmed(&host::path(CPU_DIR).join("smt/control")),
+        smt_active: read_trimmed(&host::path(CPU_DIR).join("smt/active")).map(|active| active == "1"),
+        scaling_driver: read_trimmed(&cpu0.join("cpufreq/scaling_driver")),
+        governor: read_trimmed(&cpu0.join("cpufreq/scaling_governor")),
+        boost,
+    }
+}
+
+/// A logical CPU and where it sits in the core, socket and NUMA topology.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LogicalCpu {
+    pub id: u32,
+    pub core: Option<u32>,
+    pub socket: Option<u32>,
+    pub node: Option<u32>,
+    pub online: bool,
+    pub min_mhz: Option<u32>,
+    pub max_mhz: Option<u32>,
+}
+
+/// Reads a cpufreq value, which the kernel reports in kHz, as MHz.
+fn read_mhz(path: &Path) -> Option<u32> {
+    read_trimmed(path)?.parse::<u32>().ok().map(|khz| khz / 1000)
+}
+
+/// Lists every logical CPU with its topology and frequency limits.
+pub fn logical_cpus() -> Vec<LogicalCpu> {
+    let mut cpus = fs::read_dir(host::path(CPU_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().strip_prefix("cpu")?.parse::<u32>().ok()?;
+            let path = entry.path();
+            let topology = path.join("topology");
+            let node = fs::read_dir(&path).into_iter().flatten().filter_map(Result::ok).find_map(|entry| {
+                entry.file_name().to_string_lossy().strip_prefix("node")?.parse::<u32>().ok()
+            });
+
+            Some(LogicalCpu {
+                id,
+                core: read_trimmed(&topology.join("core_id")).and_then(|core| core.parse().ok()),
+                socket: read_trimmed(&topology.join("physical_package_id")).and_then(|socket| socket.parse().ok()),
+                node,
+                // cpu0 usually cannot be taken offline and has no `online` file.
+                online: read_trimmed(&path.join("online")).map_or(true, |online| online == "1"),
+                min_mhz: read_mhz(&path.join("cpufreq/cpuinfo_min_freq")),
+                max_mhz: read_mhz(&path.join("cpufreq/cpuinfo_max_freq")),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    cpus.sort_by_key(|cpu| cpu.id);
+    cpus
+}
+
+/// Classifies the kernel's description of a vulnerability.
+fn exposure(status: &str) -> Exposure {
+    if status == "Not affected" {
+        Exposure::NotAffected
+    } else if status.starts_with("Mitigation") {
+        Exposure::Mitigated
+    } else if status.starts_with("Vulnerable") {
+        Exposure::Vulnerable
+    } else {
+        Exposure::Unknown
+    }
+}
+
+/// Lists the CPU vulnerabilities known to the kernel and their mitigation status.
+pub fn vulnerabilities() -> Vec<Vulnerability> {
+    let mut vulnerabilities = fs::read_dir(host::path(VULNERABILITIES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let status = fs::read_to_string(entry.path()).ok()?.trim().to_string();
+            Some(Vulnerability {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                exposure: exposure(&status),
+                status,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    vulnerabilities.sort_by(|a, b| a.name.cmp(&b.name));
+    vulnerabilities
+}
+
+/// Short descriptions of common `/proc/cpuinfo` flags.
+const FLAG_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("3dnowprefetch", "3DNow! prefetch instructions"),
+    ("abm", "Advanced bit manipulation (LZCNT, POPCNT)"),
+    ("adx", "Multi-precision add-carry instructions"),
+    ("aes", "AES-NI hardware encryption"),
+    ("amx_bf16", "Advanced Matrix Extensions, BFloat16"),
+    ("amx_int8", "Advanced Matrix Extensions, 8-bit integers"),
+    ("amx_tile", "Advanced Matrix Extensions tile registers"),
+    ("apic", "Advanced programmable interrupt controller"),
+    ("avx", "Advanced Vector Extensions"),
+    ("avx2", "Advanced Vector Extensions 2"),
+    ("avx512bw", "AVX-512 byte and word instructions"),
+    ("avx512cd", "AVX-512 conflict detection"),
+    ("avx512dq", "AVX-512 doubleword and quadword instructions"),
+    ("avx512f", "AVX-512 foundation"),
+    ("avx512vl", "AVX-512 vector length extensions"),
+    ("avx512_vnni", "AVX-512 vector neural network instructions"),
+    ("avx512_bf16", "AVX-512 BFloat16 instructions"),
+    ("avx_vnni", "AVX vector neural network instructions"),
+    ("bmi1", "Bit manipulation instructions 1"),
+    ("bmi2", "Bit manipulation instructions 2"),
+    ("clflushopt", "Optimized cache line flush"),
+    ("clwb", "Cache line write back"),
+    ("cmov", "Conditional move instructions"),
+    ("constant_tsc", "Time stamp counter runs at a constant rate"),
+    ("cx16", "16-byte compare and exchange (CMPXCHG16B)"),
+    ("cx8", "8-byte compare and exchange (CMPXCHG8B)"),
+    ("erms", "Enhanced REP MOVSB/STOSB"),
+    ("f16c", "Half-precision float conversion"),
+    ("fma", "Fused multiply-add (FMA3)"),
+    ("fma4", "Fused multiply-add with four operands"),
+    ("fpu", "Built-in floating point unit"),
+    ("fsrm", "Fast short REP MOV"),
+    ("ht", "Hyper-threading"),
+    ("hypervisor", "Running under a hypervisor"),
+    ("lm", "Long mode, 64-bit support"),
+    ("mmx", "MMX multimedia extensions"),
+    ("movbe", "Move data after swapping bytes"),
+    ("nx", "No-execute page protection"),
+    ("pae", "Physical address extension"),
+    ("pclmulqdq", "Carry-less multiplication, used by AES-GCM and CRC"),
+    ("pdpe1gb", "1 GB huge pages"),
+    ("pku", "Memory protection keys for user space"),
+    ("popcnt", "Population count instruction"),
+    ("rdrand", "Hardware random number generator"),
+    ("rdseed", "Hardware random seed generator"),
+    ("rdtscp", "Read time stamp counter and processor ID"),
+    ("sev", "AMD Secure Encrypted Virtualization"),
+    ("sgx", "Intel Software Guard Extensions"),
+    ("sha_ni", "SHA-1 and SHA-256 hardware acceleration"),
+    ("smap", "Supervisor mode access prevention"),
+    ("smep", "Supervisor mode execution protection"),
+    ("sme", "AMD Secure Memory Encryption"),
+    ("sse", "Streaming SIMD Extensions"),
+    ("sse2", "Streaming SIMD Extensions 2"),
+    ("sse3", "Streaming SIMD Extensions 3"),
+    ("sse4_1", "Streaming SIMD Extensions 4.1"),
+    ("sse4_2", "Streaming SIMD Extensions 4.2"),
+    ("sse4a", "AMD Streaming SIMD Extensions 4a"),
+    ("ssse3", "Supplemental Streaming SIMD Extensions 3"),
+    ("svm", "AMD-V hardware virtualization"),
+    ("tsc", "Time stamp counter"),
+    ("vaes", "Vectorized AES instructions"),
+    ("vmx", "Intel VT-x hardware virtualization"),
+    ("vpclmulqdq", "Vectorized carry-less multiplication"),
+    ("x2apic", "Extended APIC"),
+    ("xsave", "Save and restore extended processor state"),
+];
+
+/// A short description of a CPU flag, if it is a common one.
+pub fn flag_description(flag: &str) -> Option<&'static str> {
+    FLAG_DESCRIPTIONS
+        .iter()
+        .find(|(name, _)| *name == flag)
+        .map(|(_, description)| *description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X86: &str = "processor\t: 0
+vendor_id\t: GenuineIntel
+cpu family\t: 6
+model\t\t: 154
+model name\t: 12th Gen Intel(R) Core(TM) i7-1260P
+stepping\t: 3
+microcode\t: 0x432
+flags\t\t: fpu vme de pse tsc msr pae vmx avx2
+bogomips\t: 4992.00
+
+processor\t: 1
+vendor_id\t: GenuineIntel
+model name\t: 12th Gen Intel(R) Core(TM) i7-1260P
+flags\t\t: fpu
+";
+
+    const ARM: &str = "processor\t: 0
+BogoMIPS\t: 48.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32
+CPU implementer\t: 0x41
+CPU architecture: 8
+CPU variant\t: 0x0
+CPU part\t: 0xd08
+CPU revision\t: 3
+
+processor\t: 1
+BogoMIPS\t: 48.00
+
+Hardware\t: BCM2835
+Model\t\t: Raspberry Pi 4 Model B Rev 1.4
+";
+
+    #[test]
+    fn parses_the_first_x86_entry() {
+        let info = parse_info(X86);
+        assert_eq!(info.model_name.as_deref(), Some("12th Gen Intel(R) Core(TM) i7-1260P"));
+        assert_eq!(info.vendor.as_deref(), Some("GenuineIntel"));
+        assert_eq!(info.family.as_deref(), Some("6"));
+        assert_eq!(info.model.as_deref(), Some("154"));
+        assert_eq!(info.stepping.as_deref(), Some("3"));
+        assert_eq!(info.bogomips.as_deref(), Some("4992.00"));
+        assert_eq!(info.flags, ["fpu", "vme", "de", "pse", "tsc", "msr", "pae", "vmx", "avx2"]);
+        assert_eq!(info.virtualization(), Some("VT-x"));
+    }
+
+    #[test]
+    fn parses_arm_field_names() {
+        let info = parse_info(ARM);
+        assert_eq!(info.model_name, None);
+        assert_eq!(info.vendor.as_deref(), Some("0x41"));
+        assert_eq!(info.family.as_deref(), Some("8"));
+        assert_eq!(info.model.as_deref(), Some("0xd08"));
+        assert_eq!(info.stepping.as_deref(), Some("3"));
+        assert_eq!(info.flags.first().map(String::as_str), Some("fp"));
+        assert_eq!(info.virtualization(), None);
+    }
+
+    #[test]
+    fn ignores_malformed_cpuinfo() {
+        let info = parse_info("garbage\nmodel name:\n:\n\u{0}");
+        assert_eq!(info.model_name, None);
+        assert!(info.flags.is_empty());
+        assert!(parse_info("").vendor.is_none());
+    }
+
+    #[test]
+    fn counts_cpu_lists() {
+        assert_eq!(count_cpus("0"), 1);
+        assert_eq!(count_cpus("0-3,8-11"), 8);
+        assert_eq!(count_cpus("0,2,4"), 3);
+        assert_eq!(count_cpus("x,1-y,5"), 1);
+        assert_eq!(count_cpus("3-1"), 0);
+        assert_eq!(count_cpus(""), 0);
+    }
+
+    #[test]
+    fn classifies_vulnerabilities() {
+        assert_eq!(exposure("Not affected"), Exposure::NotAffected);
+        assert_eq!(exposure("Mitigation: Enhanced IBRS"), Exposure::Mitigated);
+        assert_eq!(exposure("Vulnerable: Clear CPU buffers attempted, no microcode"), Exposure::Vulnerable);
+        assert_eq!(exposure("Unknown: Dependent on hypervisor status"), Exposure::Unknown);
+    }
+
+    #[test]
+    fn names_caches() {
+        let cache = Cache {
+            level: 1,
+            kind: "Data".to_string(),
+            size_kib: Some(48),
+            ways: Some(12),
+            line_size: Some(64),
+            instances: 4,
+            shared_by: 2,
+        };
+        assert_eq!(cache.name(), "L1d");
+        assert_eq!(cache.size().as_deref(), Some("48 KiB"));
+
+        let cache = Cache {
+            level: 3,
+            kind: "Unified".to_string(),
+            size_kib: Some(18432),
+            ..cache
+        };
+        assert_eq!(cache.name(), "L3");
+        assert_eq!(cache.size().as_deref(), Some("18 MiB"));
+    }
+}
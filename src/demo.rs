@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Demo mode, which shows sample data captured from a laptop instead of this
+//! system, so that every page looks the same wherever it runs. The samples
+//! in `res/demo` are bundled into the binary and unpacked to a private
+//! temporary directory standing in for the root filesystem.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use nix::unistd::mkdtemp;
+use rust_embed::RustEmbed;
+
+use crate::host;
+
+#[derive(RustEmbed)]
+#[folder = "res/demo/"]
+struct Samples;
+
+/// Unpacks the samples and points every read of the system at them. The
+/// directory gets a random name and is only accessible to this user, so
+/// that nobody else can plant files or symlinks in it beforehand.
+pub fn start() -> io::Result<()> {
+    let root = mkdtemp(&std::env::temp_dir().join("examine-demo-XXXXXX"))?;
+    if let Err(e) = unpack(&root) {
+        _ = fs::remove_dir_all(&root);
+        return Err(e);
+    }
+
+    host::set_demo_root(root);
+    Ok(())
+}
+
+fn unpack(root: &Path) -> io::Result<()> {
+    for name in Samples::iter() {
+        let Some(file) = Samples::get(&name) else {
+            continue;
+        };
+        let path = root.join(name.as_ref());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, file.data)?;
+    }
+    Ok(())
+}
+
+/// Removes the unpacked samples.
+pub fn stop() {
+    if let Some(root) = host::demo_root() {
+        _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Compares two snapshots, answering "what changed since last month?": the
+//! devices added or removed and the key values that changed, such as the
+//! BIOS version, the memory size or the kernel.
+
+use std::collections::HashMap;
+
+use crate::pages::{
+    cameras, displays, distribution, filesystems, firmware, graphics, memory, motherboard, overview, pci, processor, usb,
+};
+use crate::snapshot::Snapshot;
+use crate::{fl, storage};
+
+/// One difference between two snapshots.
+#[derive(Clone, Debug)]
+pub enum Change {
+    /// A device, described by its category and name, is only in the newer snapshot.
+    Added(String, String),
+    /// A device, described by its category and name, is only in the older snapshot.
+    Removed(String, String),
+    /// The value with this label differs, from the old value to the new one.
+    Changed(String, String, String),
+}
+
+/// The values worth pointing out when they change, by label. Values that
+/// could not be read are `None`, so that they do not show up as changed
+/// merely because a tool was missing.
+fn values(snapshot: &Snapshot) -> Vec<(String, Option<String>)> {
+    let sections = &snapshot.sections;
+    let dmi = sections.get::<motherboard::Provider>().and_then(|section| section.board.dmi.as_ref());
+    let processor = sections.get::<processor::Provider>();
+
+    let mut values = vec![
+        (
+            fl!("distribution"),
+            sections.get::<distribution::Provider>().map(|distribution| distribution.pretty_name.clone()),
+        ),
+        (fl!("kernel"), sections.get::<overview::Provider>().and_then(|section| section.kernel.clone())),
+        (fl!("processor"), processor.and_then(|section| section.info.model_name.clone())),
+        (
+            fl!("logical-cpus"),
+            processor
+                .map(|section| section.logical_cpus.len())
+                .filter(|&count| count > 0)
+                .map(|count| count.to_string()),
+        ),
+        (
+            fl!("memory"),
+            sections
+                .get::<memory::Provider>()
+                .and_then(|section| section.memory.total())
+                .map(crate::memory::format_kib),
+        ),
+        (fl!("diff-board"), dmi.and_then(|dmi| dmi.board_name.clone())),
+        (fl!("diff-product"), dmi.and_then(|dmi| dmi.product_name.clone())),
+        (fl!("diff-bios-vendor"), dmi.and_then(|dmi| dmi.bios_vendor.clone())),
+        (fl!("diff-bios-version"), dmi.and_then(|dmi| dmi.bios_version.clone())),
+        (fl!("diff-bios-date"), dmi.and_then(|dmi| dmi.bios_date.clone())),
+    ];
+
+    // Every GPU's video BIOS and every device fwupd knows has its own version.
+    if let Some(graphics) = sections.get::<graphics::Provider>() {
+        for gpu in &graphics.gpus {
+            values.push((fl!("diff-vbios", card = gpu.card.as_str()), gpu.vbios_version.clone()));
+        }
+    }
+    if let Some(firmware) = sections.get::<firmware::Provider>() {
+        for device in &firmware.devices {
+            values.push((fl!("diff-firmware", device = device.name.as_str()), device.version.clone()));
+        }
+    }
+    values
+}
+
+/// The devices present, by category and name.
+fn devices(snapshot: &Snapshot) -> Vec<(String, String)> {
+    let sections = &snapshot.sections;
+    let pci = sections
+        .get::<pci::Provider>()
+        .into_iter()
+        .flat_map(|section| &section.devices)
+        .map(|device| (fl!("pci-devices"), format!("{} ({})", device.name(), device.ids())));
+    let usb = sections
+        .get::<usb::Provider>()
+        .into_iter()
+        .flat_map(|section| &section.devices)
+        .map(|device| (fl!("usb-devices"), device.name()));
+    let disks = sections
+        .get::<filesystems::Provider>()
+        .into_iter()
+        .flat_map(|section| &section.storage.disks)
+        .map(|disk| {
+            let name = disk.model.as_deref().unwrap_or(&disk.name);
+            (fl!("diff-disks"), format!("{name} ({})", storage::format_bytes(disk.size)))
+        });
+    let monitors = sections
+        .get::<displays::Provider>()
+        .into_iter()
+        .flatten()
+        .filter(|connector| connector.connected)
+        .map(|connector| {
+            let monitor = connector.monitor.clone().unwrap_or_else(|| fl!("unknown"));
+            (fl!("displays"), format!("{monitor} ({})", connector.name))
+        });
+    let cameras = sections
+        .get::<cameras::Provider>()
+        .into_iter()
+        .flatten()
+        .filter(|camera| camera.capture)
+        .map(|camera| (fl!("cameras"), camera.name.clone()));
+
+    pci.chain(usb).chain(disks).chain(monitors).chain(cameras).collect()
+}
+
+/// The changes from `old` to `new`: devices added, then devices removed,
+/// then changed values.
+pub fn compare(old: &Snapshot, new: &Snapshot) -> Vec<Change> {
+    // Counts identical devices, such as two of the same USB stick, so that
+    // removing one of them shows up.
+    let mut counts = HashMap::<(String, String), isize>::new();
+    for device in devices(new) {
+        *counts.entry(device).or_default() += 1;
+    }
+    for device in devices(old) {
+        *counts.entry(device).or_default() -= 1;
+    }
+    let mut counts = counts.into_iter().filter(|(_, count)| *count != 0).collect::<Vec<_>>();
+    counts.sort();
+
+    let added = counts.iter().filter(|(_, count)| *count > 0).flat_map(|((category, name), count)| {
+        std::iter::repeat(Change::Added(category.clone(), name.clone())).take(count.unsigned_abs())
+    });
+    let removed = counts.iter().filter(|(_, count)| *count < 0).flat_map(|((category, name), count)| {
+        std::iter::repeat(Change::Removed(category.clone(), name.clone())).take(count.unsigned_abs())
+    });
+
+    let old_values = values(old).into_iter().collect::<HashMap<_, _>>();
+    let changed = values(new).into_iter().filter_map(|(label, new)| {
+        let old = old_values.get(&label)?.clone()?;
+        let new = new?;
+        (old != new).then_some(Change::Changed(label, old, new))
+    });
+
+    added.chain(removed).chain(changed).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn snapshot(sections: Value) -> Snapshot {
+        serde_json::from_value(json!({
+            "version": crate::snapshot::VERSION,
+            "taken": "2026-10-16 12:00:00",
+            "pages": [],
+            "sections": sections,
+        }))
+        .unwrap()
+    }
+
+    fn usb(ports: &[&str]) -> Value {
+        let devices = ports
+            .iter()
+            .enumerate()
+            .map(|(dev, port)| {
+                json!({
+                    "port": port, "bus": 1, "dev": dev + 2,
+                    "manufacturer": "SanDisk", "product": "Cruzer Blade", "interfaces": [],
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "usb": { "Ok": { "devices": devices, "power": [] } } })
+    }
+
+    fn bios(version: Option<&str>) -> Value {
+        json!({ "motherboard": { "Ok": { "board": { "dmi": { "bios_vendor": "LENOVO", "bios_version": version } } } } })
+    }
+
+    #[test]
+    fn reports_one_of_two_identical_devices_removed() {
+        let changes = compare(&snapshot(usb(&["1-1", "1-2"])), &snapshot(usb(&["1-1"])));
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], Change::Removed(_, name) if name == "SanDisk Cruzer Blade"));
+    }
+
+    #[test]
+    fn reports_changed_bios_version() {
+        let changes = compare(&snapshot(bios(Some("N2HET71W"))), &snapshot(bios(Some("N2HET77W"))));
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            Change::Changed(label, old, new)
+                if *label == fl!("diff-bios-version") && old == "N2HET71W" && new == "N2HET77W"
+        ));
+    }
+
+    #[test]
+    fn ignores_values_missing_on_one_side() {
+        assert!(compare(&snapshot(bios(None)), &snapshot(bios(Some("N2HET77W")))).is_empty());
+        assert!(compare(&snapshot(bios(Some("N2HET71W"))), &snapshot(json!({}))).is_empty());
+    }
+}
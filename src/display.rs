@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads display connectors from DRM sysfs, their EDID preferred timing, and
+//! Display Stream Compression (DSC) and link state from debugfs when readable.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const DRM_DIR: &str = "/sys/class/drm";
+const DRI_DEBUGFS_DIR: &str = "/sys/kernel/debug/dri";
+
+/// Bits per pixel of an uncompressed 8 bpc RGB stream.
+const BITS_PER_PIXEL: f64 = 24.0;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Connector {
+    /// Connector name without the card prefix, e.g. `DP-1`.
+    pub name: String,
+    pub card: String,
+    pub connected: bool,
+    pub enabled: bool,
+    pub monitor: Option<String>,
+    /// Preferred mode from the EDID, e.g. `3840x2160`.
+    pub preferred_mode: Option<String>,
+    pub refresh_rate: Option<f64>,
+    /// Bandwidth needed by the preferred mode without compression, in Gbit/s.
+    pub required_bandwidth: Option<f64>,
+    /// Effective link capacity after line coding, in Gbit/s.
+    pub link_bandwidth: Option<f64>,
+    /// `None` if debugfs is not readable, which is the case for non-root users.
+    pub dsc_enabled: Option<bool>,
+}
+
+impl Connector {
+    /// Share of the link taken by the uncompressed preferred mode, in percent.
+    pub fn link_usage(&self) -> Option<f64> {
+        let (required, available) = self.required_bandwidth.zip(self.link_bandwidth)?;
+        (available > 0.0).then(|| required / available * 100.0)
+    }
+}
+
+struct Timing {
+    width: u32,
+    height: u32,
+    refresh_rate: f64,
+    pixel_clock_hz: f64,
+}
+
+/// Reads the monitor name descriptor of an EDID block.
+fn edid_monitor_name(edid: &[u8]) -> Option<String> {
+    edid.get(54..126)?.chunks_exact(18).find_map(|descriptor| {
+        (descriptor[..4] == [0, 0, 0, 0xfc]).then(|| {
+            String::from_utf8_lossy(&descriptor[5..])
+                .trim_end_matches(['\n', ' '])
+                .to_string()
+        })
+    })
+}
+
+/// Decodes the first detailed timing descriptor, which holds the preferred mode.
+fn edid_preferred_timing(edid: &[u8]) -> Option<Timing> {
+    let d = edid.get(54..72)?;
+    let pixel_clock_hz = f64::from(u16::from_le_bytes([d[0], d[1]])) * 10_000.0;
+    if pixel_clock_hz == 0.0 {
+        return None;
+    }
+
+    let width = u32::from(d[2]) | (u32::from(d[4] & 0xf0) << 4);
+    let h_blank = u32::from(d[3]) | (u32::from(d[4] & 0x0f) << 8);
+    let height = u32::from(d[5]) | (u32::from(d[7] & 0xf0) << 4);
+    let v_blank = u32::from(d[6]) | (u32::from(d[7] & 0x0f) << 8);
+    let total = f64::from((width + h_blank) * (height + v_blank));
+
+    Some(Timing {
+        width,
+        height,
+        refresh_rate: pixel_clock_hz / total,
+        pixel_clock_hz,
+    })
+}
+
+/// Reads DSC state from the i915 or amdgpu debugfs entries of a connector.
+fn dsc_enabled(debugfs: &Path) -> Option<bool> {
+    if let Ok(support) = fs::read_to_string(debugfs.join("i915_dsc_fec_support")) {
+        return Some(support.lines().any(|line| line.trim() == "DSC_Enabled: yes"));
+    }
+    let clock = fs::read_to_string(debugfs.join("dsc_clock_en")).ok()?;
+    clock
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|number| !number.is_empty())
+        .map(|number| number != "0")
+}
+
+/// Reads the current DisplayPort link from amdgpu's `link_settings`, which
+/// reports the lane count followed by the link rate in units of 0.27 Gbit/s.
+fn link_bandwidth(debugfs: &Path) -> Option<f64> {
+    let settings = fs::read_to_string(debugfs.join("link_settings")).ok()?;
+    let mut current = settings.split_whitespace().skip_while(|word| *word != "Current:").skip(1);
+    let lanes = current.next()?.parse::<f64>().ok()?;
+    let rate = u32::from_str_radix(current.next()?.trim_start_matches("0x"), 16).ok()?;
+    let per_lane = f64::from(rate) * 0.27;
+    // 8b/10b coding up to HBR3, 128b/132b for UHBR rates (10 Gbit/s and above).
+    let efficiency = if per_lane >= 10.0 { 128.0 / 132.0 } else { 0.8 };
+    Some(lanes * per_lane * efficiency)
+}
+
+pub fn collect() -> Vec<Connector> {
+    let mut connectors = fs::read_dir(host::path(DRM_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            // Connectors are named `card<N>-<connector>`.
+            let (card, name) = file_name.strip_prefix("card")?.split_once('-')?;
+            let path = entry.path();
+            let read = |file: &str| {
+                fs::read_to_string(path.join(file))
+                    .map(|value| value.trim().to_string())
+                    .unwrap_or_default()
+            };
+
+            let edid = fs::read(path.join("edid")).unwrap_or_default();
+            let timing = edid_preferred_timing(&edid);
+            let debugfs = host::path(DRI_DEBUGFS_DIR).join(card).join(name);
+
+            Some(Connector {
+                name: name.to_string(),
+                card: card.to_string(),
+                connected: read("status") == "connected",
+                enabled: read("enabled") == "enabled",
+                monitor: edid_monitor_name(&edid),
+                preferred_mode: timing.as_ref().map(|t| format!("{}x{}", t.width, t.height)),
+                refresh_rate: timing.as_ref().map(|t| t.refresh_rate),
+                required_bandwidth: timing.map(|t| t.pixel_clock_hz * BITS_PER_PIXEL / 1e9),
+                link_bandwidth: link_bandwidth(&debugfs),
+                dsc_enabled: dsc_enabled(&debugfs),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    connectors.sort_by(|a, b| (&a.card, &a.name).cmp(&(&b.card, &b.name)));
+    connectors
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads os-release, from the host when running in the Flatpak sandbox, into a
+//! typed [`Distribution`].
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use etc_os_release::OsRelease;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+/// The distribution as described by os-release. Fields that are unset or
+/// blank are `None`, or empty for lists. It is saved as its os-release
+/// [`fields`](Distribution::fields).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(into = "Vec<(String, String)>", try_from = "Vec<(String, String)>")]
+pub struct Distribution {
+    pub name: String,
+    pub pretty_name: String,
+    pub id: String,
+    pub id_like: Vec<String>,
+    pub version: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    pub build_id: Option<String>,
+    pub variant: Option<String>,
+    pub variant_id: Option<String>,
+    pub image_id: Option<String>,
+    pub image_version: Option<String>,
+    pub vendor_name: Option<String>,
+    pub cpe_name: Option<String>,
+    pub ansi_color: Option<String>,
+    pub logo: Option<String>,
+    pub default_hostname: Option<String>,
+    pub architecture: Option<String>,
+    pub home_url: Option<String>,
+    pub documentation_url: Option<String>,
+    pub support_url: Option<String>,
+    pub bug_report_url: Option<String>,
+    pub privacy_policy_url: Option<String>,
+    /// The date support ends, as `YYYY-MM-DD`.
+    pub support_end: Option<String>,
+    pub sysext_level: Option<String>,
+    pub sysext_scope: Vec<String>,
+    pub confext_level: Option<String>,
+    pub confext_scope: Vec<String>,
+    pub portable_prefixes: Vec<String>,
+}
+
+/// The host's os-release as exposed inside the sandbox.
+const HOST_OS_RELEASE: &str = "/run/host/os-release";
+
+/// The file os-release is read from, as shown to the user: the host's in the
+/// sandbox, otherwise `/etc/os-release` or else `/usr/lib/os-release`.
+pub fn source() -> &'static str {
+    if host::is_sandboxed() && !host::is_demo() {
+        HOST_OS_RELEASE
+    } else if !Path::new("/etc/os-release").exists() && Path::new("/usr/lib/os-release").exists() {
+        "/usr/lib/os-release"
+    } else {
+        "/etc/os-release"
+    }
+}
+
+impl Distribution {
+    /// Parses the text of an os-release file.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let os_release = OsRelease::from_str(text).map_err(|e| e.to_string())?;
+        Ok(Self {
+            name: os_release.name().to_string(),
+            pretty_name: os_release.pretty_name().to_string(),
+            id: os_release.id().to_string(),
+            id_like: list(os_release.id_like()),
+            version: text_field(os_release.version()),
+            version_id: text_field(os_release.version_id()),
+            // Fedora (and possibly other distros) set VERSION_CODENAME to a blank string.
+            version_codename: text_field(os_release.version_codename()),
+            build_id: text_field(os_release.build_id()),
+            variant: text_field(os_release.variant()),
+            variant_id: text_field(os_release.variant_id()),
+            image_id: text_field(os_release.image_id()),
+            image_version: text_field(os_release.image_version()),
+            vendor_name: text_field(os_release.vendor_name()),
+            cpe_name: text_field(os_release.cpe_name()),
+            ansi_color: text_field(os_release.ansi_color()),
+            logo: text_field(os_release.logo()),
+            default_hostname: text_field(os_release.default_hostname()),
+            architecture: text_field(os_release.architecture()),
+            home_url: text_field(os_release.home_url().ok().flatten()),
+            documentation_url: text_field(os_release.documentation_url().ok().flatten()),
+            support_url: text_field(os_release.support_url().ok().flatten()),
+            bug_report_url: text_field(os_release.bug_report_url().ok().flatten()),
+            privacy_policy_url: text_field(os_release.privacy_policy_url().ok().flatten()),
+            support_end: text_field(os_release.support_end().ok().flatten()),
+            sysext_level: text_field(os_release.sysext_level()),
+            sysext_scope: list(os_release.sysext_scope()),
+            confext_level: text_field(os_release.confext_level()),
+            confext_scope: list(os_release.confext_scope()),
+            portable_prefixes: list(os_release.portable_prefixes()),
+        })
+    }
+
+    /// The fields that are set, by their os-release variable name.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let join = |values: &[String]| (!values.is_empty()).then(|| values.join(" "));
+        [
+            ("NAME", Some(self.name.clone())),
+            ("PRETTY_NAME", Some(self.pretty_name.clone())),
+            ("ID", Some(self.id.clone())),
+            ("VERSION", self.version.clone()),
+            ("VERSION_ID", self.version_id.clone()),
+            ("VERSION_CODENAME", self.version_codename.clone()),
+            ("BUILD_ID", self.build_id.clone()),
+            ("VARIANT", self.variant.clone()),
+            ("VARIANT_ID", self.variant_id.clone()),
+            ("IMAGE_ID", self.image_id.clone()),
+            ("IMAGE_VERSION", self.image_version.clone()),
+            ("VENDOR_NAME", self.vendor_name.clone()),
+            ("CPE_NAME", self.cpe_name.clone()),
+            ("ANSI_COLOR", self.ansi_color.clone()),
+            ("LOGO", self.logo.clone()),
+            ("DEFAULT_HOSTNAME", self.default_hostname.clone()),
+            ("ARCHITECTURE", self.architecture.clone()),
+            ("ID_LIKE", join(&self.id_like)),
+            ("HOME_URL", self.home_url.clone()),
+            ("DOCUMENTATION_URL", self.documentation_url.clone()),
+            ("SUPPORT_URL", self.support_url.clone()),
+            ("BUG_REPORT_URL", self.bug_report_url.clone()),
+            ("PRIVACY_POLICY_URL", self.privacy_policy_url.clone()),
+            ("SUPPORT_END", self.support_end.clone()),
+            ("SYSEXT_LEVEL", self.sysext_level.clone()),
+            ("SYSEXT_SCOPE", join(&self.sysext_scope)),
+            ("CONFEXT_LEVEL", self.confext_level.clone()),
+            ("CONFEXT_SCOPE", join(&self.confext_scope)),
+            ("PORTABLE_PREFIXES", join(&self.portable_prefixes)),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value.filter(|value| !value.is_empty())?)))
+        .collect()
+    }
+
+    /// Parses os-release again from its `fields`, such as those saved in a snapshot.
+    pub fn from_fields(fields: &[(String, String)]) -> Result<Self, String> {
+        let text = fields
+            .iter()
+            .map(|(key, value)| format!("{key}=\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")))
+            .join("\n");
+        Self::parse(&text)
+    }
+}
+
+impl From<Distribution> for Vec<(String, String)> {
+    fn from(distribution: Distribution) -> Self {
+        distribution.fields().into_iter().map(|(key, value)| (key.to_string(), value)).collect()
+    }
+}
+
+impl TryFrom<Vec<(String, String)>> for Distribution {
+    type Error = String;
+
+    fn try_from(fields: Vec<(String, String)>) -> Result<Self, String> {
+        Self::from_fields(&fields)
+    }
+}
+
+/// A text field, `None` when unset or blank.
+fn text_field(value: Option<impl Display>) -> Option<String> {
+    value.map(|value| value.to_string()).filter(|value| !value.is_empty())
+}
+
+/// A space-separated list field, empty when unset.
+fn list(values: Option<impl Iterator<Item = impl Display>>) -> Vec<String> {
+    values.into_iter().flatten().map(|value| value.to_string()).collect()
+}
+
+/// Reads and parses the distribution's os-release.
+pub fn collect() -> Result<Distribution, host::Error> {
+    let path = if host::is_demo() {
+        host::path("/etc/os-release")
+    } else {
+        PathBuf::from(source())
+    };
+    let text = fs::read_to_string(&path).map_err(|e| host::read_error(source(), &e))?;
+    Distribution::parse(&text).map_err(|e| host::Error::Failed(format!("{}: {e}", source())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEDORA: &str = r#"NAME="Fedora Linux"
+VERSION="40 (Workstation Edition)"
+ID=fedora
+VERSION_ID=40
+VERSION_CODENAME=""
+PRETTY_NAME="Fedora Linux 40 (Workstation Edition)"
+ANSI_COLOR="0;38;2;60;110;180"
+LOGO=fedora-logo-icon
+CPE_NAME="cpe:/o:fedoraproject:fedora:40"
+HOME_URL="https://fedoraproject.org/"
+BUG_REPORT_URL="https://bugzilla.redhat.com/"
+VARIANT="Workstation Edition"
+VARIANT_ID=workstation
+"#;
+
+    const UBUNTU: &str = r#"PRETTY_NAME="Ubuntu 24.04 LTS"
+NAME="Ubuntu"
+VERSION_ID="24.04"
+VERSION="24.04 LTS (Noble Numbat)"
+VERSION_CODENAME=noble
+ID=ubuntu
+ID_LIKE=debian
+HOME_URL="https://www.ubuntu.com/"
+"#;
+
+    #[test]
+    fn parses_quoted_and_bare_values() {
+        let distribution = Distribution::parse(FEDORA).unwrap();
+        assert_eq!(distribution.name, "Fedora Linux");
+        assert_eq!(distribution.pretty_name, "Fedora Linux 40 (Workstation Edition)");
+        assert_eq!(distribution.id, "fedora");
+        assert_eq!(distribution.version_id.as_deref(), Some("40"));
+        assert_eq!(distribution.logo.as_deref(), Some("fedora-logo-icon"));
+        assert_eq!(distribution.home_url.as_deref(), Some("https://fedoraproject.org/"));
+        assert!(distribution.id_like.is_empty());
+    }
+
+    #[test]
+    fn leaves_out_blank_fields() {
+        let distribution = Distribution::parse(FEDORA).unwrap();
+        assert_eq!(distribution.version_codename, None);
+        assert!(distribution.fields().iter().all(|(key, _)| *key != "VERSION_CODENAME"));
+    }
+
+    #[test]
+    fn parses_id_like() {
+        let distribution = Distribution::parse(UBUNTU).unwrap();
+        assert_eq!(distribution.id_like, ["debian"]);
+        assert_eq!(distribution.version_codename.as_deref(), Some("noble"));
+    }
+
+    #[test]
+    fn round_trips_through_fields() {
+        for text in [FEDORA, UBUNTU] {
+            let distribution = Distribution::parse(text).unwrap();
+            let fields = distribution
+                .fields()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect::<Vec<_>>();
+            assert_eq!(Distribution::from_fields(&fields).unwrap(), distribution);
+        }
+    }
+}
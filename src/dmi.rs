@@ -0,0 +1,293 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Parses the SMBIOS tables decoded by `dmidecode`, which needs root and so
+//! runs through the privileged helper.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, privileged};
+
+/// The SMBIOS type of the physical memory array the DIMM slots belong to.
+const MEMORY_ARRAY: u8 = 16;
+
+/// The SMBIOS type of a memory device, one per DIMM slot.
+const MEMORY_DEVICE: u8 = 17;
+
+/// One structure from the SMBIOS table, such as `BIOS Information`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Record {
+    pub handle: String,
+    pub dmi_type: u8,
+    pub title: String,
+    /// Fields in table order. List fields, such as `Characteristics`, hold
+    /// one entry per line.
+    pub fields: Vec<(String, String)>,
+}
+
+impl Record {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+            // Vendors fill unused strings with placeholders.
+            .filter(|value| {
+                !value.is_empty()
+                    && !matches!(*value, "Not Specified" | "Unknown" | "Default string" | "To Be Filled By O.E.M.")
+            })
+    }
+}
+
+/// A DIMM slot and the module installed in it, if any.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryDevice {
+    /// The slot name, e.g. `DIMM_A1`.
+    pub locator: String,
+    pub bank_locator: Option<String>,
+    /// `None` for an empty slot.
+    pub size: Option<String>,
+    pub memory_type: Option<String>,
+    pub speed: Option<String>,
+    pub configured_speed: Option<String>,
+    pub manufacturer: Option<String>,
+    pub part_number: Option<String>,
+}
+
+impl MemoryDevice {
+    /// Guesses the memory channel from slot names such as `DIMM_A1`,
+    /// `ChannelA-DIMM0` or a bank locator of `P0 CHANNEL A`.
+    pub fn channel(&self) -> Option<String> {
+        let after_channel = |name: &str| {
+            let name = name.to_uppercase();
+            let (_, rest) = name.split_once("CHANNEL")?;
+            rest.trim_start_matches([' ', '-', '_'])
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .next()
+                .filter(|channel| !channel.is_empty())
+                .map(str::to_string)
+        };
+
+        if let Some(channel) = self.bank_locator.as_deref().and_then(after_channel) {
+            return Some(channel);
+        }
+        if let Some(channel) = after_channel(&self.locator) {
+            return Some(channel);
+        }
+
+        let locator = self.locator.to_uppercase();
+        let name = locator.trim_start_matches("DIMM").trim_start_matches([' ', '-', '_']);
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), Some(digit)) if letter.is_ascii_alphabetic() && digit.is_ascii_digit() => {
+                Some(letter.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Splits `dmidecode` output into records. Fields are indented by one tab;
+/// the items of list fields follow on lines indented by two.
+pub fn parse(output: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut current: Option<Record> = None;
+
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("Handle ") {
+            records.extend(current.take());
+            let mut parts = header.split(", ");
+            let handle = parts.next().unwrap_or_default().to_string();
+            let dmi_type = parts
+                .next()
+                .and_then(|part| part.strip_prefix("DMI type "))
+                .and_then(|dmi_type| dmi_type.parse().ok())
+                .unwrap_or(u8::MAX);
+            current = Some(Record {
+                handle,
+                dmi_type,
+                title: String::new(),
+                fields: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(record) = current.as_mut() else {
+            continue;
+        };
+
+        if let Some(item) = line.strip_prefix("\t\t") {
+            if let Some((_, value)) = record.fields.last_mut() {
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(item.trim());
+            }
+        } else if let Some(field) = line.strip_prefix('\t') {
+            let (key, value) = field.split_once(':').unwrap_or((field, ""));
+            record.fields.push((key.trim().to_string(), value.trim().to_string()));
+        } else if record.title.is_empty() && !line.trim().is_empty() {
+            record.title = line.trim().to_string();
+        }
+    }
+    records.extend(current);
+
+    // Inactive and end-of-table markers carry nothing worth showing.
+    records.retain(|record| record.dmi_type != 126 && record.dmi_type != 127);
+    records
+}
+
+/// Extracts the DIMM slots from parsed records.
+pub fn memory_devices(records: &[Record]) -> Vec<MemoryDevice> {
+    records
+        .iter()
+        .filter(|record| record.dmi_type == MEMORY_DEVICE)
+        .map(|record| {
+            let owned = |key: &str| record.get(key).map(str::to_string);
+            MemoryDevice {
+                locator: owned("Locator").unwrap_or_else(|| record.handle.clone()),
+                bank_locator: owned("Bank Locator"),
+                size: owned("Size").filter(|size| size != "No Module Installed" && size != "0"),
+                memory_type: owned("Type"),
+                speed: owned("Speed"),
+                configured_speed: owned("Configured Memory Speed"),
+                manufacturer: owned("Manufacturer"),
+                part_number: owned("Part Number"),
+            }
+        })
+        .collect()
+}
+
+/// The error correction type of the physical memory array, e.g. `Multi-bit ECC`.
+pub fn correction_type(records: &[Record]) -> Option<&str> {
+    records
+        .iter()
+        .filter(|record| record.dmi_type == MEMORY_ARRAY)
+        .find_map(|record| record.get("Error Correction Type"))
+}
+
+/// Groups slots by channel. Slots without a recognisable channel are grouped
+/// under `None`.
+pub fn by_channel(devices: &[MemoryDevice]) -> BTreeMap<Option<String>, Vec<&MemoryDevice>> {
+    let mut channels = BTreeMap::<Option<String>, Vec<&MemoryDevice>>::new();
+    for device in devices {
+        channels.entry(device.channel()).or_default().push(device);
+    }
+    channels
+}
+
+/// Runs `dmidecode` through the helper, prompting for authorization.
+pub fn collect() -> Result<Vec<Record>, host::Error> {
+    privileged::run(&["dmi"]).map(|output| parse(&output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMORY: &str = "# dmidecode 3.5
+Getting SMBIOS data from sysfs.
+SMBIOS 3.4.0 present.
+
+Handle 0x0040, DMI type 16, 23 bytes
+Physical Memory Array
+\tLocation: System Board Or Motherboard
+\tUse: System Memory
+\tError Correction Type: Multi-bit ECC
+\tMaximum Capacity: 128 GB
+\tNumber Of Devices: 2
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tArray Handle: 0x0040
+\tSize: 16 GB
+\tLocator: DIMM 0
+\tBank Locator: P0 CHANNEL A
+\tType: DDR5
+\tSpeed: 4800 MT/s
+\tManufacturer: Samsung
+\tPart Number: M425R2GA3BB0-CQKOD
+\tConfigured Memory Speed: 4800 MT/s
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+\tArray Handle: 0x0040
+\tSize: No Module Installed
+\tLocator: DIMM 0
+\tBank Locator: P0 CHANNEL B
+\tType: Unknown
+\tManufacturer: Not Specified
+
+Handle 0x0000, DMI type 0, 26 bytes
+BIOS Information
+\tVendor: LENOVO
+\tCharacteristics:
+\t\tPCI is supported
+\t\tBIOS is upgradeable
+
+Handle 0xFEFF, DMI type 127, 4 bytes
+End Of Table
+";
+
+    fn slot(locator: &str, bank_locator: Option<&str>) -> MemoryDevice {
+        MemoryDevice {
+            locator: locator.to_string(),
+            bank_locator: bank_locator.map(str::to_string),
+            size: None,
+            memory_type: None,
+            speed: None,
+            configured_speed: None,
+            manufacturer: None,
+            part_number: None,
+        }
+    }
+
+    #[test]
+    fn parses_records() {
+        let records = parse(MEMORY);
+        let types = records.iter().map(|record| record.dmi_type).collect::<Vec<_>>();
+        assert_eq!(types, [16, 17, 17, 0]);
+        assert_eq!(records[0].handle, "0x0040");
+        assert_eq!(records[0].title, "Physical Memory Array");
+        assert_eq!(records[3].get("Characteristics"), Some("PCI is supported\nBIOS is upgradeable"));
+        assert_eq!(correction_type(&records), Some("Multi-bit ECC"));
+    }
+
+    #[test]
+    fn extracts_memory_devices() {
+        let devices = memory_devices(&parse(MEMORY));
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].size.as_deref(), Some("16 GB"));
+        assert_eq!(devices[0].memory_type.as_deref(), Some("DDR5"));
+        assert_eq!(devices[0].configured_speed.as_deref(), Some("4800 MT/s"));
+        assert_eq!(devices[1].size, None);
+        assert_eq!(devices[1].memory_type, None);
+        assert_eq!(devices[1].manufacturer, None);
+
+        let channels = by_channel(&devices);
+        assert_eq!(channels.keys().cloned().collect::<Vec<_>>(), [Some("A".to_string()), Some("B".to_string())]);
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        assert!(parse("").is_empty());
+        assert!(parse("\tField: before any handle\nno handle here\n").is_empty());
+
+        let records = parse("Handle 0x0001, DMI type x, 4 bytes\n\t\tItem without a field\n\tBare field\n");
+        assert_eq!(records[0].dmi_type, u8::MAX);
+        assert_eq!(records[0].fields, [("Bare field".to_string(), String::new())]);
+        assert_eq!(correction_type(&records), None);
+    }
+
+    #[test]
+    fn guesses_channels() {
+        assert_eq!(slot("DIMM_A1", None).channel().as_deref(), Some("A"));
+        assert_eq!(slot("ChannelB-DIMM0", Some("BANK 1")).channel().as_deref(), Some("B"));
+        assert_eq!(slot("DIMM 0", Some("P0 CHANNEL C")).channel().as_deref(), Some("C"));
+        assert_eq!(slot("Controller0-ChannelA-DIMM1", None).channel().as_deref(), Some("A"));
+        assert_eq!(slot("DIMM 0", Some("BANK 0")).channel(), None);
+        assert_eq!(slot("", Some("CHANNEL")).channel(), None);
+        assert_eq!(slot("A", None).channel(), None);
+    }
+}
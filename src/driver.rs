@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Looks up the kernel driver bound to a device, its module parameters and
+//! the modules able to drive it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const PCI_DEVICES_DIR: &str = "/sys/bus/pci/devices";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Driver {
+    pub name: String,
+    /// The module providing the driver, absent for drivers built into the kernel.
+    pub module: Option<String>,
+    /// Current values from `/sys/module/<module>/parameters`.
+    pub parameters: Vec<(String, String)>,
+}
+
+/// Returns the sysfs directory of a PCI device given its slot name, which
+/// omits the domain unless it is non-zero, so default to `0000`.
+pub fn pci_sysfs_path(slot: &str) -> PathBuf {
+    let address = if slot.matches(':').count() == 1 {
+        format!("0000:{slot}")
+    } else {
+        slot.to_string()
+    };
+    host::path(PCI_DEVICES_DIR).join(address)
+}
+
+fn link_name(path: &Path) -> Option<String> {
+    fs::read_link(path)
+        .ok()?
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+/// NUMA placement of a device.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Affinity {
+    /// The NUMA node the device is attached to, `None` on single-node systems.
+    pub numa_node: Option<u32>,
+    /// CPUs local to the device, in kernel list format (`0-7,16-23`).
+    pub local_cpus: Option<String>,
+}
+
+/// Reads the NUMA node and local CPU list of the device at `device`.
+pub fn affinity(device: &Path) -> Affinity {
+    let read = |name: &str| {
+        fs::read_to_string(device.join(name))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+
+    Affinity {
+        // The kernel reports -1 when the platform does not describe locality.
+        numa_node: read("numa_node").and_then(|node| node.parse().ok()),
+        local_cpus: read("local_cpulist").filter(|cpus| !cpus.is_empty()),
+    }
+}
+
+/// Reads the driver bound to the device at `device`, if any.
+pub fn for_device(device: &Path) -> Option<Driver> {
+    let name = link_name(&device.join("driver"))?;
+    let module = link_name(&device.join("driver/module"));
+
+    let mut parameters = module
+        .as_ref()
+        .and_then(|module| fs::read_dir(host::path("/sys/module").join(module).join("parameters")).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            // Some parameters are write-only or root-only, skip those.
+            let value = fs::read_to_string(entry.path()).ok()?;
+            Some((
+                entry.file_name().to_string_lossy().into_owned(),
+                value.trim().to_string(),
+            ))
+        })
+        .collect::<Vec<_>>();
+    parameters.sort();
+
+    Some(Driver {
+        name,
+        module,
+        parameters,
+    })
+}
+
+/// `alias <pattern> <module>` lines from the running kernel's `modules.alias`.
+static MODULE_ALIASES: LazyLock<Vec<(String, String)>> = LazyLock::new(|| {
+    let Ok(release) = fs::read_to_string(host::path("/proc/sys/kernel/osrelease")) else {
+        return Vec::new();
+    };
+    ["/lib/modules", "/usr/lib/modules"]
+        .iter()
+        .find_map(|dir| fs::read_to_string(host::path(dir).join(release.trim()).join("modules.alias")).ok())
+        .map(|aliases| {
+            aliases
+                .lines()
+                .filter_map(|line| {
+                    let mut words = line.strip_prefix("alias ")?.split_whitespace();
+                    Some((words.next()?.to_string(), words.next()?.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+});
+
+/// Matches a `modules.alias` pattern, where `*` matches any run of characters
+/// and `?` any single character.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Lists the modules able to drive a device with the given modalias, the way
+/// `modprobe` would pick them.
+pub fn modules_for_alias(modalias: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+    for (pattern, module) in MODULE_ALIASES.iter() {
+        if glob_match(pattern.as_bytes(), modalias.as_bytes()) && !modules.contains(module) {
+            modules.push(module.clone());
+        }
+    }
+    modules
+}
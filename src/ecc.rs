@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads ECC memory state and error counts from the kernel's EDAC subsystem in
+//! `/sys/devices/system/edac`, falling back to the error correction type of
+//! the SMBIOS memory array once the DMI tables have been read.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const EDAC_MC_DIR: &str = "/sys/devices/system/edac/mc";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Dimm {
+    pub label: String,
+    /// The correction scheme, e.g. `SECDED`, or `None`.
+    pub mode: Option<String>,
+    pub corrected: u64,
+    pub uncorrected: u64,
+}
+
+/// A memory controller registered with EDAC.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Controller {
+    /// The controller driver, e.g. `Skylake Socket#0 IMC#0`.
+    pub name: String,
+    pub corrected: u64,
+    pub uncorrected: u64,
+    pub dimms: Vec<Dimm>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Ecc {
+    pub controllers: Vec<Controller>,
+}
+
+impl Ecc {
+    /// Whether ECC is in use, if that can be told. Without EDAC it is told
+    /// from `correction_type`, the SMBIOS memory array's, e.g. `Multi-bit ECC`.
+    pub fn active(&self, correction_type: Option<&str>) -> Option<bool> {
+        let dimm_modes = self
+            .controllers
+            .iter()
+            .flat_map(|controller| &controller.dimms)
+            .filter_map(|dimm| dimm.mode.as_deref())
+            .collect::<Vec<_>>();
+        if !dimm_modes.is_empty() {
+            return Some(dimm_modes.iter().any(|mode| *mode != "None" && *mode != "Unknown"));
+        }
+        if !self.controllers.is_empty() {
+            // EDAC drivers generally refuse to register controllers with ECC disabled.
+            return Some(true);
+        }
+        match correction_type {
+            Some("None") => Some(false),
+            Some("Unknown" | "Other") | None => None,
+            Some(_) => Some(true),
+        }
+    }
+
+    pub fn corrected(&self) -> u64 {
+        self.controllers.iter().map(|controller| controller.corrected).sum()
+    }
+
+    pub fn uncorrected(&self) -> u64 {
+        self.controllers.iter().map(|controller| controller.uncorrected).sum()
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|value| value.trim().to_string())
+}
+
+fn read_count(path: &Path) -> u64 {
+    read_trimmed(path).and_then(|count| count.parse().ok()).unwrap_or(0)
+}
+
+fn collect_dimms(controller: &Path) -> Vec<Dimm> {
+    let mut dimms = fs::read_dir(controller)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("dimm") || name.starts_with("rank")
+        })
+        .map(|entry| {
+            let path = entry.path();
+            Dimm {
+                label: read_trimmed(&path.join("dimm_label"))
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or_else(|| entry.file_name().to_string_lossy().into_owned()),
+                mode: read_trimmed(&path.join("dimm_edac_mode")),
+                corrected: read_count(&path.join("dimm_ce_count")),
+                uncorrected: read_count(&path.join("dimm_ue_count")),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    dimms.sort_by(|a, b| a.label.cmp(&b.label));
+    dimms
+}
+
+pub fn collect() -> Ecc {
+    let mut controllers = fs::read_dir(host::path(EDAC_MC_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("mc"))
+        .map(|entry| {
+            let path = entry.path();
+            Controller {
+                name: read_trimmed(&path.join("mc_name"))
+                    .unwrap_or_else(|| entry.file_name().to_string_lossy().into_owned()),
+                corrected: read_count(&path.join("ce_count")),
+                uncorrected: read_count(&path.join("ue_count")),
+                dimms: collect_dimms(&path),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    controllers.sort_by(|a, b| a.name.cmp(&b.name));
+    Ecc { controllers }
+}
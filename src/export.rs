@@ -0,0 +1,571 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Exports the data of a page, or of every page as one report, to a file,
+//! so that it can be attached to a support request rather than copied out
+//! of the widgets by hand. Every format renders the same structured data,
+//! the JSON each page builds in [`DataProvider::export`].
+//!
+//! [`DataProvider::export`]: crate::pages::DataProvider::export
+
+mod html;
+mod markdown;
+mod text;
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmic::dialog::file_chooser;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::i18n::LANGUAGE_LOADER;
+use crate::pages::{self, Page};
+use crate::{fl, host};
+
+/// The file formats pages can be exported to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Html,
+    Markdown,
+    Text,
+}
+
+impl Format {
+    /// Every format, in menu order.
+    pub const ALL: &'static [Format] = &[Self::Json, Self::Html, Self::Markdown, Self::Text];
+
+    /// The localized menu label.
+    pub fn label(self) -> String {
+        match self {
+            Self::Json => fl!("export-json"),
+            Self::Html => fl!("export-html"),
+            Self::Markdown => fl!("export-markdown"),
+            Self::Text => fl!("export-text"),
+        }
+    }
+
+    /// The format named `name` on the command line.
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "json" => Some(Self::Json),
+            "html" => Some(Self::Html),
+            "markdown" | "md" => Some(Self::Markdown),
+            "text" | "txt" => Some(Self::Text),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Html => "html",
+            Self::Markdown => "md",
+            Self::Text => "txt",
+        }
+    }
+}
+
+/// One exported page.
+pub struct PageData {
+    pub page: Page,
+    /// The localized page name, used as its heading.
+    pub title: String,
+    pub data: Value,
+}
+
+/// The data of a collector that may have failed, as the data itself or an
+/// object holding the error.
+pub fn result<T: Serialize, E: Display>(result: &Result<T, E>) -> Value {
+    match result {
+        Ok(value) => json!(value),
+        Err(e) => json!({ "error": e.to_string() }),
+    }
+}
+
+/// The verbatim output of the tools behind `page`, by command line.
+pub fn raw_output(page: Page) -> BTreeMap<String, String> {
+    let programs = pages::provider(page).tools();
+    host::raw_output()
+        .into_iter()
+        .filter(|(command_line, _)| {
+            command_line
+                .split(' ')
+                .next()
+                .is_some_and(|program| programs.contains(&program))
+        })
+        .collect()
+}
+
+/// Renders `pages` as a document in `format`. The raw output appendix lets
+/// whoever reads it check the parsed values against what the tools printed.
+pub fn document(format: Format, pages: &[PageData], raw_output: Option<BTreeMap<String, String>>) -> String {
+    let generated = timestamp();
+    match format {
+        Format::Json => json(pages, &generated, raw_output),
+        Format::Html => html::document(pages, &generated, raw_output),
+        Format::Markdown => markdown::document(pages, &generated, raw_output),
+        Format::Text => text::document(pages, &generated, raw_output),
+    }
+}
+
+/// `page` as plain text on its own, for pasting into a conversation.
+pub fn page_text(page: &PageData) -> String {
+    let mut out = String::new();
+    text::page(page, &mut out);
+    out.trim_end().to_string()
+}
+
+/// Fields whose values identify the machine, its owner or where it is, by
+/// their name with words joined by underscores, as in the data or the DMI
+/// tables.
+const IDENTIFYING: &[&str] = &[
+    "imei",
+    "machine_id",
+    "hostname",
+    "static_hostname",
+    "pretty_hostname",
+    "address",
+    "location",
+    "ssid",
+    "bssid",
+];
+
+/// Parts of field names that make a field identifying wherever they appear,
+/// e.g. `board_serial` or `product_asset_tag` in the IPMI FRU data.
+const IDENTIFYING_PARTS: &[&str] = &["serial", "uuid", "asset_tag"];
+
+/// Whether `key` names an identifying field.
+fn identifying(key: &str) -> bool {
+    let key = key.to_lowercase().replace([' ', '-'], "_");
+    IDENTIFYING.contains(&key.as_str()) || IDENTIFYING_PARTS.iter().any(|part| key.contains(part))
+}
+
+/// Replaces the values of identifying fields in `value`, at any depth, so
+/// that the result can be shared publicly.
+pub fn redact(value: &mut Value) {
+    let hidden = || Value::String(fl!("redacted"));
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                if identifying(key) && !value.is_null() {
+                    *value = hidden();
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        // Fields kept in order, such as those of the DMI tables, are pairs.
+        Value::Array(items) => match items.as_mut_slice() {
+            [Value::String(key), value] if identifying(key) => *value = hidden(),
+            items => items.iter_mut().for_each(redact),
+        },
+        _ => {}
+    }
+}
+
+/// The rows of `page` whose label or value contains `query`, ignoring case,
+/// as label, value and where the value is read from. Fields are labelled as
+/// the page labels them, so what is on screen can be searched for. Rows
+/// within sections are labelled with the sections they are in, e.g.
+/// `DMI Tables › Manufacturer`, and the rows of tables are matched whole and
+/// labelled with their first column.
+pub fn search(page: &PageData, query: &str) -> Vec<(String, String, &'static str)> {
+    fn find(blocks: &[Block], path: &str, query: &str, out: &mut Vec<(String, String, &'static str)>) {
+        let labelled = |label: &str| {
+            if path.is_empty() {
+                label.to_string()
+            } else {
+                format!("{path} › {label}")
+            }
+        };
+        for block in blocks {
+            match block {
+                Block::Fields(pairs) => {
+                    for (key, value, origin) in pairs {
+                        let label = labelled(key);
+                        if label.to_lowercase().contains(query) || value.to_lowercase().contains(query) {
+                            out.push((label, value.clone(), *origin));
+                        }
+                    }
+                }
+                Block::Table(headers, rows, origin) => {
+                    for row in rows {
+                        if !row.iter().any(|cell| cell.to_lowercase().contains(query)) {
+                            continue;
+                        }
+                        let value = headers
+                            .iter()
+                            .zip(row)
+                            .skip(1)
+                            .map(|(header, cell)| format!("{header}: {cell}"))
+                            .collect::<Vec<_>>()
+                            .join("; ");
+                        out.push((labelled(row.first().map_or("", String::as_str)), value, *origin));
+                    }
+                }
+                Block::Section(title, blocks) => find(blocks, &labelled(title), query, out),
+                Block::Text(_) => {}
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    find(&blocks(page.page, &page.data), "", &query.trim().to_lowercase(), &mut out);
+    out
+}
+
+fn json(pages: &[PageData], generated: &str, raw_output: Option<BTreeMap<String, String>>) -> String {
+    let pages = pages
+        .iter()
+        .map(|page| {
+            json!({
+                "page": page.page.id(),
+                "title": page.title,
+                "data": page.data,
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut document = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "generated": generated,
+        "pages": pages,
+    });
+    if let Some(raw_output) = raw_output {
+        document["raw_output"] = json!(raw_output);
+    }
+    serde_json::to_string_pretty(&document).unwrap_or_default()
+}
+
+/// The current time in UTC, e.g. `2024-05-01 14:03:09 UTC`.
+pub fn timestamp() -> String {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs());
+    let (days, time) = (seconds / 86400, seconds % 86400);
+
+    // Converts days since the epoch to a civil date, after Howard Hinnant's
+    // `civil_from_days`.
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// The Fluent ids of the labels pages show for fields not named after them,
+/// such as a device's `serial` shown as "Serial Number". Those of one page,
+/// its [`DataProvider::labels`], take precedence over these, since the same
+/// name can mean different things on different pages.
+///
+/// [`DataProvider::labels`]: crate::pages::DataProvider::labels
+const LABELS: &[(&str, &str)] = &[
+    ("serial", "serial-number"),
+    ("model_name", "cpu-model-name"),
+    ("desktop", "desktop-environment"),
+    ("chassis", "chassis-type"),
+    ("decode", "decode-profiles"),
+    ("encode", "encode-profiles"),
+    ("core_version", "core-profile-version"),
+    ("compat_version", "compat-profile-version"),
+    ("es_version", "es-profile-version"),
+    ("information", "driver-info"),
+    ("vbios_version", "gpu-firmware"),
+    ("discard", "discard-support"),
+    ("raid_disks", "raid-devices"),
+    ("ac_online", "ac-adapter"),
+    ("plugin", "fwupd-plugin"),
+    ("formats", "pixel-formats"),
+    ("signal_quality", "signal"),
+    ("card_present", "card"),
+    ("ntp_enabled", "network-time"),
+    ("synchronized", "clock-synchronized"),
+    ("servers", "ntp-servers"),
+    ("offset", "clock-offset"),
+    ("phases", "boot-phases"),
+    ("slowest", "slowest-units"),
+    ("trim_timer", "periodic-trim"),
+    ("last_run", "trim-last-run"),
+    ("next_run", "trim-next-run"),
+    ("vg_size", "size"),
+    ("vg_free_count", "free-extents"),
+    ("mounts", "mounted-filesystems"),
+    ("slots", "memory-slots"),
+    ("correction_type", "error-correction"),
+    ("DOCUMENTATION_URL", "doc-url"),
+    ("ARCHITECTURE", "arch"),
+];
+
+/// Fields whose message of the same name is a sentence taking arguments
+/// rather than a label.
+const SENTENCES: &[&str] = &["enrolled_fingers", "performance_degraded", "scan_type"];
+
+/// The label `page` shows for the field `key`, so that exports and searches
+/// use the words on screen: the message the page or [`LABELS`] lists, or
+/// else the one named after the field in lowercase with dashes, e.g.
+/// `cycle-count` for `cycle_count`. Fields without either are named after
+/// the key, e.g. `line_size` as `Line size`.
+fn label(page: Page, key: &str) -> String {
+    let id = pages::provider(page)
+        .labels()
+        .iter()
+        .chain(LABELS)
+        .find(|(field, _)| *field == key)
+        .map_or_else(|| key.to_lowercase().replace(['_', ' '], "-"), |(_, id)| (*id).to_string());
+    if !SENTENCES.contains(&key) && LANGUAGE_LOADER.has(&id) {
+        return LANGUAGE_LOADER.get(&id);
+    }
+
+    let key = key.replace('_', " ");
+    let mut chars = key.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Splits a value that holds a result, either one from [`result`] or a
+/// `Result` serialized within the data such as each graphics API's, into
+/// its data or its error. Any other value is data.
+fn outcome(value: &Value) -> Result<&Value, &Value> {
+    let Value::Object(fields) = value else {
+        return Ok(value);
+    };
+    if fields.len() != 1 {
+        return Ok(value);
+    }
+    match (fields.get("Ok"), fields.get("Err").or(fields.get("error"))) {
+        (Some(data), _) => Ok(data),
+        (None, Some(error)) => Err(error),
+        (None, None) => Ok(value),
+    }
+}
+
+/// Whether `value` has structure of its own, rather than fitting on one line.
+fn is_nested(value: &Value) -> bool {
+    match outcome(value) {
+        Ok(Value::Object(fields)) => !fields.is_empty(),
+        Ok(Value::Array(items)) => items.iter().any(|item| outcome(item).is_ok_and(Value::is_object)),
+        _ => false,
+    }
+}
+
+/// `value` of `page` on one line, for the text formats.
+fn plain(page: Page, value: &Value) -> String {
+    match outcome(value) {
+        Err(error) => fl!("error-occurred-with-msg", error = plain(page, error)),
+        Ok(Value::Null) => "—".to_string(),
+        Ok(Value::Bool(true)) => fl!("yes"),
+        Ok(Value::Bool(false)) => fl!("no"),
+        Ok(Value::Number(number)) => number.to_string(),
+        Ok(Value::String(text)) => text.replace('\n', " "),
+        Ok(Value::Array(items)) if items.is_empty() => "—".to_string(),
+        Ok(Value::Array(items)) => items.iter().map(|item| plain(page, item)).collect::<Vec<_>>().join(", "),
+        Ok(Value::Object(fields)) => fields
+            .iter()
+            .map(|(key, value)| format!("{}: {}", label(page, key), plain(page, value)))
+            .collect::<Vec<_>>()
+            .join("; "),
+    }
+}
+
+/// The layout of a page's data shared by the Markdown and plain text formats.
+enum Block {
+    /// Key and value pairs, aligned in a column, each with where it is read from.
+    Fields(Vec<(String, String, &'static str)>),
+    /// A list of records, with a column per field, and where they are read from.
+    Table(Vec<String>, Vec<Vec<String>>, &'static str),
+    /// A titled part of the data, such as the state of the processor.
+    Section(String, Vec<Block>),
+    Text(String),
+}
+
+/// Lays out `value` of `page`: the flat fields of an object as one block of
+/// pairs, nested objects as sections and lists of objects as tables.
+fn blocks(page: Page, value: &Value) -> Vec<Block> {
+    lay_out(page, value, "", pages::provider(page).origin())
+}
+
+/// Lays out `value` found at `path` in the data of `page`, read from `origin`
+/// unless the page names another origin for the fields within.
+fn lay_out(page: Page, value: &Value, path: &str, origin: &'static str) -> Vec<Block> {
+    let data = match outcome(value) {
+        Ok(data) => data,
+        Err(_) => return vec![Block::Text(plain(page, value))],
+    };
+    match data {
+        Value::Object(fields) => {
+            let mut blocks = Vec::new();
+            let mut pairs = Vec::new();
+            for (key, value) in fields {
+                let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                let origin = pages::provider(page).field_origin(&path).unwrap_or(origin);
+                if !is_nested(value) {
+                    pairs.push((label(page, key), plain(page, value), origin));
+                    continue;
+                }
+                if !pairs.is_empty() {
+                    blocks.push(Block::Fields(std::mem::take(&mut pairs)));
+                }
+                blocks.push(Block::Section(label(page, key), lay_out(page, value, &path, origin)));
+            }
+            if !pairs.is_empty() {
+                blocks.push(Block::Fields(pairs));
+            }
+            blocks
+        }
+        Value::Array(items) if is_nested(data) => {
+            let records = items
+                .iter()
+                .filter_map(|item| outcome(item).ok()?.as_object())
+                .collect::<Vec<_>>();
+            let mut keys = Vec::<&String>::new();
+            for key in records.iter().flat_map(|record| record.keys()) {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            let rows = records
+                .iter()
+                .map(|record| {
+                    keys.iter()
+                        .map(|key| plain(page, record.get(key.as_str()).unwrap_or(&Value::Null)))
+                        .collect()
+                })
+                .collect();
+            vec![Block::Table(keys.iter().map(|key| label(page, key)).collect(), rows, origin)]
+        }
+        data => vec![Block::Text(plain(page, data))],
+    }
+}
+
+/// The suggested name of the file a page is exported to, e.g. `examine-processor.json`.
+pub fn file_name(page: Page, format: Format) -> String {
+    format!("examine-{}.{}", page.id(), format.extension())
+}
+
+/// The suggested name of the file the full report is saved to.
+pub fn report_file_name(format: Format) -> String {
+    format!("examine-report.{}", format.extension())
+}
+
+/// Asks where to save `contents` through the file chooser portal and writes
+/// it there. Returns the path written to, or `None` when the user cancelled.
+pub async fn save(title: String, file_name: String, contents: String) -> Result<Option<PathBuf>, String> {
+    let response = file_chooser::save::Dialog::new()
+        .title(title)
+        .file_name(file_name)
+        .save_file()
+        .await;
+    let path = match response {
+        Ok(response) => match response.url().and_then(|url| url.to_file_path().ok()) {
+            Some(path) => path,
+            None => return Ok(None),
+        },
+        Err(file_chooser::Error::Cancelled) => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+    tokio::fs::write(&path, contents)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_nested_fields() {
+        let mut value = json!({
+            "board": { "vendor": "LENOVO", "serial_number": "PF3ABCDE", "system_uuid": "4c4c4544-0042" },
+            "adapters": [{ "ssid": "home", "bssid": "aa:bb:cc:dd:ee:ff", "signal": 70 }],
+            "machine_id": null,
+        });
+        redact(&mut value);
+        let hidden = json!(fl!("redacted"));
+        assert_eq!(value["board"]["vendor"], "LENOVO");
+        assert_eq!(value["board"]["serial_number"], hidden);
+        assert_eq!(value["board"]["system_uuid"], hidden);
+        assert_eq!(value["adapters"][0]["ssid"], hidden);
+        assert_eq!(value["adapters"][0]["bssid"], hidden);
+        assert_eq!(value["adapters"][0]["signal"], 70);
+        assert_eq!(value["machine_id"], Value::Null);
+    }
+
+    #[test]
+    fn redacts_key_value_pairs() {
+        let mut value = json!({
+            "fru": [
+                ["Board Serial", "CN1234"],
+                ["Product Asset Tag", "IT-0042"],
+                ["Chassis Serial", "SGH123"],
+                ["Board Mfg", "Dell Inc."],
+            ],
+            "records": [{ "fields": [["Location", "Bay 3"], ["Manufacturer", "Samsung"]] }],
+        });
+        redact(&mut value);
+        let hidden = json!(fl!("redacted"));
+        assert_eq!(value["fru"][0], json!(["Board Serial", hidden]));
+        assert_eq!(value["fru"][1], json!(["Product Asset Tag", hidden]));
+        assert_eq!(value["fru"][2], json!(["Chassis Serial", hidden]));
+        assert_eq!(value["fru"][3], json!(["Board Mfg", "Dell Inc."]));
+        assert_eq!(value["records"][0]["fields"][0], json!(["Location", hidden]));
+        assert_eq!(value["records"][0]["fields"][1], json!(["Manufacturer", "Samsung"]));
+    }
+
+    #[test]
+    fn names_json_pages_by_id() {
+        let page = PageData { page: Page::Processor, title: "Processor".into(), data: json!({ "cores": 8 }) };
+        let document: Value = serde_json::from_str(&json(&[page], "2026-10-16", None)).unwrap();
+        assert_eq!(document["pages"][0]["page"], "processor");
+        assert_eq!(document["pages"][0]["data"]["cores"], 8);
+    }
+
+    fn processor(data: Value) -> PageData {
+        PageData { page: Page::Processor, title: "Processor".into(), data }
+    }
+
+    #[test]
+    fn searches_ignoring_case() {
+        let page = processor(json!({ "model_name": "AMD Ryzen 7 7840U", "cores": 8 }));
+        let hits = search(&page, " RYZEN ");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, label(Page::Processor, "model_name"));
+        assert_eq!(hits[0].1, "AMD Ryzen 7 7840U");
+    }
+
+    #[test]
+    fn searches_nested_values() {
+        let page = processor(json!({ "cores": 8, "info": { "microcode": "0xa704104" } }));
+        let hits = search(&page, "0xA704");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].0,
+            format!("{} › {}", label(Page::Processor, "info"), label(Page::Processor, "microcode"))
+        );
+        assert_eq!(hits[0].1, "0xa704104");
+    }
+
+    #[test]
+    fn searches_without_match() {
+        let page = processor(json!({ "model_name": "AMD Ryzen 7 7840U", "info": { "microcode": "0xa704104" } }));
+        assert!(search(&page, "intel").is_empty());
+    }
+
+    #[test]
+    fn labels_unknown_fields_after_their_key() {
+        assert_eq!(label(Page::Processor, "zz_line_size"), "Zz line size");
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! A single self-contained HTML document, with a table for each object.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use super::{label, outcome, PageData};
+use crate::fl;
+use crate::pages::Page;
+
+/// Styles the document, kept inline so that the file stands on its own.
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 70em; padding: 0 1em; color: #222; }
+h1 { margin-bottom: 0; }
+h2 { border-bottom: 1px solid #ccc; margin-top: 2em; padding-bottom: 0.2em; }
+nav ul { columns: 3; }
+table { border-collapse: collapse; margin: 0.2em 0; }
+th, td { border: 1px solid #ddd; padding: 0.2em 0.5em; text-align: left; vertical-align: top; }
+th { background: #f4f4f4; font-weight: 600; }
+.meta { color: #666; }
+.none { color: #999; }
+.error { color: #b00; }
+pre { background: #f4f4f4; overflow-x: auto; padding: 0.5em; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders `value` of `page` as HTML: objects as tables of fields, lists of
+/// objects as tables with a column per field, other lists as comma separated
+/// values.
+fn render(page: Page, value: &Value, out: &mut String) {
+    let value = match outcome(value) {
+        Ok(value) => value,
+        Err(error) => {
+            out.push_str("<span class=\"error\">");
+            render(page, error, out);
+            out.push_str("</span>");
+            return;
+        }
+    };
+    match value {
+        Value::Null => out.push_str("<span class=\"none\">—</span>"),
+        Value::Bool(true) => out.push_str(&escape(&fl!("yes"))),
+        Value::Bool(false) => out.push_str(&escape(&fl!("no"))),
+        Value::Number(number) => out.push_str(&number.to_string()),
+        Value::String(text) => out.push_str(&escape(text)),
+        Value::Array(items) if items.is_empty() => out.push_str("<span class=\"none\">—</span>"),
+        Value::Array(items) if items.iter().all(Value::is_object) => {
+            let mut columns = Vec::<&String>::new();
+            for key in items.iter().filter_map(Value::as_object).flat_map(|item| item.keys()) {
+                if !columns.contains(&key) {
+                    columns.push(key);
+                }
+            }
+            out.push_str("<table><tr>");
+            for column in &columns {
+                _ = write!(out, "<th>{}</th>", escape(&label(page, column)));
+            }
+            out.push_str("</tr>");
+            for item in items {
+                out.push_str("<tr>");
+                for column in &columns {
+                    out.push_str("<td>");
+                    render(page, item.get(column.as_str()).unwrap_or(&Value::Null), out);
+                    out.push_str("</td>");
+                }
+                out.push_str("</tr>");
+            }
+            out.push_str("</table>");
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push_str(", ");
+                }
+                render(page, item, out);
+            }
+        }
+        Value::Object(fields) => {
+            out.push_str("<table>");
+            for (key, value) in fields {
+                _ = write!(out, "<tr><th>{}</th><td>", escape(&label(page, key)));
+                render(page, value, out);
+                out.push_str("</td></tr>");
+            }
+            out.push_str("</table>");
+        }
+    }
+}
+
+/// A document with a linked section for each of `pages`.
+pub fn document(pages: &[PageData], generated: &str, raw_output: Option<BTreeMap<String, String>>) -> String {
+    let title = escape(&fl!("report-title"));
+    let generated = fl!("report-generated", time = generated, version = env!("CARGO_PKG_VERSION"));
+    let mut out = String::new();
+    _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n<p class=\"meta\">{}</p>\n",
+        escape(&generated),
+    );
+
+    if pages.len() > 1 {
+        out.push_str("<nav><ul>\n");
+        for (index, page) in pages.iter().enumerate() {
+            _ = writeln!(out, "<li><a href=\"#page-{index}\">{}</a></li>", escape(&page.title));
+        }
+        out.push_str("</ul></nav>\n");
+    }
+
+    for (index, page) in pages.iter().enumerate() {
+        _ = writeln!(out, "<section id=\"page-{index}\">\n<h2>{}</h2>", escape(&page.title));
+        render(page.page, &page.data, &mut out);
+        out.push_str("\n</section>\n");
+    }
+
+    if let Some(raw_output) = raw_output {
+        _ = writeln!(out, "<section>\n<h2>{}</h2>", escape(&fl!("raw-tool-output")));
+        for (command_line, output) in raw_output {
+            _ = writeln!(out, "<h3><code>{}</code></h3>\n<pre>{}</pre>", escape(&command_line), escape(&output));
+        }
+        out.push_str("</section>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Markdown for pasting into forums and issue trackers, with a heading per
+//! page and aligned tables.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{blocks, Block, PageData};
+use crate::fl;
+
+/// Escapes the pipes that would end a table cell.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// A table with its columns padded to the same width, so that it also
+/// reads well where the Markdown is not rendered.
+fn table(headers: &[String], rows: &[Vec<String>], out: &mut String) {
+    let headers = headers.iter().map(|header| escape(header)).collect::<Vec<_>>();
+    let rows = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| escape(cell)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let widths = headers
+        .iter()
+        .enumerate()
+        .map(|(column, header)| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .chain([header])
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(3)
+        })
+        .collect::<Vec<_>>();
+
+    let line = |cells: &[String], out: &mut String| {
+        out.push('|');
+        for (cell, width) in cells.iter().zip(widths.iter().copied()) {
+            _ = write!(out, " {cell:<width$} |");
+        }
+        out.push('\n');
+    };
+    line(&headers, out);
+    line(&widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>(), out);
+    for row in &rows {
+        line(row, out);
+    }
+    out.push('\n');
+}
+
+fn render(blocks: &[Block], level: usize, out: &mut String) {
+    for block in blocks {
+        match block {
+            Block::Fields(pairs) => {
+                let rows = pairs
+                    .iter()
+                    .map(|(key, value, _)| vec![key.clone(), value.clone()])
+                    .collect::<Vec<_>>();
+                table(&[fl!("export-field"), fl!("export-value")], &rows, out);
+            }
+            Block::Table(headers, rows, _) => table(headers, rows, out),
+            Block::Section(title, blocks) => {
+                // Markdown has six heading levels; deeper sections get a bold title.
+                if level <= 6 {
+                    _ = writeln!(out, "{} {}\n", "#".repeat(level), escape(title));
+                } else {
+                    _ = writeln!(out, "**{}**\n", escape(title));
+                }
+                render(blocks, level + 1, out);
+            }
+            Block::Text(text) => _ = writeln!(out, "{}\n", escape(text)),
+        }
+    }
+}
+
+/// A document with a second level heading for each of `pages`.
+pub fn document(pages: &[PageData], generated: &str, raw_output: Option<BTreeMap<String, String>>) -> String {
+    let mut out = String::new();
+    _ = writeln!(out, "# {}\n", fl!("report-title"));
+    _ = writeln!(
+        out,
+        "{}\n",
+        fl!("report-generated", time = generated, version = env!("CARGO_PKG_VERSION"))
+    );
+
+    for page in pages {
+        _ = writeln!(out, "## {}\n", page.title);
+        render(&blocks(page.page, &page.data), 3, &mut out);
+    }
+
+    if let Some(raw_output) = raw_output {
+        _ = writeln!(out, "## {}\n", fl!("raw-tool-output"));
+        for (command_line, output) in raw_output {
+            _ = writeln!(out, "### `{command_line}`\n\n```\n{}\n```\n", output.trim_end());
+        }
+    }
+
+    out
+}
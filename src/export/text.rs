@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Plain text with underlined headings and aligned columns, for places that
+//! show text as it is, such as mailing lists and terminals.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{blocks, Block, PageData};
+use crate::fl;
+
+/// How far each nested section is indented.
+const INDENT: usize = 2;
+
+fn heading(title: &str, underline: char, out: &mut String) {
+    _ = writeln!(out, "{title}\n{}\n", underline.to_string().repeat(title.chars().count()));
+}
+
+fn render(blocks: &[Block], indent: usize, out: &mut String) {
+    let margin = " ".repeat(indent);
+    for block in blocks {
+        match block {
+            Block::Fields(pairs) => {
+                let width = pairs.iter().map(|(key, _, _)| key.chars().count() + 1).max().unwrap_or(0);
+                for (key, value, _) in pairs {
+                    _ = writeln!(out, "{margin}{:<width$} {value}", format!("{key}:"));
+                }
+                out.push('\n');
+            }
+            Block::Table(headers, rows, _) => {
+                let widths = headers
+                    .iter()
+                    .enumerate()
+                    .map(|(column, header)| {
+                        rows.iter()
+                            .filter_map(|row| row.get(column))
+                            .chain([header])
+                            .map(|cell| cell.chars().count())
+                            .max()
+                            .unwrap_or(0)
+                    })
+                    .collect::<Vec<_>>();
+                let line = |cells: &[String], out: &mut String| {
+                    let cells = cells
+                        .iter()
+                        .zip(widths.iter().copied())
+                        .map(|(cell, width)| format!("{cell:<width$}"))
+                        .collect::<Vec<_>>();
+                    _ = writeln!(out, "{margin}{}", cells.join("  ").trim_end());
+                };
+                line(headers, out);
+                line(&widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>(), out);
+                for row in rows {
+                    line(row, out);
+                }
+                out.push('\n');
+            }
+            Block::Section(title, blocks) => {
+                _ = writeln!(out, "{margin}{title}");
+                render(blocks, indent + INDENT, out);
+            }
+            Block::Text(text) => _ = writeln!(out, "{margin}{text}\n"),
+        }
+    }
+}
+
+/// `page` under an underlined heading.
+pub fn page(page: &PageData, out: &mut String) {
+    heading(&page.title, '-', out);
+    render(&blocks(page.page, &page.data), 0, out);
+}
+
+/// A document with an underlined heading for each of `pages`.
+pub fn document(pages: &[PageData], generated: &str, raw_output: Option<BTreeMap<String, String>>) -> String {
+    let mut out = String::new();
+    heading(&fl!("report-title"), '=', &mut out);
+    _ = writeln!(
+        out,
+        "{}\n",
+        fl!("report-generated", time = generated, version = env!("CARGO_PKG_VERSION"))
+    );
+
+    for data in pages {
+        page(data, &mut out);
+    }
+
+    if let Some(raw_output) = raw_output {
+        heading(&fl!("raw-tool-output"), '-', &mut out);
+        for (command_line, output) in raw_output {
+            _ = writeln!(out, "$ {command_line}\n{}\n", output.trim_end());
+        }
+    }
+
+    out
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Queries fprintd over D-Bus for fingerprint readers.
+
+use serde::{Deserialize, Serialize};
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::host;
+
+#[zbus::proxy(
+    interface = "net.reactivated.Fprint.Manager",
+    default_service = "net.reactivated.Fprint",
+    default_path = "/net/reactivated/Fprint/Manager"
+)]
+trait Manager {
+    fn get_devices(&self) -> zbus::Result<Vec<OwnedObjectPath>>;
+}
+
+#[zbus::proxy(interface = "net.reactivated.Fprint.Device", default_service = "net.reactivated.Fprint")]
+trait Device {
+    fn list_enrolled_fingers(&self, username: &str) -> zbus::Result<Vec<String>>;
+
+    #[zbus(property, name = "name")]
+    fn name(&self) -> zbus::Result<String>;
+
+    #[zbus(property, name = "scan-type")]
+    fn scan_type(&self) -> zbus::Result<String>;
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Reader {
+    /// The libfprint driver's name for the sensor.
+    pub driver: String,
+    /// Either `press` or `swipe`.
+    pub scan_type: Option<String>,
+    /// Fingers enrolled for the current user.
+    pub enrolled_fingers: usize,
+}
+
+/// Collects all fingerprint readers. Fails when fprintd is not installed.
+pub fn collect() -> zbus::Result<Vec<Reader>> {
+    let connection = host::system_bus()?;
+    let username = std::env::var("USER").unwrap_or_default();
+
+    let readers = ManagerProxyBlocking::new(&connection)?
+        .get_devices()?
+        .into_iter()
+        .filter_map(|path| {
+            let device = DeviceProxyBlocking::builder(&connection).path(path).ok()?.build().ok()?;
+            Some(Reader {
+                driver: device.name().ok()?,
+                scan_type: device.scan_type().ok(),
+                // fprintd reports an error rather than an empty list when
+                // nothing is enrolled.
+                enrolled_fingers: device
+                    .list_enrolled_fingers(&username)
+                    .map(|fingers| fingers.len())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Ok(readers)
+}
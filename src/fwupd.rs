@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Queries the fwupd daemon over D-Bus for device firmware versions.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use zbus::zvariant::OwnedValue;
+
+use crate::host;
+
+/// Set by fwupd on devices whose firmware can be updated.
+const DEVICE_FLAG_UPDATABLE: u64 = 1 << 1;
+
+#[zbus::proxy(
+    interface = "org.freedesktop.fwupd",
+    default_service = "org.freedesktop.fwupd",
+    default_path = "/"
+)]
+trait Fwupd {
+    fn get_devices(&self) -> zbus::Result<Vec<HashMap<String, OwnedValue>>>;
+
+    fn get_upgrades(&self, device_id: &str) -> zbus::Result<Vec<HashMap<String, OwnedValue>>>;
+
+    #[zbus(property)]
+    fn daemon_version(&self) -> zbus::Result<String>;
+}
+
+/// A device known to fwupd.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Device {
+    pub name: String,
+    pub vendor: Option<String>,
+    pub version: Option<String>,
+    pub plugin: Option<String>,
+    pub updatable: bool,
+    /// The newest version offered by the configured remotes, if any.
+    pub upgrade: Option<String>,
+}
+
+/// The daemon version along with every device it manages.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Firmware {
+    pub daemon_version: String,
+    pub devices: Vec<Device>,
+}
+
+fn string(map: &HashMap<String, OwnedValue>, key: &str) -> Option<String> {
+    map.get(key)
+        .and_then(|value| value.downcast_ref::<&str>().ok())
+        .map(str::to_string)
+}
+
+/// Collects the device list from fwupd.
+pub fn collect() -> zbus::Result<Firmware> {
+    let connection = host::system_bus()?;
+    let proxy = FwupdProxyBlocking::new(&connection)?;
+
+    let devices = proxy
+        .get_devices()?
+        .iter()
+        .map(|device| {
+            let flags = device
+                .get("Flags")
+                .and_then(|value| value.downcast_ref::<u64>().ok())
+                .unwrap_or_default();
+            let updatable = flags & DEVICE_FLAG_UPDATABLE != 0;
+
+            // fwupd answers with an error rather than an empty list when
+            // there is nothing newer, so treat any failure as "no upgrade".
+            let upgrade = string(device, "DeviceId")
+                .filter(|_| updatable)
+                .and_then(|id| proxy.get_upgrades(&id).ok())
+                .and_then(|releases| releases.first().and_then(|release| string(release, "Version")));
+
+            Device {
+                name: string(device, "Name").unwrap_or_default(),
+                vendor: string(device, "Vendor"),
+                version: string(device, "Version"),
+                plugin: string(device, "Plugin"),
+                updatable,
+                upgrade,
+            }
+        })
+        .collect();
+
+    Ok(Firmware {
+        daemon_version: proxy.daemon_version()?,
+        devices,
+    })
+}
@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Collects graphics API capabilities from `vulkaninfo`, `glxinfo`, `vainfo` and `vdpauinfo`,
+//! along with each GPU's VBIOS version.
+
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+use nix::unistd::{access, AccessFlags};
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const DRM_DIR: &str = "/sys/class/drm";
+const DEV_DRI_DIR: &str = "/dev/dri";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VulkanDevice {
+    pub device_name: String,
+    pub device_type: Option<String>,
+    pub api_version: Option<String>,
+    pub driver_name: Option<String>,
+    pub driver_info: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OpenGl {
+    pub vendor: Option<String>,
+    pub renderer: Option<String>,
+    pub core_version: Option<String>,
+    pub compat_version: Option<String>,
+    pub es_version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VaApi {
+    pub api_version: Option<String>,
+    pub driver: Option<String>,
+    /// Profiles with a decode (`VAEntrypointVLD`) entrypoint.
+    pub decode: Vec<String>,
+    /// Profiles with an encode (`VAEntrypointEncSlice*`) entrypoint.
+    pub encode: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Vdpau {
+    pub information: Option<String>,
+    pub decode: Vec<String>,
+}
+
+/// A GPU and the version of its video BIOS.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Gpu {
+    /// The DRM card, e.g. `card1`.
+    pub card: String,
+    pub driver: Option<String>,
+    pub vbios_version: Option<String>,
+}
+
+/// A `/dev/dri/renderD*` node, used for rendering and video acceleration
+/// without display access.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RenderNode {
+    pub node: String,
+    /// The DRM card of the same GPU.
+    pub card: Option<String>,
+    pub driver: Option<String>,
+    /// The group owning the node, usually `render`.
+    pub group: Option<String>,
+    /// Permission bits, e.g. `0660`.
+    pub mode: u32,
+    /// Whether this process may open the node for reading and writing.
+    pub accessible: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Graphics {
+    pub gpus: Vec<Gpu>,
+    pub render_nodes: Vec<RenderNode>,
+    pub vulkan: Result<Vec<VulkanDevice>, String>,
+    pub opengl: Result<OpenGl, String>,
+    pub vaapi: Result<VaApi, String>,
+    pub vdpau: Result<Vdpau, String>,
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = host::output(host::command(program).args(args))
+        .map_err(|e| host::spawn_error(program, &e))?;
+    if !output.status.success() && output.stdout.is_empty() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses the `Devices:` section of `vulkaninfo --summary`.
+fn parse_vulkan(output: &str) -> Vec<VulkanDevice> {
+    let mut devices = Vec::new();
+    let mut current: Option<VulkanDevice> = None;
+
+    for line in output.lines().skip_while(|line| !line.starts_with("Devices:")) {
+        let line = line.trim();
+        if line.starts_with("GPU") && line.ends_with(':') {
+            devices.extend(current.take());
+            current = Some(VulkanDevice::default());
+            continue;
+        }
+        let (Some(device), Some((key, value))) = (current.as_mut(), line.split_once('=')) else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        match key.trim() {
+            "deviceName" => device.device_name = value,
+            "deviceType" => device.device_type = Some(value),
+            "apiVersion" => device.api_version = Some(value),
+            "driverName" => device.driver_name = Some(value),
+            "driverInfo" => device.driver_info = Some(value),
+            _ => {}
+        }
+    }
+    devices.extend(current);
+    devices
+}
+
+fn parse_opengl(output: &str) -> OpenGl {
+    let mut opengl = OpenGl::default();
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "OpenGL vendor string" => opengl.vendor = value,
+            "OpenGL renderer string" => opengl.renderer = value,
+            "OpenGL core profile version string" => opengl.core_version = value,
+            "OpenGL version string" => opengl.compat_version = value,
+            "OpenGL ES profile version string" => opengl.es_version = value,
+            _ => {}
+        }
+    }
+    opengl
+}
+
+fn parse_vaapi(output: &str) -> VaApi {
+    let mut vaapi = VaApi::default();
+    for line in output.lines() {
+        if let Some(version) = line.strip_prefix("vainfo: VA-API version:") {
+            vaapi.api_version = Some(version.trim().to_string());
+        } else if let Some(driver) = line.strip_prefix("vainfo: Driver version:") {
+            vaapi.driver = Some(driver.trim().to_string());
+        } else if let Some((profile, entrypoint)) = line.trim().split_once(':') {
+            let profile = profile.trim();
+            if !profile.starts_with("VAProfile") {
+                continue;
+            }
+            let profile = profile.trim_start_matches("VAProfile").to_string();
+            let list = match entrypoint.trim() {
+                "VAEntrypointVLD" => &mut vaapi.decode,
+                entrypoint if entrypoint.starts_with("VAEntrypointEncSlice") => &mut vaapi.encode,
+                _ => continue,
+            };
+            if !list.contains(&profile) {
+                list.push(profile);
+            }
+        }
+    }
+    vaapi
+}
+
+/// Parses the decoder table of `vdpauinfo`, keeping only supported profiles.
+fn parse_vdpau(output: &str) -> Vdpau {
+    let mut vdpau = Vdpau::default();
+    let mut in_decoders = false;
+    for line in output.lines() {
+        if let Some(information) = line.strip_prefix("Information string:") {
+            vdpau.information = Some(information.trim().to_string());
+        } else if line.starts_with("Decoder capabilities:") {
+            in_decoders = true;
+        } else if in_decoders {
+            if line.trim().is_empty() && !vdpau.decode.is_empty() {
+                break;
+            }
+            let mut columns = line.split_whitespace();
+            let (Some(name), Some(level)) = (columns.next(), columns.next()) else {
+                continue;
+            };
+            if level.parse::<i64>().is_ok() {
+                vdpau.decode.push(name.to_string());
+            }
+        }
+    }
+    vdpau
+}
+
+/// VBIOS versions reported by `nvidia-smi`, keyed by PCI slot in lowercase.
+fn nvidia_vbios() -> Vec<(String, String)> {
+    run(
+        "nvidia-smi",
+        &["--query-gpu=pci.bus_id,vbios_version", "--format=csv,noheader"],
+    )
+    .map(|output| {
+        output
+            .lines()
+            .filter_map(|line| line.split_once(','))
+            .map(|(slot, version)| (slot.trim().to_lowercase(), version.trim().to_string()))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Lists DRM cards with the VBIOS version from amdgpu's sysfs attribute or,
+/// for the proprietary NVIDIA driver, from `nvidia-smi`.
+fn collect_gpus() -> Vec<Gpu> {
+    let mut nvidia = None;
+    let mut gpus = fs::read_dir(host::path(DRM_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        // Connectors are named like `card1-DP-1`.
+        .filter(|name| name.starts_with("card") && !name.contains('-'))
+        .map(|card| {
+            let device = host::path(DRM_DIR).join(&card).join("device");
+            let driver = driver_name(&device);
+            let mut vbios_version = fs::read_to_string(device.join("vbios_version"))
+                .ok()
+                .map(|version| version.trim().to_string())
+                .filter(|version| !version.is_empty());
+
+            if vbios_version.is_none() && driver.as_deref() == Some("nvidia") {
+                // nvidia-smi pads the PCI domain to eight digits.
+                let slot = fs::canonicalize(&device)
+                    .ok()
+                    .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_lowercase()));
+                vbios_version = slot.and_then(|slot| {
+                    nvidia
+                        .get_or_insert_with(nvidia_vbios)
+                        .iter()
+                        .find(|(bus_id, _)| bus_id.ends_with(&slot))
+                        .map(|(_, version)| version.clone())
+                });
+            }
+
+            Gpu {
+                card,
+                driver,
+                vbios_version,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    gpus.sort_by(|a, b| a.card.cmp(&b.card));
+    gpus
+}
+
+fn driver_name(device: &Path) -> Option<String> {
+    fs::read_link(device.join("driver"))
+        .ok()
+        .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned()))
+}
+
+/// Looks up a group name in `/etc/group`.
+fn group_name(gid: u32) -> Option<String> {
+    fs::read_to_string(host::path("/etc/group")).ok()?.lines().find_map(|line| {
+        let mut fields = line.split(':');
+        let name = fields.next()?;
+        (fields.nth(1)?.parse::<u32>().ok()? == gid).then(|| name.to_string())
+    })
+}
+
+/// Lists render nodes with their GPU and who may open them.
+fn collect_render_nodes(gpus: &[Gpu]) -> Vec<RenderNode> {
+    let mut nodes = fs::read_dir(host::path(DEV_DRI_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("renderD"))
+        .filter_map(|entry| {
+            let node = entry.file_name().to_string_lossy().into_owned();
+            let metadata = entry.metadata().ok()?;
+            let device = fs::canonicalize(host::path(DRM_DIR).join(&node).join("device")).ok();
+            let card = device.as_ref().and_then(|device| {
+                gpus.iter()
+                    .find(|gpu| {
+                        fs::canonicalize(host::path(DRM_DIR).join(&gpu.card).join("device")).ok().as_ref()
+                            == Some(device)
+                    })
+                    .map(|gpu| gpu.card.clone())
+            });
+
+            Some(RenderNode {
+                card,
+                driver: device.as_deref().and_then(driver_name),
+                group: group_name(metadata.gid()),
+                mode: metadata.mode() & 0o7777,
+                accessible: access(&entry.path(), AccessFlags::R_OK | AccessFlags::W_OK).is_ok(),
+                node,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    nodes.sort_by(|a, b| a.node.cmp(&b.node));
+    nodes
+}
+
+pub fn collect() -> Graphics {
+    let gpus = collect_gpus();
+    Graphics {
+        render_nodes: collect_render_nodes(&gpus),
+        gpus,
+        vulkan: run("vulkaninfo", &["--summary"]).map(|output| parse_vulkan(&output)),
+        opengl: run("glxinfo", &["-B"]).map(|output| parse_opengl(&output)),
+        // vainfo prints its header on stderr on some versions, so merge both.
+        vaapi: host::output(&mut host::command("vainfo"))
+            .map_err(|e| host::spawn_error("vainfo", &e).to_string())
+            .map(|output| {
+                let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+                text.push_str(&String::from_utf8_lossy(&output.stderr));
+                parse_vaapi(&text)
+            }),
+        vdpau: run("vdpauinfo", &[]).map(|output| parse_vdpau(&output)),
+    }
+}
@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Runs tools on the host system. Inside the Flatpak sandbox the tools are
+//! missing and would only see the sandbox, so commands go through
+//! `flatpak-spawn --host` instead.
+//!
+//! In demo mode every read of the system goes to sample data instead: files
+//! through [`path`], tool output through [`output`] and D-Bus services
+//! through [`system_bus`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use nix::sys::signal::{self, Signal};
+use nix::unistd::Pid;
+use serde::{Deserialize, Serialize};
+
+/// How long a tool may run when no timeout has been configured.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a running tool is checked for exit, timeout or cancellation.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How long a tool asked to stop gets to exit before it is killed.
+const STOP_GRACE: Duration = Duration::from_millis(500);
+
+static SANDBOXED: LazyLock<bool> = LazyLock::new(|| Path::new("/.flatpak-info").exists());
+
+static TIMEOUT_MS: AtomicU64 = AtomicU64::new(DEFAULT_TIMEOUT.as_millis() as u64);
+
+/// Bumped by [`cancel_running`]; tools started before the bump are stopped.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// The directory of sample data standing in for the root filesystem in demo mode.
+static DEMO_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// The latest output of every command line run through [`output`], kept
+/// verbatim so that exports can show what the parsed values came from.
+static RAW_OUTPUT: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+/// Whether Examine runs inside the Flatpak sandbox.
+pub fn is_sandboxed() -> bool {
+    *SANDBOXED
+}
+
+/// Switches to demo mode, reading files and tool output from `root`. Tool
+/// output is kept in `root/commands`, one file per command line.
+pub fn set_demo_root(root: PathBuf) {
+    _ = DEMO_ROOT.set(root);
+}
+
+/// The directory of the sample data, in demo mode.
+pub fn demo_root() -> Option<&'static Path> {
+    DEMO_ROOT.get().map(PathBuf::as_path)
+}
+
+/// Whether Examine shows sample data rather than this system's.
+pub fn is_demo() -> bool {
+    DEMO_ROOT.get().is_some()
+}
+
+/// Where to read the system file or directory `path`, which is `path`
+/// itself unless in demo mode.
+pub fn path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    match DEMO_ROOT.get() {
+        Some(root) => root.join(path.strip_prefix("/").unwrap_or(path)),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Connects to the system bus. Demo mode has no services to ask, so the
+/// pages reading them fail as on a system without them.
+pub fn system_bus() -> zbus::Result<zbus::blocking::Connection> {
+    if is_demo() {
+        return Err(zbus::Error::Failure("not available in demo mode".to_string()));
+    }
+    zbus::blocking::Connection::system()
+}
+
+/// Builds a command that runs `program` on the host.
+pub fn command(program: &str) -> Command {
+    if is_sandboxed() {
+        let mut command = Command::new("flatpak-spawn");
+        command.args(["--host", program]);
+        command
+    } else {
+        Command::new(program)
+    }
+}
+
+/// Sets how long [`output`] lets a tool run before stopping it.
+pub fn set_timeout(timeout: Duration) {
+    TIMEOUT_MS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+fn timeout() -> Duration {
+    Duration::from_millis(TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
+/// Stops every tool currently running through [`output`].
+pub fn cancel_running() {
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Reads a pipe to the end on its own thread, so that a tool writing a lot
+/// to one pipe does not block while the other is being waited on.
+fn drain(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// The command line of `command` as run on the host, without the
+/// `flatpak-spawn --host` prefix.
+fn command_line(command: &Command) -> String {
+    let mut words = std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|word| word.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    if words.first().is_some_and(|word| word == "flatpak-spawn") {
+        words.drain(..2);
+    }
+    words.join(" ")
+}
+
+/// The latest output of every command line that has run, in order of the
+/// command lines.
+pub fn raw_output() -> BTreeMap<String, String> {
+    RAW_OUTPUT.lock().map(|outputs| outputs.clone()).unwrap_or_default()
+}
+
+/// Returns the captured output of `command` in demo mode, from the file named
+/// after its command line with slashes replaced. A command without a capture
+/// fails like a missing tool.
+fn replay(root: &Path, command: &Command) -> io::Result<Output> {
+    let name = command_line(command).replace('/', "_");
+    Ok(Output {
+        status: ExitStatus::default(),
+        stdout: fs::read(root.join("commands").join(name))?,
+        stderr: Vec::new(),
+    })
+}
+
+/// Runs `command` to completion like [`Command::output`], but stops it once
+/// the configured timeout passes or [`cancel_running`] is called. Not meant
+/// for `pkexec`, which waits on the user to authenticate.
+pub fn output(command: &mut Command) -> io::Result<Output> {
+    let output = match DEMO_ROOT.get() {
+        Some(root) => replay(root, command)?,
+        None => run(command)?,
+    };
+    record(command, &output.stdout);
+    Ok(output)
+}
+
+/// Keeps `stdout` as the latest output of `command` for [`raw_output`].
+pub fn record(command: &Command, stdout: &[u8]) {
+    if let Ok(mut outputs) = RAW_OUTPUT.lock() {
+        outputs.insert(command_line(command), String::from_utf8_lossy(stdout).into_owned());
+    }
+}
+
+fn run(command: &mut Command) -> io::Result<Output> {
+    let generation = GENERATION.load(Ordering::Relaxed);
+    let deadline = Instant::now() + timeout();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let error = if GENERATION.load(Ordering::Relaxed) != generation {
+            io::Error::new(io::ErrorKind::Interrupted, "cancelled")
+        } else if Instant::now() >= deadline {
+            io::Error::new(io::ErrorKind::TimedOut, "timed out")
+        } else {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        };
+        stop(&mut child);
+        return Err(error);
+    };
+
+    let collect = |pipe: Option<JoinHandle<Vec<u8>>>| {
+        pipe.and_then(|pipe| pipe.join().ok()).unwrap_or_default()
+    };
+    Ok(Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+    })
+}
+
+/// Stops a tool that timed out or was cancelled. It is asked to terminate
+/// first, since `flatpak-spawn` forwards SIGTERM to the tool on the host but
+/// cannot forward SIGKILL, and only killed if it has not exited shortly after.
+fn stop(child: &mut Child) {
+    if let Ok(pid) = i32::try_from(child.id()) {
+        _ = signal::kill(Pid::from_raw(pid), Signal::SIGTERM);
+        let deadline = Instant::now() + STOP_GRACE;
+        while Instant::now() < deadline {
+            if !matches!(child.try_wait(), Ok(None)) {
+                return;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+    _ = child.kill();
+    _ = child.wait();
+}
+
+/// Why a tool did not produce its output.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Error {
+    /// The tool, named here, is not installed.
+    Missing(String),
+    /// The tool was not allowed to read the information.
+    PermissionDenied(String),
+    /// The tool did not finish before the timeout.
+    TimedOut(String),
+    /// The tool was stopped by [`cancel_running`].
+    Cancelled(String),
+    /// The tool ran but failed, with its error output.
+    Failed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(program) => write!(f, "{program} is not installed"),
+            Self::PermissionDenied(program) => write!(f, "{program}: permission denied"),
+            Self::TimedOut(program) => write!(f, "{program} timed out"),
+            Self::Cancelled(program) => write!(f, "{program} was cancelled"),
+            Self::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<Error> for String {
+    fn from(error: Error) -> Self {
+        error.to_string()
+    }
+}
+
+/// Classifies a failure to run `program`. In the sandbox a missing
+/// `flatpak-spawn` is reported rather than the tool.
+pub fn spawn_error(program: &str, error: &io::Error) -> Error {
+    match (error.kind(), is_sandboxed()) {
+        (io::ErrorKind::NotFound, true) => Error::Missing("flatpak-spawn".to_string()),
+        (io::ErrorKind::NotFound, false) => Error::Missing(program.to_string()),
+        (io::ErrorKind::PermissionDenied, _) => Error::PermissionDenied(program.to_string()),
+        (io::ErrorKind::TimedOut, _) => Error::TimedOut(program.to_string()),
+        (io::ErrorKind::Interrupted, _) => Error::Cancelled(program.to_string()),
+        _ => Error::Failed(format!("{program}: {error}")),
+    }
+}
+
+/// Classifies a failure to read the system file `path`, named as on the
+/// system rather than in the demo data.
+pub fn read_error(path: impl AsRef<Path>, error: &io::Error) -> Error {
+    let path = path.as_ref().display().to_string();
+    match error.kind() {
+        io::ErrorKind::PermissionDenied => Error::PermissionDenied(path),
+        io::ErrorKind::TimedOut => Error::TimedOut(path),
+        io::ErrorKind::Interrupted => Error::Cancelled(path),
+        _ => Error::Failed(format!("{path}: {error}")),
+    }
+}
+
+/// Classifies a failure to query the D-Bus service of `program`. A service
+/// that is not running reports the program as missing.
+pub fn bus_error(program: &str, error: &zbus::Error) -> Error {
+    let zbus::Error::MethodError(name, _, _) = error else {
+        return Error::Failed(format!("{program}: {error}"));
+    };
+    match name.as_str() {
+        "org.freedesktop.DBus.Error.ServiceUnknown" | "org.freedesktop.DBus.Error.NameHasNoOwner" => {
+            Error::Missing(program.to_string())
+        }
+        "org.freedesktop.DBus.Error.AccessDenied" | "org.freedesktop.DBus.Error.AuthFailed" => {
+            Error::PermissionDenied(program.to_string())
+        }
+        "org.freedesktop.DBus.Error.Timeout" | "org.freedesktop.DBus.Error.NoReply" => {
+            Error::TimedOut(program.to_string())
+        }
+        _ => Error::Failed(format!("{program}: {error}")),
+    }
+}
+
+/// The package providing `program` on most distributions, where it is not
+/// named after the tool.
+pub fn package(program: &str) -> &str {
+    match program {
+        "smartctl" => "smartmontools",
+        "nvme" => "nvme-cli",
+        "vulkaninfo" => "vulkan-tools",
+        "glxinfo" => "mesa-utils",
+        "vainfo" => "libva-utils",
+        "lspci" => "pciutils",
+        "v4l2-ctl" => "v4l-utils",
+        "opensc-tool" => "opensc",
+        "tpm2_getcap" => "tpm2-tools",
+        "vgs" | "pvs" | "lvs" => "lvm2",
+        "systemd-analyze" => "systemd",
+        "pw-metadata" | "pw-top" => "pipewire",
+        "flatpak-spawn" => "flatpak",
+        "systemd-hostnamed" => "systemd",
+        program => program,
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Watches the kernel's uevents for devices being plugged in or removed, the
+//! same netlink broadcast udev itself listens to.
+
+use std::io;
+use std::os::fd::AsRawFd;
+
+use cosmic::iced::stream;
+use futures_util::{SinkExt, Stream};
+use log::warn;
+use nix::sys::socket::{bind, recv, socket, AddressFamily, MsgFlags, NetlinkAddr, SockFlag, SockProtocol, SockType};
+
+use crate::pages::Page;
+
+/// The multicast group of events sent by the kernel, before udev processes them.
+const KERNEL_GROUP: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Added,
+    Removed,
+}
+
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub action: Action,
+    pub subsystem: String,
+    pub devtype: Option<String>,
+}
+
+impl Event {
+    /// The page listing the device, if any shows it. USB interfaces and
+    /// partitions are skipped since their device already raised an event.
+    pub fn page(&self) -> Option<Page> {
+        match (self.subsystem.as_str(), self.devtype.as_deref()) {
+            ("usb", Some("usb_device")) => Some(Page::USBs),
+            ("pci", _) => Some(Page::PCIs),
+            ("block", Some("disk")) => Some(Page::Filesystems),
+            ("input", _) => Some(Page::Controllers),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a uevent, `add@/devices/...` followed by `KEY=value` lines, all
+/// separated by NUL bytes.
+fn parse(message: &[u8]) -> Option<Event> {
+    let mut action = None;
+    let mut subsystem = None;
+    let mut devtype = None;
+    for field in message.split(|&byte| byte == 0).skip(1) {
+        let field = String::from_utf8_lossy(field);
+        let Some((key, value)) = field.split_once('=') else {
+            continue;
+        };
+        match key {
+            "ACTION" => {
+                action = match value {
+                    "add" => Some(Action::Added),
+                    "remove" => Some(Action::Removed),
+                    _ => None,
+                }
+            }
+            "SUBSYSTEM" => subsystem = Some(value.to_string()),
+            "DEVTYPE" => devtype = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    Some(Event {
+        action: action?,
+        subsystem: subsystem?,
+        devtype,
+    })
+}
+
+/// Reads uevents until the receiver goes away.
+fn watch(sender: tokio::sync::mpsc::Sender<Event>) -> io::Result<()> {
+    let socket = socket(
+        AddressFamily::Netlink,
+        SockType::Datagram,
+        SockFlag::SOCK_CLOEXEC,
+        SockProtocol::NetlinkKObjectUEvent,
+    )?;
+    bind(socket.as_raw_fd(), &NetlinkAddr::new(0, KERNEL_GROUP))?;
+
+    let mut buffer = [0; 8192];
+    loop {
+        let len = recv(socket.as_raw_fd(), &mut buffer, MsgFlags::empty())?;
+        if let Some(event) = parse(&buffer[..len]) {
+            if sender.blocking_send(event).is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Devices being added or removed, for as long as the subscription runs.
+pub fn events() -> impl Stream<Item = Event> {
+    stream::channel(16, |mut output| async move {
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(16);
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = watch(sender) {
+                warn!("cannot watch for hotplugged devices: {}", e);
+            }
+        });
+        while let Some(event) = receiver.recv().await {
+            _ = output.send(event).await;
+        }
+        futures_util::future::pending().await
+    })
+}
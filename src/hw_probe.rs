@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Submits this computer's hardware to the Linux hardware database at
+//! linux-hardware.org with `hw-probe`, run as root by `examine-probe` since
+//! most of what it collects needs root to read.
+
+use crate::{host, privileged};
+
+/// Where hw-probe explains what it collects and how it anonymizes it.
+pub const PRIVACY_URL: &str = "https://github.com/linuxhw/hw-probe#privacy";
+
+/// Collects and uploads a probe, returning the address of its public page.
+pub fn submit() -> Result<String, host::Error> {
+    let output = privileged::probe()?;
+    probe_url(&output).ok_or_else(|| {
+        // The last lines say why the upload failed.
+        let tail = output.lines().rev().take(3).collect::<Vec<_>>();
+        host::Error::Failed(tail.into_iter().rev().collect::<Vec<_>>().join("\n"))
+    })
+}
+
+/// Finds the `Probe URL: https://linux-hardware.org/?probe=…` line hw-probe
+/// prints after a successful upload.
+fn probe_url(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let (_, url) = line.split_once("Probe URL:")?;
+        Some(url.trim().to_string()).filter(|url| url.starts_with("https://"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_probe_url() {
+        let output = "Probe for hardware ... Ok
+Reading logs ... Ok
+Uploaded to DB, Thank you!
+
+Probe URL: https://linux-hardware.org/?probe=0123456789
+";
+        assert_eq!(probe_url(output).as_deref(), Some("https://linux-hardware.org/?probe=0123456789"));
+    }
+
+    #[test]
+    fn rejects_missing_or_malformed_url() {
+        assert_eq!(probe_url(""), None);
+        assert_eq!(probe_url("ERROR: failed to upload data\n"), None);
+        assert_eq!(probe_url("Probe URL:\n"), None);
+        assert_eq!(probe_url("Probe URL: http://linux-hardware.org/?probe=1\n"), None);
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Resolves vendor, device and class IDs to names from the local `pci.ids`
+//! and `usb.ids` databases, so names are available without lspci and lsusb
+//! and for devices newer than those tools.
+
+use std::collections::HashMap;
+use std::fs;
+use std::sync::LazyLock;
+
+/// Where distributions install the databases: hwdata on Fedora and Arch,
+/// pciutils and usbutils on Debian, and the host's copy inside the Flatpak.
+const PCI_IDS: &[&str] = &[
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+    "/run/host/usr/share/hwdata/pci.ids",
+    "/run/host/usr/share/misc/pci.ids",
+];
+const USB_IDS: &[&str] = &[
+    "/usr/share/hwdata/usb.ids",
+    "/usr/share/misc/usb.ids",
+    "/usr/share/usb.ids",
+    "/run/host/usr/share/hwdata/usb.ids",
+    "/run/host/usr/share/misc/usb.ids",
+];
+
+static PCI: LazyLock<Database> = LazyLock::new(|| Database::load(PCI_IDS));
+static USB: LazyLock<Database> = LazyLock::new(|| Database::load(USB_IDS));
+
+#[derive(Debug, Default)]
+struct Vendor {
+    name: String,
+    devices: HashMap<u16, String>,
+}
+
+#[derive(Debug, Default)]
+struct Database {
+    vendors: HashMap<u16, Vendor>,
+    /// Class names keyed by class, and subclass names by class and subclass.
+    classes: HashMap<(u8, Option<u8>), String>,
+}
+
+/// Splits `1234  Name` into its hexadecimal ID and name.
+fn id_and_name(line: &str) -> Option<(&str, &str)> {
+    let (id, name) = line.split_once("  ")?;
+    Some((id.trim(), name.trim()))
+}
+
+impl Database {
+    fn load(paths: &[&str]) -> Self {
+        paths
+            .iter()
+            .find_map(|path| fs::read(path).ok())
+            .map(|bytes| Self::parse(&String::from_utf8_lossy(&bytes)))
+            .unwrap_or_default()
+    }
+
+    /// Parses the ids format: vendors at the start of a line, their devices
+    /// indented by one tab and subsystems by two. Other sections, such as
+    /// classes, start with a keyword like `C`.
+    fn parse(text: &str) -> Self {
+        let mut database = Self::default();
+        let mut vendor = None;
+        let mut class = None;
+
+        for line in text.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("C ") {
+                vendor = None;
+                class = id_and_name(rest).and_then(|(id, name)| {
+                    let id = u8::from_str_radix(id, 16).ok()?;
+                    database.classes.insert((id, None), name.to_string());
+                    Some(id)
+                });
+            } else if let Some(rest) = line.strip_prefix('\t') {
+                if rest.starts_with('\t') {
+                    continue;
+                }
+                let Some((id, name)) = id_and_name(rest) else {
+                    continue;
+                };
+                if let Some(vendor) = vendor.and_then(|vendor| database.vendors.get_mut(&vendor)) {
+                    if let Ok(id) = u16::from_str_radix(id, 16) {
+                        vendor.devices.insert(id, name.to_string());
+                    }
+                } else if let (Some(class), Ok(id)) = (class, u8::from_str_radix(id, 16)) {
+                    database.classes.insert((class, Some(id)), name.to_string());
+                }
+            } else {
+                class = None;
+                vendor = id_and_name(line).and_then(|(id, name)| {
+                    let id = u16::from_str_radix(id, 16).ok()?;
+                    database.vendors.insert(
+                        id,
+                        Vendor {
+                            name: name.to_string(),
+                            devices: HashMap::new(),
+                        },
+                    );
+                    Some(id)
+                });
+            }
+        }
+        database
+    }
+
+    fn vendor(&self, vendor: u16) -> Option<&str> {
+        self.vendors.get(&vendor).map(|vendor| vendor.name.as_str())
+    }
+
+    fn device(&self, vendor: u16, device: u16) -> Option<&str> {
+        self.vendors.get(&vendor)?.devices.get(&device).map(String::as_str)
+    }
+}
+
+pub fn pci_vendor(vendor: u16) -> Option<&'static str> {
+    PCI.vendor(vendor)
+}
+
+pub fn pci_device(vendor: u16, device: u16) -> Option<&'static str> {
+    PCI.device(vendor, device)
+}
+
+/// The most specific name for a PCI class, e.g. `VGA compatible controller`.
+pub fn pci_class(class: u8, subclass: u8) -> Option<&'static str> {
+    PCI.classes
+        .get(&(class, Some(subclass)))
+        .or_else(|| PCI.classes.get(&(class, None)))
+        .map(String::as_str)
+}
+
+pub fn usb_vendor(vendor: u16) -> Option<&'static str> {
+    USB.vendor(vendor)
+}
+
+pub fn usb_device(vendor: u16, device: u16) -> Option<&'static str> {
+    USB.device(vendor, device)
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Queries systemd-hostnamed over D-Bus for the system's identity.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[zbus::proxy(
+    interface = "org.freedesktop.hostname1",
+    default_service = "org.freedesktop.hostname1",
+    default_path = "/org/freedesktop/hostname1"
+)]
+trait Hostname {
+    #[zbus(property)]
+    fn hostname(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn static_hostname(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn pretty_hostname(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn chassis(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn deployment(&self) -> zbus::Result<String>;
+
+    #[zbus(property)]
+    fn location(&self) -> zbus::Result<String>;
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Identity {
+    /// The transient hostname currently in use.
+    pub hostname: Option<String>,
+    pub static_hostname: Option<String>,
+    pub pretty_hostname: Option<String>,
+    /// `desktop`, `laptop`, `server`, `vm`, …
+    pub chassis: Option<String>,
+    /// E.g. `production` or `staging`, as set with `hostnamectl deployment`.
+    pub deployment: Option<String>,
+    pub location: Option<String>,
+    pub machine_id: Option<String>,
+}
+
+fn non_empty(value: zbus::Result<String>) -> Option<String> {
+    value.ok().filter(|value| !value.is_empty())
+}
+
+/// Collects the identity. Fails when hostnamed cannot be reached.
+pub fn collect() -> zbus::Result<Identity> {
+    let connection = host::system_bus()?;
+    let proxy = HostnameProxyBlocking::new(&connection)?;
+
+    Ok(Identity {
+        hostname: non_empty(proxy.hostname()),
+        static_hostname: non_empty(proxy.static_hostname()),
+        pretty_hostname: non_empty(proxy.pretty_hostname()),
+        chassis: non_empty(proxy.chassis()),
+        deployment: non_empty(proxy.deployment()),
+        location: non_empty(proxy.location()),
+        machine_id: fs::read_to_string(host::path("/etc/machine-id"))
+            .ok()
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty()),
+    })
+}
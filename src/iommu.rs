@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads IOMMU groups from `/sys/kernel/iommu_groups`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const GROUPS_DIR: &str = "/sys/kernel/iommu_groups";
+const IOMMU_CLASS_DIR: &str = "/sys/class/iommu";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Iommu {
+    /// Whether an IOMMU driver such as `dmar` or `AMD-Vi` is active.
+    pub enabled: bool,
+    /// PCI slots, e.g. `0000:01:00.0`, by group number.
+    pub groups: BTreeMap<u32, Vec<String>>,
+}
+
+impl Iommu {
+    /// The group of a slot as printed by `lspci`, which omits the `0000:` domain.
+    pub fn group_of(&self, slot: &str) -> Option<u32> {
+        self.groups
+            .iter()
+            .find(|(_, slots)| slots.iter().any(|device| device.strip_prefix("0000:").unwrap_or(device) == slot))
+            .map(|(group, _)| *group)
+    }
+}
+
+fn names(dir: impl AsRef<Path>) -> Vec<String> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect()
+}
+
+pub fn collect() -> Iommu {
+    let groups = names(host::path(GROUPS_DIR))
+        .into_iter()
+        .filter_map(|group| {
+            let mut slots = names(host::path(GROUPS_DIR).join(&group).join("devices"));
+            slots.sort();
+            Some((group.parse().ok()?, slots))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    Iommu {
+        enabled: !names(host::path(IOMMU_CLASS_DIR)).is_empty() || !groups.is_empty(),
+        groups,
+    }
+}
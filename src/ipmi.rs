@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads the baseboard management controller through `ipmitool`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, privileged};
+
+const DEVICE: &str = "/dev/ipmi0";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Sensor {
+    pub name: String,
+    pub reading: String,
+    /// `ok`, `ns` (no reading), `cr` (critical), …
+    pub status: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Bmc {
+    pub manufacturer: Option<String>,
+    pub firmware_revision: Option<String>,
+    pub ipmi_version: Option<String>,
+    pub sensors: Vec<Sensor>,
+    /// Field replaceable unit inventory as printed by `ipmitool fru print`.
+    pub fru: Vec<(String, String)>,
+}
+
+/// Whether the kernel's IPMI driver found a BMC.
+pub fn is_present() -> bool {
+    host::path(DEVICE).exists()
+}
+
+/// Runs one query, directly or through the helper when `privileged`.
+fn run(query: &str, privileged: bool) -> Result<String, host::Error> {
+    if privileged {
+        return privileged::run(&["ipmi", query]);
+    }
+
+    let args: &[&str] = match query {
+        "mc" => &["mc", "info"],
+        "fru" => &["fru", "print"],
+        _ => &["sdr"],
+    };
+    let output = host::output(host::command("ipmitool").args(args))
+        .map_err(|e| host::spawn_error("ipmitool", &e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        // Users other than root usually may not open the device.
+        if stderr.contains("Permission denied") {
+            return Err(host::Error::PermissionDenied("ipmitool".to_string()));
+        }
+        return Err(host::Error::Failed(stderr));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Splits `Key : Value` lines, skipping lines without a value.
+fn key_values(output: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    output.lines().filter_map(|line| {
+        let (key, value) = line.split_once(" : ")?;
+        let value = value.trim();
+        (!value.is_empty()).then(|| (key.trim().to_string(), value.to_string()))
+    })
+}
+
+/// Parses `ipmitool sdr`, one `name | reading | status` row per sensor.
+fn parse_sensors(output: &str) -> Vec<Sensor> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split('|').map(str::trim);
+            Some(Sensor {
+                name: columns.next()?.to_string(),
+                reading: columns.next()?.to_string(),
+                status: columns.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Collects the BMC's identity, sensors and FRU data. `/dev/ipmi0` is usually
+/// only accessible to root, in which case `privileged` asks through polkit.
+pub fn collect(privileged: bool) -> Result<Bmc, host::Error> {
+    let mut bmc = Bmc::default();
+    for (key, value) in key_values(&run("mc", privileged)?) {
+        match key.as_str() {
+            "Manufacturer Name" => bmc.manufacturer = Some(value),
+            "Firmware Revision" => bmc.firmware_revision = Some(value),
+            "IPMI Version" => bmc.ipmi_version = Some(value),
+            _ => {}
+        }
+    }
+
+    bmc.sensors = parse_sensors(&run("sdr", privileged)?);
+    // Not every BMC has FRU data, so its absence is not an error.
+    bmc.fru = run("fru", privileged)
+        .map(|output| key_values(&output).collect())
+        .unwrap_or_default();
+
+    Ok(bmc)
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads uptime and load averages from `/proc/uptime` and `/proc/loadavg`.
+
+use std::fs;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Load {
+    pub uptime: Duration,
+    /// The 1, 5 and 15 minute load averages.
+    pub averages: [f32; 3],
+}
+
+fn read(path: &str) -> Result<String, host::Error> {
+    fs::read_to_string(host::path(path)).map_err(|e| host::read_error(path, &e))
+}
+
+fn invalid(path: &str) -> host::Error {
+    host::Error::Failed(format!("unexpected contents in {path}"))
+}
+
+pub fn collect() -> Result<Load, host::Error> {
+    let uptime = read("/proc/uptime")?
+        .split_whitespace()
+        .next()
+        .and_then(|seconds| seconds.parse::<f64>().ok())
+        .ok_or_else(|| invalid("/proc/uptime"))?;
+
+    let loadavg = read("/proc/loadavg")?;
+    let mut averages = loadavg.split_whitespace().filter_map(|value| value.parse::<f32>().ok());
+    let averages = [averages.next(), averages.next(), averages.next()];
+    let [Some(one), Some(five), Some(fifteen)] = averages else {
+        return Err(invalid("/proc/loadavg"));
+    };
+
+    Ok(Load {
+        uptime: Duration::from_secs_f64(uptime),
+        averages: [one, five, fifteen],
+    })
+}
+
+/// The boot time in local time, as printed by `uptime --since`.
+pub fn boot_time() -> Option<String> {
+    let output = host::output(host::command("uptime").arg("--since")).ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
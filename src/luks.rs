@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Detects LUKS containers from udev and device-mapper sysfs data, and reads
+//! their cipher with `cryptsetup status` when permitted.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage::Disk;
+
+use crate::host;
+
+const SYS_BLOCK_DIR: &str = "/sys/block";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Container {
+    /// The partition holding the LUKS header, e.g. `nvme0n1p3`.
+    pub device: String,
+    /// The device-mapper name of the unlocked container, `None` while locked.
+    pub mapping: Option<String>,
+    /// `LUKS1` or `LUKS2`.
+    pub version: Option<String>,
+    /// Only readable by root; `None` otherwise or while locked.
+    pub cipher: Option<String>,
+    pub key_size: Option<String>,
+}
+
+struct Mapping {
+    name: String,
+    version: Option<String>,
+    slaves: Vec<String>,
+}
+
+/// Lists dm-crypt mappings. Their dm UUID has the form `CRYPT-LUKS2-<uuid>-<name>`.
+fn mappings() -> Vec<Mapping> {
+    fs::read_dir(host::path(SYS_BLOCK_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("dm-"))
+        .filter_map(|entry| {
+            let path = entry.path();
+            let uuid = fs::read_to_string(path.join("dm/uuid")).ok()?;
+            let kind = uuid.trim().strip_prefix("CRYPT-")?;
+            let name = fs::read_to_string(path.join("dm/name")).ok()?.trim().to_string();
+            let slaves = fs::read_dir(path.join("slaves"))
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .map(|slave| slave.file_name().to_string_lossy().into_owned())
+                .collect();
+
+            Some(Mapping {
+                name,
+                version: kind.split('-').next().filter(|kind| kind.starts_with("LUKS")).map(str::to_string),
+                slaves,
+            })
+        })
+        .collect()
+}
+
+/// Reads cipher and key size from `cryptsetup status`, which needs root.
+fn status(mapping: &str) -> (Option<String>, Option<String>) {
+    let Ok(output) = host::output(host::command("cryptsetup").args(["status", mapping])) else {
+        return (None, None);
+    };
+
+    let mut cipher = None;
+    let mut key_size = None;
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            "cipher" => cipher = Some(value.trim().to_string()),
+            "keysize" => key_size = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    (cipher, key_size)
+}
+
+/// Finds every LUKS container among the partitions of `disks`.
+pub fn collect(disks: &[Disk]) -> Vec<Container> {
+    let mappings = mappings();
+
+    disks
+        .iter()
+        .flat_map(|disk| &disk.partitions)
+        .filter(|partition| partition.fs_type.as_deref() == Some("crypto_LUKS"))
+        .map(|partition| {
+            let mapping = mappings
+                .iter()
+                .find(|mapping| mapping.slaves.contains(&partition.name));
+            let (cipher, key_size) = mapping.map_or((None, None), |mapping| status(&mapping.name));
+
+            Container {
+                device: partition.name.clone(),
+                mapping: mapping.map(|mapping| mapping.name.clone()),
+                version: mapping.and_then(|mapping| mapping.version.clone()),
+                cipher,
+                key_size,
+            }
+        })
+        .collect()
+}
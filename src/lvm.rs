@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads LVM volume groups, physical and logical volumes from the JSON
+//! reports of `vgs`, `pvs` and `lvs`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VolumeGroup {
+    pub vg_name: String,
+    #[serde(deserialize_with = "bytes")]
+    pub vg_size: u64,
+    #[serde(deserialize_with = "bytes")]
+    pub vg_free: u64,
+    #[serde(deserialize_with = "bytes")]
+    pub vg_extent_size: u64,
+    #[serde(deserialize_with = "bytes")]
+    pub vg_free_count: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PhysicalVolume {
+    pub pv_name: String,
+    pub vg_name: String,
+    #[serde(deserialize_with = "bytes")]
+    pub pv_size: u64,
+    #[serde(deserialize_with = "bytes")]
+    pub pv_free: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LogicalVolume {
+    pub lv_name: String,
+    pub vg_name: String,
+    #[serde(deserialize_with = "bytes")]
+    pub lv_size: u64,
+    /// Segment type, e.g. `linear`, `striped`, `raid1` or `thin-pool`.
+    pub segtype: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Lvm {
+    pub volume_groups: Vec<VolumeGroup>,
+    pub physical_volumes: Vec<PhysicalVolume>,
+    pub logical_volumes: Vec<LogicalVolume>,
+}
+
+/// LVM reports every number as a string, even with `--nosuffix`. Snapshots
+/// store them as numbers.
+fn bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Bytes {
+        Number(u64),
+        Text(String),
+    }
+    match Bytes::deserialize(deserializer)? {
+        Bytes::Number(bytes) => Ok(bytes),
+        Bytes::Text(value) => value.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// Runs an LVM reporting command and returns the rows of its first report.
+fn report<T: for<'de> Deserialize<'de>>(program: &str, key: &str, fields: &str) -> Result<Vec<T>, String> {
+    let output = host::output(host::command(program).args([
+        "--reportformat",
+        "json",
+        "--units",
+        "b",
+        "--nosuffix",
+        "-o",
+        fields,
+    ]))
+        .map_err(|e| host::spawn_error(program, &e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    parse_report(program, key, &output.stdout)
+}
+
+/// Parses the JSON of an LVM report, `{"report": [{"<key>": [...]}]}`.
+fn parse_report<T: for<'de> Deserialize<'de>>(program: &str, key: &str, json: &[u8]) -> Result<Vec<T>, String> {
+    let mut json: serde_json::Value = serde_json::from_slice(json).map_err(|e| format!("{program}: {e}"))?;
+    // Indexing a missing array element would panic, so look the rows up by pointer.
+    let rows = json.pointer_mut(&format!("/report/0/{key}")).map(serde_json::Value::take).unwrap_or_default();
+    serde_json::from_value(rows).map_err(|e| format!("{program}: {e}"))
+}
+
+/// Collects the LVM layout. Returns an empty layout when LVM is not installed.
+pub fn collect() -> Result<Lvm, String> {
+    if host::output(host::command("vgs").arg("--version")).is_err() {
+        return Ok(Lvm::default());
+    }
+
+    Ok(Lvm {
+        volume_groups: report(
+            "vgs",
+            "vg",
+            "vg_name,vg_size,vg_free,vg_extent_size,vg_free_count",
+        )?,
+        physical_volumes: report("pvs", "pv", "pv_name,vg_name,pv_size,pv_free")?,
+        logical_volumes: report("lvs", "lv", "lv_name,vg_name,lv_size,segtype")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VGS: &str = r#"{
+      "report": [
+          {
+              "vg": [
+                  {"vg_name":"fedora", "vg_size":"510770802688", "vg_free":"0", "vg_extent_size":"4194304", "vg_free_count":"0"}
+              ]
+          }
+      ]
+  }"#;
+
+    const LVS: &str = r#"{
+      "report": [
+          {
+              "lv": [
+                  {"lv_name":"root", "vg_name":"fedora", "lv_size":"75161927680", "segtype":"linear"},
+                  {"lv_name":"pool", "vg_name":"fedora", "lv_size":" 1073741824", "segtype":"thin-pool"}
+              ]
+          }
+      ]
+  }"#;
+
+    #[test]
+    fn parses_reports() {
+        let groups = parse_report::<VolumeGroup>("vgs", "vg", VGS.as_bytes()).unwrap();
+        assert_eq!(groups[0].vg_name, "fedora");
+        assert_eq!(groups[0].vg_size, 510_770_802_688);
+        assert_eq!(groups[0].vg_extent_size, 4_194_304);
+
+        let volumes = parse_report::<LogicalVolume>("lvs", "lv", LVS.as_bytes()).unwrap();
+        assert_eq!(volumes.len(), 2);
+        assert_eq!(volumes[1].lv_size, 1_073_741_824);
+        assert_eq!(volumes[1].segtype, "thin-pool");
+    }
+
+    #[test]
+    fn round_trips_numbers_through_snapshots() {
+        let volumes = parse_report::<LogicalVolume>("lvs", "lv", LVS.as_bytes()).unwrap();
+        let json = serde_json::to_string(&volumes).unwrap();
+        let volumes = serde_json::from_str::<Vec<LogicalVolume>>(&json).unwrap();
+        assert_eq!(volumes[0].lv_size, 75_161_927_680);
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        assert!(parse_report::<VolumeGroup>("vgs", "vg", b"").unwrap_err().starts_with("vgs: "));
+        assert!(parse_report::<VolumeGroup>("vgs", "vg", b"{\"report\": []}").is_err());
+        let size = br#"{"report": [{"pv": [{"pv_name":"/dev/sda2", "vg_name":"fedora", "pv_size":"big", "pv_free":"0"}]}]}"#;
+        assert!(parse_report::<PhysicalVolume>("pvs", "pv", size).is_err());
+        assert!(parse_report::<PhysicalVolume>("pvs", "pv", br#"{"report": [{"pv": []}]}"#).unwrap().is_empty());
+    }
+}
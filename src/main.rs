@@ -1,13 +1,118 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 mod app;
+mod audio;
+mod baseline;
+mod board;
+mod boot;
+mod camera;
+mod cli;
 mod config;
+mod controller;
+mod cpu;
+mod demo;
+mod diff;
+mod display;
+mod distribution;
+mod dmi;
+mod driver;
+mod ecc;
+mod export;
+mod fingerprint;
+mod fwupd;
+mod graphics;
+mod host;
+mod hotplug;
+mod hw_probe;
+mod hwdata;
 mod i18n;
+mod identity;
+mod iommu;
+mod ipmi;
+mod load;
+mod luks;
+mod lvm;
+mod mdraid;
+mod memory;
+mod modem;
+mod pages;
+mod pci;
+mod power;
+mod power_profiles;
+mod pressure;
+mod privileged;
+mod service;
+mod session;
+mod snapshot;
+mod smart;
+mod smartcard;
+mod storage;
+mod thermal;
+mod thunderbolt;
+mod time;
+mod tools;
+mod tpm;
+mod uefi;
+mod usb;
+mod usb_pm;
+mod wifi;
 
 fn main() -> cosmic::iced::Result {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn,examine=info,warn")).init();
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
     i18n::init(&requested_languages);
+
+    // Shows bundled sample data rather than this system's, for screenshots,
+    // translation work and checking every page without the hardware.
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--demo") {
+        if let Err(e) = demo::start() {
+            log::error!("cannot unpack the demo data: {}", e);
+        }
+    }
+
+    // Prints a page and exits without opening a window, for scripts and
+    // remote sessions.
+    if args.iter().any(|arg| arg == "--page") {
+        let code = match cli::run(&args) {
+            Ok(()) => 0,
+            Err(e) => {
+                eprintln!("examine: {e}");
+                2
+            }
+        };
+        demo::stop();
+        std::process::exit(code);
+    }
+
+    // Serves the collected data over D-Bus instead of opening a window.
+    if args.iter().any(|arg| arg == "--service") {
+        let code = match service::run() {
+            Ok(()) => 0,
+            Err(e) => {
+                log::error!("cannot serve {}: {}", service::NAME, e);
+                1
+            }
+        };
+        demo::stop();
+        std::process::exit(code);
+    }
+
+    // Starts on the page named by `--open`, such as from the desktop entry's actions.
+    let open = args
+        .iter()
+        .position(|arg| arg == "--open")
+        .and_then(|index| args.get(index + 1))
+        .and_then(|id| {
+            let page = pages::Page::from_id(id);
+            if page.is_none() {
+                log::warn!("cannot open unknown page {}", id);
+            }
+            page
+        });
+
     let settings = cosmic::app::Settings::default();
-    cosmic::app::run::<app::AppModel>(settings, ())
+    let result = cosmic::app::run::<app::AppModel>(settings, open);
+    demo::stop();
+    result
 }
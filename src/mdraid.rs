@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Parses Linux software RAID arrays from `/proc/mdstat`.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum MemberState {
+    Active,
+    Spare,
+    Faulty,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Member {
+    pub device: String,
+    pub state: MemberState,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Array {
+    pub name: String,
+    pub active: bool,
+    pub level: Option<String>,
+    pub members: Vec<Member>,
+    /// Number of devices the array should have.
+    pub raid_disks: Option<u32>,
+    /// Number of devices currently working.
+    pub working_disks: Option<u32>,
+    /// Per-slot state such as `UU_`, where `_` marks a missing device.
+    pub slots: Option<String>,
+    /// Running operation (`resync`, `recovery`, `check`, `reshape`) and its progress in percent.
+    pub sync: Option<(String, f32)>,
+}
+
+impl Array {
+    pub fn is_degraded(&self) -> bool {
+        self.slots.as_deref().is_some_and(|slots| slots.contains('_'))
+            || self
+                .raid_disks
+                .zip(self.working_disks)
+                .is_some_and(|(raid, working)| working < raid)
+    }
+}
+
+/// Parses a member such as `sda1[0]`, `sdc1[2](S)` or `sdb1[1](F)`.
+fn parse_member(word: &str) -> Option<Member> {
+    let (device, rest) = word.split_once('[')?;
+    let state = if rest.ends_with("(F)") {
+        MemberState::Faulty
+    } else if rest.ends_with("(S)") {
+        MemberState::Spare
+    } else {
+        MemberState::Active
+    };
+    Some(Member {
+        device: device.to_string(),
+        state,
+    })
+}
+
+fn parse(mdstat: &str) -> Vec<Array> {
+    let mut arrays: Vec<Array> = Vec::new();
+
+    for line in mdstat.lines() {
+        if line.starts_with("Personalities") || line.starts_with("unused devices") {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            // md0 : active raid1 sdb1[1] sda1[0]
+            let Some((name, rest)) = line.split_once(" : ") else {
+                continue;
+            };
+            let mut words = rest.split_whitespace().peekable();
+            let active = words.next() == Some("active");
+            if words.peek() == Some(&"(read-only)") || words.peek() == Some(&"(auto-read-only)") {
+                words.next();
+            }
+            let level = words.next_if(|word| !word.contains('[')).map(str::to_string);
+            arrays.push(Array {
+                name: name.trim().to_string(),
+                active,
+                level,
+                members: words.filter_map(parse_member).collect(),
+                raid_disks: None,
+                working_disks: None,
+                slots: None,
+                sync: None,
+            });
+            continue;
+        }
+
+        let Some(array) = arrays.last_mut() else {
+            continue;
+        };
+        let line = line.trim();
+
+        // 976630464 blocks super 1.2 [2/2] [UU]
+        for word in line.split_whitespace() {
+            let Some(inner) = word.strip_prefix('[').and_then(|word| word.strip_suffix(']')) else {
+                continue;
+            };
+            if let Some((raid, working)) = inner.split_once('/') {
+                array.raid_disks = raid.parse().ok();
+                array.working_disks = working.parse().ok();
+            } else if inner.chars().all(|c| c == 'U' || c == '_') {
+                array.slots = Some(inner.to_string());
+            }
+        }
+
+        // [==>....]  resync = 12.6% (123/456) finish=10.1min speed=100K/sec
+        if let Some((action, progress)) = line.split_once(" = ") {
+            let action = action.split_whitespace().last().unwrap_or_default().to_string();
+            if let Some(percent) = progress.split('%').next().and_then(|percent| percent.trim().parse().ok()) {
+                array.sync = Some((action, percent));
+            }
+        }
+    }
+
+    arrays
+}
+
+/// Collects all arrays. Empty when the md driver is not loaded.
+pub fn collect() -> Vec<Array> {
+    fs::read_to_string(host::path("/proc/mdstat"))
+        .map(|mdstat| parse(&mdstat))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MDSTAT: &str = "Personalities : [raid1] [raid6] [raid5] [raid4]
+md1 : active raid5 sdd1[3] sdc1[2](S) sdb1[1](F) sda1[0]
+      1953260544 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [U_U]
+      [==>..................]  recovery = 12.6% (123014144/976630272) finish=80.1min speed=177578K/sec
+      bitmap: 0/8 pages [0KB], 65536KB chunk
+
+md0 : active (auto-read-only) raid1 nvme1n1p1[1] nvme0n1p1[0]
+      976630464 blocks super 1.2 [2/2] [UU]
+
+md127 : inactive sde[0](S)
+      976631512 blocks super 1.2
+
+unused devices: <none>
+";
+
+    #[test]
+    fn parses_arrays() {
+        let arrays = parse(MDSTAT);
+        let names = arrays.iter().map(|array| array.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["md1", "md0", "md127"]);
+
+        let md1 = &arrays[0];
+        assert!(md1.active);
+        assert_eq!(md1.level.as_deref(), Some("raid5"));
+        let states = md1.members.iter().map(|member| member.state).collect::<Vec<_>>();
+        assert_eq!(states, [MemberState::Active, MemberState::Spare, MemberState::Faulty, MemberState::Active]);
+        assert_eq!(md1.members[0].device, "sdd1");
+        assert_eq!((md1.raid_disks, md1.working_disks), (Some(3), Some(2)));
+        assert_eq!(md1.slots.as_deref(), Some("U_U"));
+        assert_eq!(md1.sync, Some(("recovery".to_string(), 12.6)));
+        assert!(md1.is_degraded());
+
+        let md0 = &arrays[1];
+        assert!(md0.active);
+        assert_eq!(md0.level.as_deref(), Some("raid1"));
+        assert_eq!(md0.sync, None);
+        assert!(!md0.is_degraded());
+    }
+
+    #[test]
+    fn parses_inactive_arrays() {
+        let md127 = &parse(MDSTAT)[2];
+        assert!(!md127.active);
+        assert_eq!(md127.level, None);
+        assert_eq!(md127.members[0].state, MemberState::Spare);
+        assert_eq!(md127.raid_disks, None);
+        assert!(!md127.is_degraded());
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        assert!(parse("").is_empty());
+        // Detail lines before any array, and headers without a separator.
+        assert!(parse("      [2/2] [UU]\nmd0 active raid1\n").is_empty());
+
+        let arrays = parse("md0 : active raid1 sda1 sdb1[x\n      [a/b] [UX] resync = fast\n");
+        assert_eq!(arrays[0].members.len(), 1);
+        assert_eq!((arrays[0].raid_disks, arrays[0].working_disks), (None, None));
+        assert_eq!(arrays[0].slots, None);
+        assert_eq!(arrays[0].sync, None);
+    }
+}
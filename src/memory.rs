@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads memory usage from `/proc/meminfo` and the NUMA layout from
+//! `/sys/devices/system/node`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const NODE_DIR: &str = "/sys/devices/system/node";
+const THP_DIR: &str = "/sys/kernel/mm/transparent_hugepage";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Memory {
+    /// Every `/proc/meminfo` field, in kibibytes where the kernel reports a unit.
+    pub fields: HashMap<String, u64>,
+    /// The transparent hugepage mode: `always`, `madvise` or `never`.
+    pub thp_enabled: Option<String>,
+    /// When the kernel compacts memory to make transparent hugepages.
+    pub thp_defrag: Option<String>,
+}
+
+impl Memory {
+    pub fn get(&self, field: &str) -> Option<u64> {
+        self.fields.get(field).copied()
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        self.get("MemTotal")
+    }
+
+    pub fn available(&self) -> Option<u64> {
+        self.get("MemAvailable")
+    }
+
+    pub fn swap_total(&self) -> Option<u64> {
+        self.get("SwapTotal")
+    }
+
+    pub fn swap_free(&self) -> Option<u64> {
+        self.get("SwapFree")
+    }
+
+    pub fn hugepages_total(&self) -> Option<u64> {
+        self.get("HugePages_Total")
+    }
+
+    pub fn hugepages_free(&self) -> Option<u64> {
+        self.get("HugePages_Free")
+    }
+
+    /// The default hugepage size in kibibytes.
+    pub fn hugepage_size(&self) -> Option<u64> {
+        self.get("Hugepagesize")
+    }
+}
+
+/// A NUMA node with its CPUs and local memory.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NumaNode {
+    pub id: u32,
+    /// The CPU list, e.g. `0-15,32-47`.
+    pub cpus: String,
+    pub total_kib: Option<u64>,
+    pub free_kib: Option<u64>,
+    /// Relative access cost to every node, indexed by position in the node list.
+    pub distances: Vec<u32>,
+}
+
+/// Reads a field from a node's `meminfo`, whose lines look like
+/// `Node 0 MemTotal:       32768000 kB`.
+fn node_meminfo(meminfo: &str, field: &str) -> Option<u64> {
+    meminfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.split_whitespace().last()? == field)
+            .then(|| value.split_whitespace().next()?.parse().ok())
+            .flatten()
+    })
+}
+
+/// Lists the NUMA nodes that have CPUs or memory.
+pub fn numa_nodes() -> Vec<NumaNode> {
+    let mut nodes = fs::read_dir(host::path(NODE_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().strip_prefix("node")?.parse::<u32>().ok()?;
+            let path = entry.path();
+            let read = |name: &str| fs::read_to_string(Path::new(&path).join(name)).ok();
+            let meminfo = read("meminfo").unwrap_or_default();
+
+            Some(NumaNode {
+                id,
+                cpus: read("cpulist").map(|cpus| cpus.trim().to_string()).unwrap_or_default(),
+                total_kib: node_meminfo(&meminfo, "MemTotal"),
+                free_kib: node_meminfo(&meminfo, "MemFree"),
+                distances: read("distance")
+                    .map(|distance| distance.split_whitespace().filter_map(|d| d.parse().ok()).collect())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+/// Formats a size in kibibytes using binary units.
+pub fn format_kib(kib: u64) -> String {
+    crate::storage::format_bytes(kib * 1024)
+}
+
+pub fn collect() -> Result<Memory, host::Error> {
+    let meminfo =
+        fs::read_to_string(host::path("/proc/meminfo")).map_err(|e| host::read_error("/proc/meminfo", &e))?;
+    let fields = meminfo
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let value = value.split_whitespace().next()?.parse().ok()?;
+            Some((key.to_string(), value))
+        })
+        .collect();
+
+    Ok(Memory {
+        fields,
+        thp_enabled: thp_mode("enabled"),
+        thp_defrag: thp_mode("defrag"),
+    })
+}
+
+/// Reads the selected mode from a THP setting such as `always [madvise] never`.
+fn thp_mode(name: &str) -> Option<String> {
+    let modes = fs::read_to_string(host::path(THP_DIR).join(name)).ok()?;
+    let (_, selected) = modes.split_once('[')?;
+    let (selected, _) = selected.split_once(']')?;
+    Some(selected.to_string())
+}
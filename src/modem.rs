@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Queries ModemManager over D-Bus for WWAN modems.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use zbus::blocking::fdo::ObjectManagerProxy;
+use zbus::zvariant::OwnedValue;
+
+use crate::host;
+
+const SERVICE: &str = "org.freedesktop.ModemManager1";
+const PATH: &str = "/org/freedesktop/ModemManager1";
+const MODEM_INTERFACE: &str = "org.freedesktop.ModemManager1.Modem";
+const MODEM_3GPP_INTERFACE: &str = "org.freedesktop.ModemManager1.Modem.Modem3gpp";
+const SIM_INTERFACE: &str = "org.freedesktop.ModemManager1.Sim";
+
+/// `MMModemLock` value meaning no PIN or PUK is required.
+const LOCK_NONE: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum SimState {
+    Missing,
+    Locked,
+    Ready,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Modem {
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub revision: Option<String>,
+    pub imei: Option<String>,
+    pub state: Option<String>,
+    /// Signal quality in percent.
+    pub signal_quality: Option<u32>,
+    pub sim: SimState,
+    pub sim_operator: Option<String>,
+    pub operator: Option<String>,
+    pub registration: Option<String>,
+}
+
+type Properties = HashMap<String, OwnedValue>;
+
+fn string(properties: Option<&Properties>, key: &str) -> Option<String> {
+    properties?
+        .get(key)
+        .and_then(|value| value.downcast_ref::<&str>().ok())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn number<T: TryFrom<OwnedValue>>(properties: Option<&Properties>, key: &str) -> Option<T> {
+    let value = properties?.get(key)?.try_clone().ok()?;
+    T::try_from(value).ok()
+}
+
+/// Names for `MMModemState`.
+fn state_name(state: i32) -> Option<&'static str> {
+    Some(match state {
+        -1 => "failed",
+        1 => "initializing",
+        2 => "locked",
+        3 => "disabled",
+        4 => "disabling",
+        5 => "enabling",
+        6 => "enabled",
+        7 => "searching",
+        8 => "registered",
+        9 => "disconnecting",
+        10 => "connecting",
+        11 => "connected",
+        _ => return None,
+    })
+}
+
+/// Names for `MMModem3gppRegistrationState`.
+fn registration_name(state: u32) -> Option<&'static str> {
+    Some(match state {
+        0 => "idle",
+        1 => "home",
+        2 => "searching",
+        3 => "denied",
+        5 => "roaming",
+        _ => return None,
+    })
+}
+
+/// Collects all modems. Fails when ModemManager is not running.
+pub fn collect() -> zbus::Result<Vec<Modem>> {
+    let connection = host::system_bus()?;
+    let objects = ObjectManagerProxy::builder(&connection)
+        .destination(SERVICE)?
+        .path(PATH)?
+        .build()?
+        .get_managed_objects()?;
+
+    let interface = |path: &str, name: &str| {
+        objects
+            .iter()
+            .find(|(object, _)| object.as_str() == path)
+            .and_then(|(_, interfaces)| interfaces.iter().find(|(interface, _)| interface.as_str() == name))
+            .map(|(_, properties)| properties)
+    };
+
+    let modems = objects
+        .iter()
+        .filter_map(|(path, _)| {
+            let modem = interface(path.as_str(), MODEM_INTERFACE)?;
+            let gpp = interface(path.as_str(), MODEM_3GPP_INTERFACE);
+
+            let sim_path = modem
+                .get("Sim")
+                .and_then(|value| value.downcast_ref::<zbus::zvariant::ObjectPath>().ok())
+                .map(|path| path.to_string())
+                .filter(|path| path != "/");
+            let sim_properties = sim_path.as_deref().and_then(|path| interface(path, SIM_INTERFACE));
+            let unlock_required = number::<u32>(Some(modem), "UnlockRequired");
+            let sim = match (&sim_path, unlock_required) {
+                (None, _) => SimState::Missing,
+                (Some(_), Some(lock)) if lock > LOCK_NONE => SimState::Locked,
+                (Some(_), _) => SimState::Ready,
+            };
+
+            Some(Modem {
+                manufacturer: string(Some(modem), "Manufacturer"),
+                model: string(Some(modem), "Model"),
+                revision: string(Some(modem), "Revision"),
+                imei: string(gpp, "Imei").or_else(|| string(Some(modem), "EquipmentIdentifier")),
+                state: number::<i32>(Some(modem), "State").and_then(state_name).map(str::to_string),
+                signal_quality: number::<(u32, bool)>(Some(modem), "SignalQuality").map(|(quality, _)| quality),
+                sim,
+                sim_operator: string(sim_properties, "OperatorName"),
+                operator: string(gpp, "OperatorName"),
+                registration: number::<u32>(gpp, "RegistrationState")
+                    .and_then(registration_name)
+                    .map(str::to_string),
+            })
+        })
+        .collect();
+
+    Ok(modems)
+}
@@ -0,0 +1,411 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The registry of pages. Each page is a module whose `Provider` implements
+//! [`DataProvider`]: it describes the page, collects its data and draws it.
+//! The nav bar, collection, the page view, exports, snapshots, the help
+//! drawer and the diagnostics are all driven from [`PAGES`], declared at
+//! the end of this file.
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use cosmic::widget;
+use cosmic::Element;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Value};
+
+use crate::app::{AppModel, Message};
+use crate::{fl, host};
+
+/// Groups of related pages, separated by a divider in the nav bar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Category {
+    System,
+    Hardware,
+    Devices,
+    Security,
+}
+
+/// A page of the application: how it is listed, how its data is collected,
+/// and how that data is shown and exported.
+pub trait DataProvider: Sync + 'static {
+    /// Everything the page collects, held by the app until it is collected
+    /// again and saved in snapshots.
+    type Section: Clone + fmt::Debug + Send + Sync + Serialize + DeserializeOwned + 'static;
+
+    /// The page the provider fills.
+    const PAGE: Page;
+
+    /// The name of the page on the command line, over D-Bus, in the config,
+    /// in snapshots and in export file names, e.g. `processor`. Others rely
+    /// on it, so it must not change.
+    fn id(&self) -> &'static str;
+
+    /// The localized name shown in the nav bar.
+    fn name(&self) -> String;
+
+    fn icon(&self) -> &'static str;
+
+    fn category(&self) -> Category;
+
+    /// Whether the page applies to this system at all.
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Whether the data changes while the app runs, such as sensors or hotplugged
+    /// devices, so that auto refresh collects it again.
+    fn is_dynamic(&self) -> bool {
+        false
+    }
+
+    /// Whether some of the page's data is only readable through the privileged helper.
+    fn requires_privilege(&self) -> bool {
+        false
+    }
+
+    /// The external tools the page runs, listed in the diagnostics when they
+    /// are missing and whose raw output goes into the page's exports.
+    fn tools(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The messages naming the page's exported fields where the field name
+    /// alone names a different message, by field, e.g. `("caches", "cpu-caches")`.
+    /// They take precedence over the labels shared by every page.
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// Where the page's data is read from, e.g. `/proc/cpuinfo`, shown as the
+    /// source of the rows the search finds on it.
+    fn origin(&self) -> &'static str;
+
+    /// Where the exported field at `path` is read from, when not from
+    /// [`origin`](Self::origin). The path joins the names of the fields it is
+    /// in with dots, e.g. `board.dmi`, and the fields within take its origin.
+    fn field_origin(&self, _path: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// Other pages whose data this page shows too, collected along with it.
+    fn sources(&self) -> &'static [Page] {
+        &[]
+    }
+
+    /// Collects the page's data. It runs in the background the first time the
+    /// page is shown, so tools behind pages the user never opens do not run at
+    /// all. An error leaves the page nothing to show but why.
+    fn collect(&self) -> Result<Self::Section, host::Error>;
+
+    /// Moves what `old` holds that collecting does not read, such as data read
+    /// through the privileged helper on request, into the freshly collected `new`.
+    fn carry_over(&self, _old: Self::Section, _new: &mut Self::Section) {}
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Self::Section) -> Element<'a, Message>;
+
+    /// The structured data behind the page, as exported and searched.
+    fn export(&self, _app: &AppModel, section: &Self::Section) -> Value {
+        json!(section)
+    }
+
+    /// Where the data comes from, what the fields mean and which values hint at problems.
+    fn help(&self) -> (String, String, String);
+}
+
+/// A [`DataProvider`] with the type of its section erased, so that the
+/// providers of every page can be listed together in [`PAGES`].
+pub trait AnyProvider: Sync {
+    fn page(&self) -> Page;
+    fn id(&self) -> &'static str;
+    fn name(&self) -> String;
+    fn icon(&self) -> &'static str;
+    fn category(&self) -> Category;
+    fn is_available(&self) -> bool;
+    fn is_dynamic(&self) -> bool;
+    fn requires_privilege(&self) -> bool;
+    fn tools(&self) -> &'static [&'static str];
+    fn labels(&self) -> &'static [(&'static str, &'static str)];
+    fn origin(&self) -> &'static str;
+    fn field_origin(&self, path: &str) -> Option<&'static str>;
+    fn sources(&self) -> &'static [Page];
+    fn collect(&self) -> Result<Box<dyn AnySection>, host::Error>;
+    fn carry_over(&self, old: Box<dyn AnySection>, new: &mut Box<dyn AnySection>);
+    fn view<'a>(&self, app: &'a AppModel, section: &'a dyn AnySection) -> Element<'a, Message>;
+    fn export(&self, app: &AppModel, section: &dyn AnySection) -> Value;
+    /// Reads a section back from the JSON it was saved as in a snapshot.
+    fn restore(&self, value: Value) -> Result<Box<dyn AnySection>, serde_json::Error>;
+    fn help(&self) -> (String, String, String);
+}
+
+impl<P: DataProvider> AnyProvider for P {
+    fn page(&self) -> Page {
+        P::PAGE
+    }
+
+    fn id(&self) -> &'static str {
+        DataProvider::id(self)
+    }
+
+    fn name(&self) -> String {
+        DataProvider::name(self)
+    }
+
+    fn icon(&self) -> &'static str {
+        DataProvider::icon(self)
+    }
+
+    fn category(&self) -> Category {
+        DataProvider::category(self)
+    }
+
+    fn is_available(&self) -> bool {
+        DataProvider::is_available(self)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        DataProvider::is_dynamic(self)
+    }
+
+    fn requires_privilege(&self) -> bool {
+        DataProvider::requires_privilege(self)
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        DataProvider::tools(self)
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        DataProvider::labels(self)
+    }
+
+    fn origin(&self) -> &'static str {
+        DataProvider::origin(self)
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        DataProvider::field_origin(self, path)
+    }
+
+    fn sources(&self) -> &'static [Page] {
+        DataProvider::sources(self)
+    }
+
+    fn collect(&self) -> Result<Box<dyn AnySection>, host::Error> {
+        DataProvider::collect(self).map(|section| Box::new(section) as Box<dyn AnySection>)
+    }
+
+    fn carry_over(&self, old: Box<dyn AnySection>, new: &mut Box<dyn AnySection>) {
+        if let (Ok(old), Some(new)) = (old.into_any().downcast::<P::Section>(), new.as_any_mut().downcast_mut()) {
+            DataProvider::carry_over(self, *old, new);
+        }
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a dyn AnySection) -> Element<'a, Message> {
+        match section.as_any().downcast_ref() {
+            Some(section) => DataProvider::view(self, app, section),
+            None => widget::text::title1(fl!("no-page")).into(),
+        }
+    }
+
+    fn export(&self, app: &AppModel, section: &dyn AnySection) -> Value {
+        section
+            .as_any()
+            .downcast_ref()
+            .map_or(Value::Null, |section| DataProvider::export(self, app, section))
+    }
+
+    fn restore(&self, value: Value) -> Result<Box<dyn AnySection>, serde_json::Error> {
+        serde_json::from_value::<P::Section>(value).map(|section| Box::new(section) as Box<dyn AnySection>)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        DataProvider::help(self)
+    }
+}
+
+/// The data of a page, whatever its [`DataProvider::Section`] is.
+pub trait AnySection: Any + fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+    fn clone_box(&self) -> Box<dyn AnySection>;
+    fn to_json(&self) -> Result<Value, serde_json::Error>;
+}
+
+impl<T: Clone + fmt::Debug + Send + Sync + Serialize + 'static> AnySection for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn AnySection> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+}
+
+impl Clone for Box<dyn AnySection> {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+/// The data collected for each page, or why collecting it failed. Pages
+/// without an entry have not been collected.
+#[derive(Clone, Debug, Default)]
+pub struct Sections(HashMap<Page, Result<Box<dyn AnySection>, host::Error>>);
+
+impl Sections {
+    /// The data of the page `P` provides, if it was collected.
+    pub fn get<P: DataProvider>(&self) -> Option<&P::Section> {
+        self.0.get(&P::PAGE)?.as_ref().ok()?.as_any().downcast_ref()
+    }
+
+    pub fn get_mut<P: DataProvider>(&mut self) -> Option<&mut P::Section> {
+        self.0.get_mut(&P::PAGE)?.as_mut().ok()?.as_any_mut().downcast_mut()
+    }
+
+    /// Replaces the data of the page `P` provides.
+    pub fn set<P: DataProvider>(&mut self, section: P::Section) {
+        self.0.insert(P::PAGE, Ok(Box::new(section)));
+    }
+
+    pub fn result(&self, page: Page) -> Option<&Result<Box<dyn AnySection>, host::Error>> {
+        self.0.get(&page)
+    }
+
+    pub fn contains(&self, page: Page) -> bool {
+        self.0.contains_key(&page)
+    }
+
+    pub fn insert(&mut self, page: Page, result: Result<Box<dyn AnySection>, host::Error>) {
+        self.0.insert(page, result);
+    }
+
+    pub fn remove(&mut self, page: Page) -> Option<Result<Box<dyn AnySection>, host::Error>> {
+        self.0.remove(&page)
+    }
+}
+
+/// Saved by page id, in the order of the ids so that snapshots of the same
+/// system compare line by line.
+impl Serialize for Sections {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut sections = BTreeMap::new();
+        for (page, result) in &self.0 {
+            let result = match result {
+                Ok(section) => Ok(section.to_json().map_err(S::Error::custom)?),
+                Err(e) => Err(e),
+            };
+            sections.insert(page.id(), result);
+        }
+        sections.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Sections {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut sections = HashMap::new();
+        for (id, result) in HashMap::<String, Result<Value, host::Error>>::deserialize(deserializer)? {
+            let provider = Page::from_id(&id)
+                .map(provider)
+                .ok_or_else(|| D::Error::custom(format!("unknown page {id}")))?;
+            let result = match result {
+                Ok(value) => Ok(provider.restore(value).map_err(|e| D::Error::custom(format!("{id}: {e}")))?),
+                Err(e) => Err(e),
+            };
+            sections.insert(provider.page(), result);
+        }
+        Ok(Self(sections))
+    }
+}
+
+/// Declares the module, the [`Page`] variant and the [`PAGES`] entry of
+/// each page listed.
+macro_rules! pages {
+    ($($page:ident => $module:ident,)*) => {
+        $(pub(crate) mod $module;)*
+
+        /// The page to display in the application.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum Page {
+            $($page,)*
+        }
+
+        /// All pages in nav bar order.
+        pub static PAGES: &[&dyn AnyProvider] = &[$(&$module::Provider,)*];
+
+        /// The provider of `page`.
+        pub fn provider(page: Page) -> &'static dyn AnyProvider {
+            match page {
+                $(Page::$page => &$module::Provider,)*
+            }
+        }
+    };
+}
+
+// To add a page, add a module whose `Provider` implements [`DataProvider`]
+// and an entry here, in nav bar order. Pages with controls of their own,
+// such as the Processor page's tabs, also keep that state in their module's
+// `State` and handle their own `Message`, which need a field and a variant
+// on the app.
+pages! {
+    Overview => overview,
+    Distribution => distribution,
+    Identity => identity,
+    Session => session,
+    Time => time,
+    Boot => boot,
+    Processor => processor,
+    Motherboard => motherboard,
+    Memory => memory,
+    Filesystems => filesystems,
+    Thermal => thermal,
+    Graphics => graphics,
+    Displays => displays,
+    Power => power,
+    Firmware => firmware,
+    Uefi => uefi,
+    Ipmi => ipmi,
+    PCIs => pci,
+    USBs => usb,
+    Thunderbolt => thunderbolt,
+    Cameras => cameras,
+    Audio => audio,
+    WiFi => wifi,
+    Modem => modem,
+    Controllers => controllers,
+    SecurityDevices => security_devices,
+    Tpm => tpm,
+}
+
+impl Page {
+    /// The page's [`DataProvider::id`].
+    pub fn id(self) -> &'static str {
+        provider(self).id()
+    }
+
+    /// The page named `id`, as given on the command line or stored in the
+    /// config or a snapshot.
+    pub fn from_id(id: &str) -> Option<Page> {
+        let id = id.to_lowercase();
+        PAGES
+            .iter()
+            .find(|provider| provider.id() == id)
+            .map(|provider| provider.page())
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Audio page, from PipeWire.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{audio, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = audio::Audio;
+
+    const PAGE: Page = Page::Audio;
+
+    fn id(&self) -> &'static str {
+        "audio"
+    }
+
+    fn name(&self) -> String {
+        fl!("audio")
+    }
+
+    fn icon(&self) -> &'static str {
+        "audio-card-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["pw-metadata", "pw-top"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("clock", "pipewire-clock"),
+            ("nodes", "audio-nodes"),
+            ("rate", "sample-rate"),
+            ("driver", "driver-name"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "pw-metadata -n settings"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        (path == "nodes").then_some("pw-top --batch-mode")
+    }
+
+    fn collect(&self) -> Result<audio::Audio, host::Error> {
+        Ok(audio::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a audio::Audio) -> Element<'a, Message> {
+        app.audio(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-audio-source"), fl!("help-audio-fields"), fl!("help-audio-problems"))
+    }
+}
+
+impl AppModel {
+    /// The audio page, showing the PipeWire clock and per-node xruns.
+    pub fn audio<'a>(&'a self, audio: &'a audio::Audio) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_m);
+
+        let mut clock_section = settings::section().title(fl!("pipewire-clock"));
+        match &audio.clock {
+            Ok(clock) => {
+                let rows = [
+                    (fl!("sample-rate"), clock.rate.as_ref().map(|rate| format!("{rate} Hz")), "clock.rate"),
+                    (fl!("allowed-rates"), clock.allowed_rates.clone(), "clock.allowed-rates"),
+                    (fl!("quantum"), clock.quantum.clone(), "clock.quantum"),
+                    (fl!("min-quantum"), clock.min_quantum.clone(), "clock.min-quantum"),
+                    (fl!("max-quantum"), clock.max_quantum.clone(), "clock.max-quantum"),
+                    (
+                        fl!("force-quantum"),
+                        clock.force_quantum.clone().filter(|value| value != "0"),
+                        "clock.force-quantum",
+                    ),
+                    (fl!("force-rate"), clock.force_rate.clone().filter(|value| value != "0"), "clock.force-rate"),
+                    (
+                        fl!("latency"),
+                        clock.latency_ms().map(|latency| format!("{latency:.2} ms")),
+                        "clock.quantum, clock.rate",
+                    ),
+                ];
+                for (title, value, key) in rows {
+                    if let Some(value) = value {
+                        clock_section =
+                            clock_section.add(value_item(title, value, format!("pw-metadata -n settings: {key}")));
+                    }
+                }
+            }
+            Err(e) => {
+                clock_section = clock_section.add(settings::item(
+                    fl!("not-available"),
+                    widget::text::caption(e.clone()),
+                ));
+            }
+        }
+        column = column.push(clock_section);
+
+        let mut nodes_section = settings::section().title(fl!("audio-nodes"));
+        match &audio.nodes {
+            Ok(nodes) => {
+                for node in nodes {
+                    let title = if node.driver {
+                        fl!("driver-node", name = node.name.as_str())
+                    } else {
+                        node.name.clone()
+                    };
+                    let mut details = row::with_capacity(2)
+                        .push(widget::text::body(fl!(
+                            "node-timing",
+                            quantum = node.quantum.as_str(),
+                            rate = node.rate.as_str()
+                        )))
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs);
+                    if node.errors > 0 {
+                        details = details.push(widget::tooltip(
+                            widget::text::body(fl!("xruns", count = node.errors)),
+                            widget::text::body(fl!("xruns-description")),
+                            widget::tooltip::Position::Left,
+                        ));
+                    }
+                    nodes_section = nodes_section.add(settings::item(title, details));
+                }
+            }
+            Err(e) => {
+                nodes_section = nodes_section.add(settings::item(
+                    fl!("not-available"),
+                    widget::text::caption(e.clone()),
+                ));
+            }
+        }
+        column = column.push(nodes_section);
+
+        column.apply(page_scrollable).into()
+    }
+}
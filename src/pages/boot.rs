@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Boot Performance page, from systemd-analyze.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{boot, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = boot::Boot;
+
+    const PAGE: Page = Page::Boot;
+
+    fn id(&self) -> &'static str {
+        "boot"
+    }
+
+    fn name(&self) -> String {
+        fl!("boot-performance")
+    }
+
+    fn icon(&self) -> &'static str {
+        "system-reboot-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["systemd-analyze"]
+    }
+
+    fn origin(&self) -> &'static str {
+        "systemd-analyze time"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        (path == "slowest").then_some("systemd-analyze blame")
+    }
+
+    fn collect(&self) -> Result<boot::Boot, host::Error> {
+        boot::collect()
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a boot::Boot) -> Element<'a, Message> {
+        app.boot(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-boot-source"), fl!("help-boot-fields"), fl!("help-boot-problems"))
+    }
+}
+
+impl AppModel {
+    /// The boot performance page, showing how long each boot phase and the slowest units took.
+    pub fn boot<'a>(&'a self, boot: &'a boot::Boot) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let mut phases = settings::section().title(fl!("boot-phases"));
+        for phase in &boot.phases {
+            let name = match phase.name.as_str() {
+                "firmware" => fl!("boot-firmware"),
+                "loader" => fl!("boot-loader"),
+                "kernel" => fl!("kernel"),
+                "initrd" => fl!("boot-initrd"),
+                "userspace" => fl!("boot-userspace"),
+                other => other.to_string(),
+            };
+            phases = phases.add(value_item(name, phase.duration.clone(), "systemd-analyze time"));
+        }
+        if let Some(total) = &boot.total {
+            let mut item = settings::item::builder(fl!("boot-total"));
+            if let Some(target) = &boot.target {
+                item = item.description(target.clone());
+            }
+            phases = phases.add(item.control(widget::text::heading(total.clone())));
+        }
+
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_m).push(phases);
+
+        if !boot.slowest.is_empty() {
+            let mut slowest = settings::section().title(fl!("slowest-units"));
+            for unit in &boot.slowest {
+                slowest = slowest.add(value_item(unit.name.clone(), unit.duration.clone(), "systemd-analyze blame"));
+            }
+            column = column.push(slowest);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
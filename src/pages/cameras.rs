@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Cameras page, listing V4L2 capture devices.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{camera, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<camera::Camera>;
+
+    const PAGE: Page = Page::Cameras;
+
+    fn id(&self) -> &'static str {
+        "cameras"
+    }
+
+    fn name(&self) -> String {
+        fl!("cameras")
+    }
+
+    fn icon(&self) -> &'static str {
+        "camera-web-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["v4l2-ctl"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("device", "device-node"), ("driver", "kernel-driver")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/video4linux, v4l2-ctl"
+    }
+
+    fn collect(&self) -> Result<Vec<camera::Camera>, host::Error> {
+        Ok(camera::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<camera::Camera>) -> Element<'a, Message> {
+        app.cameras(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-cameras-source"), fl!("help-cameras-fields"), fl!("help-cameras-problems"))
+    }
+}
+
+impl AppModel {
+    /// The cameras page, listing V4L2 capture devices and their formats.
+    pub fn cameras<'a>(&'a self, cameras: &'a [camera::Camera]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let cameras = cameras.iter().filter(|camera| camera.capture).collect::<Vec<_>>();
+        if cameras.is_empty() {
+            return widget::text::title1(fl!("no-cameras")).into();
+        }
+
+        let mut column = widget::column::with_capacity(cameras.len()).spacing(spacing.space_m);
+        for camera in cameras {
+            let mut section = settings::section().title(camera.name.clone());
+            section = section.add(value_item(fl!("device-node"), camera.device.clone(), "/sys/class/video4linux"));
+            if let Some(driver) = &camera.driver {
+                section = section.add(value_item(
+                    fl!("kernel-driver"),
+                    driver.clone(),
+                    "/sys/class/video4linux/*/device/driver",
+                ));
+            }
+            if let Some(bus_info) = &camera.bus_info {
+                section = section.add(value_item(
+                    fl!("bus-info"),
+                    bus_info.clone(),
+                    format!("v4l2-ctl --device {} --info: Bus info", camera.device),
+                ));
+            }
+            match &camera.formats {
+                Ok(formats) => {
+                    for format in formats {
+                        section = section.add(value_item(
+                            format!("{} ({})", format.fourcc, format.description),
+                            format.resolutions.join(", "),
+                            format!("v4l2-ctl --device {} --list-formats-ext", camera.device),
+                        ));
+                    }
+                }
+                Err(e) => {
+                    section = section.add(settings::item(
+                        fl!("pixel-formats"),
+                        widget::text::caption(e.clone()),
+                    ));
+                }
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Game Controllers page, listing joysticks and gamepads from `/sys/class/input`.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{controller, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<controller::Controller>;
+
+    const PAGE: Page = Page::Controllers;
+
+    fn id(&self) -> &'static str {
+        "controllers"
+    }
+
+    fn name(&self) -> String {
+        fl!("controllers")
+    }
+
+    fn icon(&self) -> &'static str {
+        "input-gaming-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("nodes", "device-node")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/input"
+    }
+
+    fn collect(&self) -> Result<Vec<controller::Controller>, host::Error> {
+        Ok(controller::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<controller::Controller>) -> Element<'a, Message> {
+        app.controllers(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-controllers-source"),
+            fl!("help-controllers-fields"),
+            fl!("help-controllers-problems"),
+        )
+    }
+}
+
+impl AppModel {
+    /// The game controllers page, listing joysticks and gamepads.
+    pub fn controllers<'a>(&'a self, controllers: &'a [controller::Controller]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if controllers.is_empty() {
+            return widget::text::title1(fl!("no-controllers")).into();
+        }
+
+        let mut column = widget::column::with_capacity(controllers.len()).spacing(spacing.space_m);
+        for controller in &controllers {
+            let sysfs = |file: &str| match controller.nodes.first() {
+                Some(node) => format!("/sys/class/input/{node}/device/{file}"),
+                None => format!("/sys/class/input/input*/{file}"),
+            };
+            let mut section = settings::section().title(controller.name.clone());
+            if let (Some(vendor), Some(product)) = (&controller.vendor_id, &controller.product_id) {
+                section = section.add(value_item(
+                    fl!("vendor-product-id"),
+                    format!("{vendor}:{product}"),
+                    sysfs("id/vendor, id/product"),
+                ));
+            }
+            section = section.add(value_item(
+                fl!("device-node"),
+                controller.nodes.iter().map(|node| format!("/dev/input/{node}")).join(", "),
+                "/sys/class/input",
+            ));
+            section = section.add(value_item(fl!("axes"), controller.axes.to_string(), sysfs("capabilities/abs")));
+            section = section.add(value_item(
+                fl!("buttons"),
+                controller.buttons.to_string(),
+                sysfs("capabilities/key"),
+            ));
+            section = section.add(value_item(
+                fl!("force-feedback"),
+                if controller.force_feedback {
+                    fl!("supported")
+                } else {
+                    fl!("unsupported")
+                },
+                sysfs("capabilities/ff"),
+            ));
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Displays page, from the DRM connectors in sysfs and debugfs.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{display, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<display::Connector>;
+
+    const PAGE: Page = Page::Displays;
+
+    fn id(&self) -> &'static str {
+        "displays"
+    }
+
+    fn name(&self) -> String {
+        fl!("displays")
+    }
+
+    fn icon(&self) -> &'static str {
+        "preferences-desktop-display-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/drm/card*-*"
+    }
+
+    fn collect(&self) -> Result<Vec<display::Connector>, host::Error> {
+        Ok(display::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<display::Connector>) -> Element<'a, Message> {
+        app.displays(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-displays-source"), fl!("help-displays-fields"), fl!("help-displays-problems"))
+    }
+}
+
+impl AppModel {
+    /// The displays page, listing connectors with their DSC and link bandwidth state.
+    pub fn displays<'a>(&'a self, displays: &'a [display::Connector]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let mut column = widget::column::with_capacity(displays.len()).spacing(spacing.space_m);
+        for connector in displays.iter().filter(|connector| connector.connected) {
+            let title = match &connector.monitor {
+                Some(monitor) => format!("{} — {monitor}", connector.name),
+                None => connector.name.clone(),
+            };
+            let mut section = settings::section().title(title);
+
+            section = section.add(value_item(
+                fl!("status"),
+                if connector.enabled {
+                    fl!("enabled")
+                } else {
+                    fl!("disabled")
+                },
+                format!("/sys/class/drm/card{}-{}/enabled", connector.card, connector.name),
+            ));
+            if let Some(mode) = &connector.preferred_mode {
+                let mode = match connector.refresh_rate {
+                    Some(rate) => format!("{mode} @ {rate:.2} Hz"),
+                    None => mode.clone(),
+                };
+                section = section.add(value_item(
+                    fl!("preferred-mode"),
+                    mode,
+                    format!("/sys/class/drm/card{}-{}/edid", connector.card, connector.name),
+                ));
+            }
+            if let Some(required) = connector.required_bandwidth {
+                section = section.add(value_item(
+                    fl!("required-bandwidth"),
+                    format!("{required:.2} Gbit/s"),
+                    format!("/sys/class/drm/card{}-{}/edid", connector.card, connector.name),
+                ));
+            }
+            if let Some(link) = connector.link_bandwidth {
+                section = section.add(value_item(
+                    fl!("link-bandwidth"),
+                    format!("{link:.2} Gbit/s"),
+                    format!("/sys/kernel/debug/dri/{}/{}/link_settings", connector.card, connector.name),
+                ));
+            }
+            if let Some(usage) = connector.link_usage() {
+                section = section.add(value_item(
+                    fl!("link-usage"),
+                    format!("{usage:.0} %"),
+                    format!("/sys/kernel/debug/dri/{}/{}/link_settings", connector.card, connector.name),
+                ));
+            }
+            section = section.add(
+                settings::item::builder(fl!("dsc"))
+                    .description(fl!("dsc-description"))
+                    .control(widget::text::body(match connector.dsc_enabled {
+                        Some(true) => fl!("active"),
+                        Some(false) => fl!("inactive"),
+                        None => fl!("requires-root"),
+                    })),
+            );
+
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
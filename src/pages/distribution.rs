@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Distribution page, listing every os-release field.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{self, icon, list_column, row, settings};
+use cosmic::{theme, Apply, Element};
+use serde_json::{json, Value};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::distribution::Distribution;
+use crate::pages::{Category, DataProvider, Page};
+use crate::{distribution, fl, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Distribution;
+
+    const PAGE: Page = Page::Distribution;
+
+    fn id(&self) -> &'static str {
+        "distribution"
+    }
+
+    fn name(&self) -> String {
+        fl!("distribution")
+    }
+
+    fn icon(&self) -> &'static str {
+        "applications-system-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn origin(&self) -> &'static str {
+        crate::distribution::source()
+    }
+
+    fn collect(&self) -> Result<Distribution, host::Error> {
+        distribution::collect()
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Distribution) -> Element<'a, Message> {
+        app.distribution(section)
+    }
+
+    /// The fields by their os-release variable name.
+    fn export(&self, _app: &AppModel, section: &Distribution) -> Value {
+        Value::Object(
+            section
+                .fields()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), json!(value)))
+                .collect(),
+        )
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-distribution-source"),
+            fl!("help-distribution-fields"),
+            fl!("help-distribution-problems"),
+        )
+    }
+}
+
+impl AppModel {
+    /// The distribution page, showing every field of os-release.
+    pub fn distribution<'a>(&'a self, osrelease: &'a Distribution) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let source = distribution::source();
+        let item = |title: String, key: &str, value: String| value_item(title, value, format!("{source}: {key}"));
+
+        let mut list = list_column();
+
+        list = list.add(item(fl!("pretty-name"), "PRETTY_NAME", osrelease.pretty_name.clone()));
+        list = list.add(item(fl!("name"), "NAME", osrelease.name.clone()));
+        if let Some(version) = &osrelease.version {
+            list = list.add(item(fl!("version"), "VERSION", version.clone()));
+        }
+        if let Some(version_id) = &osrelease.version_id {
+            list = list.add(item(fl!("version-id"), "VERSION_ID", version_id.clone()));
+        }
+        list = list.add(item(fl!("id"), "ID", osrelease.id.clone()));
+        if !osrelease.id_like.is_empty() {
+            list = list.add(item(fl!("id-like"), "ID_LIKE", osrelease.id_like.join(", ")));
+        }
+        if let Some(version_codename) = &osrelease.version_codename {
+            list = list.add(item(fl!("version-codename"), "VERSION_CODENAME", version_codename.clone()));
+        }
+        if let Some(build_id) = &osrelease.build_id {
+            list = list.add(item(fl!("build-id"), "BUILD_ID", build_id.clone()));
+        }
+        if let Some(image_id) = &osrelease.image_id {
+            list = list.add(item(fl!("image-id"), "IMAGE_ID", image_id.clone()));
+        }
+        if let Some(image_version) = &osrelease.image_version {
+            list = list.add(item(fl!("image-version"), "IMAGE_VERSION", image_version.clone()));
+        }
+        if let Some(vendor_name) = &osrelease.vendor_name {
+            list = list.add(item(fl!("vendor-name"), "VENDOR_NAME", vendor_name.clone()));
+        }
+        if let Some(ansi_color) = &osrelease.ansi_color {
+            list = list.add(item(fl!("ansi-color"), "ANSI_COLOR", ansi_color.clone()));
+        }
+        if let Some(logo) = &osrelease.logo {
+            list = list.add(settings::item(
+                fl!("logo"),
+                row::with_capacity(2)
+                    .push(icon::from_name(logo.as_str()))
+                    .push(widget::text::body(logo.as_str()))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxxs),
+            ));
+        }
+        if let Some(cpe_name) = &osrelease.cpe_name {
+            list = list.add(item(fl!("cpe-name"), "CPE_NAME", cpe_name.clone()));
+        }
+        let urls = [
+            (fl!("home-url"), &osrelease.home_url),
+            (fl!("vendor-url"), &osrelease.support_url),
+            (fl!("doc-url"), &osrelease.documentation_url),
+            (fl!("support-url"), &osrelease.support_url),
+            (fl!("bug-report-url"), &osrelease.bug_report_url),
+            (fl!("privacy-policy-url"), &osrelease.privacy_policy_url),
+        ];
+        for (title, url) in urls {
+            if let Some(url) = url {
+                list = list.add(settings::item(
+                    title,
+                    widget::button::link(url.clone()).on_press(Message::LaunchUrl(url.clone())),
+                ));
+            }
+        }
+        if let Some(support_end) = &osrelease.support_end {
+            list = list.add(item(fl!("support-end"), "SUPPORT_END", support_end.clone()));
+        }
+        if let Some(variant) = &osrelease.variant {
+            list = list.add(item(fl!("variant"), "VARIANT", variant.clone()));
+        }
+        if let Some(variant_id) = &osrelease.variant_id {
+            list = list.add(item(fl!("variant-id"), "VARIANT_ID", variant_id.clone()));
+        }
+        if let Some(default_hostname) = &osrelease.default_hostname {
+            list = list.add(item(fl!("default-hostname"), "DEFAULT_HOSTNAME", default_hostname.clone()));
+        }
+        if let Some(architecture) = &osrelease.architecture {
+            list = list.add(item(fl!("arch"), "ARCHITECTURE", architecture.clone()));
+        }
+        if let Some(sysext_level) = &osrelease.sysext_level {
+            list = list.add(item("SYSEXT_LEVEL".to_string(), "SYSEXT_LEVEL", sysext_level.clone()));
+        }
+        if !osrelease.sysext_scope.is_empty() {
+            list = list.add(item("SYSEXT_SCOPE".to_string(), "SYSEXT_SCOPE", osrelease.sysext_scope.join(", ")));
+        }
+        if let Some(confext_level) = &osrelease.confext_level {
+            list = list.add(item("CONFEXT_LEVEL".to_string(), "CONFEXT_LEVEL", confext_level.clone()));
+        }
+        if !osrelease.confext_scope.is_empty() {
+            list = list.add(item("CONFEXT_SCOPE".to_string(), "CONFEXT_SCOPE", osrelease.confext_scope.join(", ")));
+        }
+        if !osrelease.portable_prefixes.is_empty() {
+            list = list.add(item(
+                fl!("portable-prefixes"),
+                "PORTABLE_PREFIXES",
+                osrelease.portable_prefixes.join(", "),
+            ));
+        }
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_xxs)
+            .push(list)
+            .apply(widget::container)
+            .height(Length::Shrink)
+            .apply(page_scrollable)
+    }
+}
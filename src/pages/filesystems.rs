@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Filesystems page, from the mount table, sysfs block devices, mdraid,
+//! LVM and, on request, SMART.
+
+use std::collections::HashMap;
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, list_column, row, settings};
+use cosmic::{theme, Apply, Element};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{
+    error_explanation, install_hint, missing_tool, page_scrollable, usage_gauge, value_item, AppModel, Message,
+    Severity,
+};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{export, fl, host, mdraid, smart, storage, tools};
+
+/// The data of the Filesystems page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub storage: storage::Storage,
+    /// The SMART health of each disk by name, read on request because
+    /// smartctl needs root.
+    pub health: HashMap<String, Result<smart::Health, host::Error>>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Filesystems;
+
+    fn id(&self) -> &'static str {
+        "filesystems"
+    }
+
+    fn name(&self) -> String {
+        fl!("filesystems")
+    }
+
+    fn icon(&self) -> &'static str {
+        "drive-harddisk-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn requires_privilege(&self) -> bool {
+        true
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["smartctl", "nvme", "cryptsetup", "vgs"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("health", "disk-health"), ("level", "raid-level"), ("members", "raid-devices")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/proc/self/mounts"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "storage.disks" => Some("/sys/block"),
+            "storage.trim_timer" => Some("systemctl show fstrim.timer"),
+            "storage.raid" => Some("/proc/mdstat"),
+            "storage.lvm" => Some("vgs"),
+            "storage.encryption" => Some("cryptsetup status"),
+            "health" => Some("smartctl --all, nvme smart-log"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            storage: storage::collect()?,
+            health: HashMap::new(),
+        })
+    }
+
+    /// Disk health is only read on request.
+    fn carry_over(&self, old: Section, new: &mut Section) {
+        new.health = old.health;
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.filesystems(section)
+    }
+
+    fn export(&self, _app: &AppModel, section: &Section) -> Value {
+        json!({
+            "storage": section.storage,
+            "health": section
+                .health
+                .iter()
+                .map(|(disk, health)| (disk.clone(), export::result(health)))
+                .collect::<serde_json::Map<_, _>>(),
+        })
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-filesystems-source"),
+            fl!("help-filesystems-fields"),
+            fl!("help-filesystems-problems"),
+        )
+    }
+}
+
+impl AppModel {
+    /// The filesystems page, listing mounts and the partition layout of each disk.
+    pub fn filesystems<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Section { storage, health } = section;
+
+        let mut column = widget::column::with_capacity(storage.disks.len() + 1).spacing(spacing.space_m);
+
+        let mut mounts = settings::section().title(fl!("mounted-filesystems"));
+        for mount in &storage.mounts {
+            mounts = mounts.add(
+                settings::item::builder(mount.mount_point.clone())
+                    .description(format!("{} · {} · {}", mount.source, mount.fs_type, mount.options))
+                    .control(usage_gauge(
+                        mount.used() as f32,
+                        mount.total as f32,
+                        fl!(
+                            "used-of-total",
+                            used = storage::format_bytes(mount.used()),
+                            total = storage::format_bytes(mount.total)
+                        ),
+                    )),
+            );
+        }
+        column = column.push(mounts);
+
+        if storage.disks.iter().any(|disk| disk.discard) {
+            let timer = &storage.trim_timer;
+            let mut trim = settings::section().title(fl!("trim"));
+            trim = trim.add(value_item(
+                fl!("periodic-trim"),
+                match timer.enabled {
+                    Some(true) => fl!("enabled"),
+                    Some(false) => fl!("disabled"),
+                    None => fl!("unknown"),
+                },
+                "systemctl is-enabled fstrim.timer",
+            ));
+            if let Some(last_run) = &timer.last_run {
+                trim = trim.add(value_item(
+                    fl!("trim-last-run"),
+                    last_run.clone(),
+                    "systemctl show fstrim.timer: LastTriggerUSec",
+                ));
+            }
+            if let Some(next_run) = &timer.next_run {
+                trim = trim.add(value_item(
+                    fl!("trim-next-run"),
+                    next_run.clone(),
+                    "systemctl show fstrim.timer: NextElapseUSecRealtime",
+                ));
+            }
+            let continuous = storage
+                .mounts
+                .iter()
+                .filter(|mount| mount.continuous_discard())
+                .map(|mount| mount.mount_point.as_str())
+                .join(", ");
+            trim = trim.add(value_item(
+                fl!("continuous-discard"),
+                if continuous.is_empty() {
+                    fl!("none")
+                } else {
+                    continuous
+                },
+                "/proc/self/mounts",
+            ));
+            if timer.enabled == Some(false) && storage.mounts.iter().all(|mount| !mount.continuous_discard()) {
+                trim = trim.add(settings::item(
+                    fl!("trim-warning"),
+                    self.status_indicator(Severity::Warning),
+                ));
+            }
+            column = column.push(trim);
+        }
+
+        if !storage.encryption.is_empty() {
+            let mut section = settings::section().title(fl!("encryption"));
+            for container in &storage.encryption {
+                let state = match &container.mapping {
+                    Some(mapping) => fl!("unlocked-as", name = mapping.as_str()),
+                    None => fl!("locked"),
+                };
+                let mut details = vec![container.version.clone().unwrap_or_else(|| "LUKS".to_string())];
+                match (&container.cipher, &container.key_size) {
+                    (Some(cipher), Some(key_size)) => details.push(format!("{cipher}, {key_size}")),
+                    (Some(cipher), None) => details.push(cipher.clone()),
+                    _ if container.mapping.is_some() => details.push(fl!("cipher-requires-root")),
+                    _ => {}
+                }
+                section = section.add(
+                    settings::item::builder(container.device.clone())
+                        .description(details.join(" · "))
+                        .control(widget::text::body(state)),
+                );
+            }
+            column = column.push(section);
+        }
+
+        for array in &storage.raid {
+            let mut section = settings::section().title(fl!("raid-array", name = array.name.as_str()));
+            section = section.add(value_item(
+                fl!("raid-level"),
+                array.level.clone().unwrap_or_else(|| fl!("unknown")),
+                "/proc/mdstat",
+            ));
+
+            let state = if !array.active {
+                fl!("inactive")
+            } else if array.is_degraded() {
+                fl!("degraded")
+            } else {
+                fl!("healthy")
+            };
+            let mut state_row = row::with_capacity(2)
+                .push(widget::text::body(state))
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xxs);
+            if array.is_degraded() || !array.active {
+                state_row = state_row.push(self.status_indicator(Severity::Warning));
+            }
+            section = section.add(settings::item(fl!("status"), state_row));
+
+            if let (Some(raid), Some(working)) = (array.raid_disks, array.working_disks) {
+                section = section.add(value_item(
+                    fl!("raid-devices"),
+                    fl!("raid-working", working = working, total = raid),
+                    "/proc/mdstat",
+                ));
+            }
+            if let Some((action, progress)) = &array.sync {
+                section = section.add(settings::item(
+                    fl!("raid-sync"),
+                    usage_gauge(*progress, 100.0, format!("{action} {progress:.1} %")),
+                ));
+            }
+            for member in &array.members {
+                section = section.add(value_item(
+                    member.device.clone(),
+                    match member.state {
+                        mdraid::MemberState::Active => fl!("active"),
+                        mdraid::MemberState::Spare => fl!("raid-spare"),
+                        mdraid::MemberState::Faulty => fl!("raid-faulty"),
+                    },
+                    "/proc/mdstat",
+                ));
+            }
+            column = column.push(section);
+        }
+
+        match &storage.lvm {
+            Ok(lvm) => {
+                for group in &lvm.volume_groups {
+                    let mut section = settings::section().title(fl!("volume-group", name = group.vg_name.as_str()));
+                    section = section.add(settings::item(
+                        fl!("size"),
+                        usage_gauge(
+                            group.vg_size.saturating_sub(group.vg_free) as f32,
+                            group.vg_size as f32,
+                            fl!(
+                                "used-of-total",
+                                used = storage::format_bytes(group.vg_size.saturating_sub(group.vg_free)),
+                                total = storage::format_bytes(group.vg_size)
+                            ),
+                        ),
+                    ));
+                    section = section.add(value_item(
+                        fl!("free-extents"),
+                        fl!(
+                            "extents",
+                            count = group.vg_free_count,
+                            size = storage::format_bytes(group.vg_extent_size)
+                        ),
+                        format!("vgs: {}", group.vg_name),
+                    ));
+                    for pv in lvm.physical_volumes.iter().filter(|pv| pv.vg_name == group.vg_name) {
+                        section = section.add(
+                            settings::item::builder(pv.pv_name.clone())
+                                .description(fl!("physical-volume"))
+                                .control(widget::text::body(fl!(
+                                    "size-free",
+                                    size = storage::format_bytes(pv.pv_size),
+                                    free = storage::format_bytes(pv.pv_free)
+                                ))),
+                        );
+                    }
+                    for lv in lvm.logical_volumes.iter().filter(|lv| lv.vg_name == group.vg_name) {
+                        section = section.add(
+                            settings::item::builder(lv.lv_name.clone())
+                                .description(fl!("logical-volume", kind = lv.segtype.as_str()))
+                                .control(widget::text::body(storage::format_bytes(lv.lv_size))),
+                        );
+                    }
+                    column = column.push(section);
+                }
+            }
+            Err(e) => {
+                column = column.push(settings::section().title(fl!("lvm")).add(settings::item(
+                    fl!("not-available"),
+                    widget::text::caption(e.clone()),
+                )));
+            }
+        }
+
+        for disk in &storage.disks {
+            let title = match &disk.model {
+                Some(model) => format!("{} — {model}", disk.name),
+                None => disk.name.clone(),
+            };
+            let mut section = settings::section().title(title);
+            section = section.add(value_item(
+                fl!("size"),
+                storage::format_bytes(disk.size),
+                format!("/sys/block/{}/size", disk.name),
+            ));
+            section = section.add(value_item(
+                fl!("disk-type"),
+                match (disk.removable, disk.rotational) {
+                    (true, _) => fl!("removable"),
+                    (false, true) => fl!("rotational"),
+                    (false, false) => fl!("solid-state"),
+                },
+                format!("/sys/block/{}/removable, queue/rotational", disk.name),
+            ));
+            section = section.add(self.disk_health(health, &disk.name));
+            section = section.add(value_item(
+                fl!("discard-support"),
+                if disk.discard { fl!("supported") } else { fl!("unsupported") },
+                format!("/sys/block/{}/queue/discard_max_bytes", disk.name),
+            ));
+            if let Some(table) = &disk.partition_table {
+                section = section.add(value_item(
+                    fl!("partition-table"),
+                    match table.as_str() {
+                        "gpt" => "GPT".to_string(),
+                        "dos" => "MBR".to_string(),
+                        other => other.to_string(),
+                    },
+                    "udev: ID_PART_TABLE_TYPE",
+                ));
+            }
+            for partition in &disk.partitions {
+                let title = match &partition.label {
+                    Some(label) => format!("{} ({label})", partition.name),
+                    None => partition.name.clone(),
+                };
+                let mut description = vec![fl!("partition-start", offset = storage::format_bytes(partition.start))];
+                if let Some(type_id) = &partition.type_id {
+                    description.push(partition.type_name().map_or_else(|| type_id.clone(), str::to_string));
+                }
+                if let Some(fs_type) = &partition.fs_type {
+                    description.push(fs_type.clone());
+                }
+
+                let mut control = row::with_capacity(2)
+                    .push(widget::text::body(storage::format_bytes(partition.size)))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs);
+                if !disk.is_aligned(partition) {
+                    control = control.push(widget::tooltip(
+                        self.status_indicator(Severity::Warning),
+                        widget::text::body(fl!("misaligned")),
+                        widget::tooltip::Position::Left,
+                    ));
+                } else if !disk.is_optimally_aligned(partition) {
+                    control = control.push(widget::tooltip(
+                        self.status_indicator(Severity::Info),
+                        widget::text::body(fl!("not-mib-aligned")),
+                        widget::tooltip::Position::Left,
+                    ));
+                }
+
+                section = section.add(
+                    settings::item::builder(title)
+                        .description(description.join(" · "))
+                        .control(control),
+                );
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+
+    /// The SMART health row of a disk, with a button to read it as administrator.
+    fn disk_health<'a>(
+        &'a self,
+        health: &'a HashMap<String, Result<smart::Health, host::Error>>,
+        disk: &str,
+    ) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        // NVMe disks fall back to nvme-cli when smartctl is missing.
+        let readable = tools::is_available("smartctl") || (disk.starts_with("nvme") && tools::is_available("nvme"));
+        if !readable {
+            return missing_tool(fl!("disk-health"), "smartctl");
+        }
+        let read_button = |title: String, hint: Option<String>| {
+            settings::item::builder(title)
+                .description(hint.unwrap_or_else(|| fl!("requires-root")))
+                .control(
+                    widget::button::standard(fl!("read-as-administrator"))
+                        .on_press(Message::ReadDiskHealth(disk.to_string())),
+                )
+                .into()
+        };
+        let health = match health.get(disk) {
+            Some(Ok(health)) => health,
+            Some(Err(e)) => return read_button(error_explanation(e), install_hint(e)),
+            None => return read_button(fl!("disk-health"), None),
+        };
+
+        let (label, severity) = match health.passed {
+            Some(true) => (fl!("health-passed"), Severity::Good),
+            Some(false) => (fl!("health-failed"), Severity::Critical),
+            None => (fl!("unknown"), Severity::Info),
+        };
+        let status = settings::item(
+            fl!("disk-health"),
+            row::with_capacity(2)
+                .push(widget::text::body(label))
+                .push(self.status_indicator(severity))
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xxs),
+        );
+        if health.attributes.is_empty() {
+            return status.into();
+        }
+
+        let mut attributes = list_column();
+        for (key, value) in &health.attributes {
+            attributes = attributes.add(value_item(
+                key.as_str(),
+                value.as_str(),
+                format!("smartctl --all /dev/{disk}: {key}"),
+            ));
+        }
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_xxs)
+            .push(status)
+            .push(self.collapsible(
+                Page::Filesystems,
+                format!("health/{disk}"),
+                fl!("disk-health-details"),
+                attributes,
+            ))
+            .into()
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Firmware page, listing the devices fwupd manages.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, fwupd, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = fwupd::Firmware;
+
+    const PAGE: Page = Page::Firmware;
+
+    fn id(&self) -> &'static str {
+        "firmware"
+    }
+
+    fn name(&self) -> String {
+        fl!("firmware")
+    }
+
+    fn icon(&self) -> &'static str {
+        "application-x-firmware-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("version", "firmware-version")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "fwupd"
+    }
+
+    fn collect(&self) -> Result<fwupd::Firmware, host::Error> {
+        fwupd::collect().map_err(|e| host::bus_error("fwupd", &e))
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a fwupd::Firmware) -> Element<'a, Message> {
+        app.firmware(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-firmware-source"), fl!("help-firmware-fields"), fl!("help-firmware-problems"))
+    }
+}
+
+impl AppModel {
+    /// The firmware page, listing devices managed by fwupd.
+    pub fn firmware<'a>(&'a self, firmware: &'a fwupd::Firmware) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let mut column = widget::column::with_capacity(firmware.devices.len() + 1)
+            .spacing(spacing.space_m)
+            .push(
+                settings::section().add(settings::item(
+                    fl!("fwupd-version", version = firmware.daemon_version.as_str()),
+                    widget::button::standard(fl!("open-updater")).on_press(Message::LaunchUpdater),
+                )),
+            );
+
+        for device in &firmware.devices {
+            let mut section = settings::section().title(device.name.clone());
+
+            if let Some(vendor) = &device.vendor {
+                section = section.add(value_item(fl!("vendor"), vendor.clone(), "fwupd: Vendor"));
+            }
+            section = section.add(value_item(
+                fl!("firmware-version"),
+                device.version.clone().unwrap_or_else(|| fl!("not-provided")),
+                "fwupd: Version",
+            ));
+            if let Some(plugin) = &device.plugin {
+                section = section.add(value_item(fl!("fwupd-plugin"), plugin.clone(), "fwupd: Plugin"));
+            }
+            let status = match (&device.upgrade, device.updatable) {
+                (Some(version), _) => fl!("update-available", version = version.as_str()),
+                (None, true) => fl!("up-to-date"),
+                (None, false) => fl!("not-updatable"),
+            };
+            section = section.add(value_item(fl!("update-status"), status, "fwupd: GetUpgrades"));
+
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
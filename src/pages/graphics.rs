@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Graphics page, from vulkaninfo, glxinfo, vainfo, vdpauinfo and the DRM render nodes.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message, Severity};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, graphics, host};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = graphics::Graphics;
+
+    const PAGE: Page = Page::Graphics;
+
+    fn id(&self) -> &'static str {
+        "graphics"
+    }
+
+    fn name(&self) -> String {
+        fl!("graphics")
+    }
+
+    fn icon(&self) -> &'static str {
+        "video-display-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["vulkaninfo", "glxinfo", "vainfo", "vdpauinfo"]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/drm"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "render_nodes" => Some("/dev/dri"),
+            "vulkan" => Some("vulkaninfo --summary"),
+            "opengl" => Some("glxinfo -B"),
+            "vaapi" => Some("vainfo"),
+            "vdpau" => Some("vdpauinfo"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<graphics::Graphics, host::Error> {
+        Ok(graphics::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a graphics::Graphics) -> Element<'a, Message> {
+        app.graphics(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-graphics-source"), fl!("help-graphics-fields"), fl!("help-graphics-problems"))
+    }
+}
+
+impl AppModel {
+    /// The graphics page, listing Vulkan, OpenGL, VA-API and VDPAU capabilities.
+    pub fn graphics<'a>(&'a self, graphics: &'a graphics::Graphics) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let unavailable = |title: String, error: &str| {
+            settings::section().title(title).add(settings::item(
+                fl!("not-available"),
+                widget::text::caption(error.to_string()),
+            ))
+        };
+        type Section = widget::settings::Section<'static, Message>;
+        let optional = |section: Section, title: String, value: &Option<String>, source: &str| match value {
+            Some(value) => section.add(value_item(title, value.clone(), source)),
+            None => section,
+        };
+
+        let mut column = widget::column::with_capacity(5).spacing(spacing.space_m);
+
+        if !graphics.gpus.is_empty() {
+            let mut section = settings::section().title(fl!("gpu-firmware"));
+            for gpu in &graphics.gpus {
+                let mut item = settings::item::builder(gpu.card.clone());
+                if let Some(driver) = &gpu.driver {
+                    item = item.description(driver.clone());
+                }
+                section = section.add(item.control(widget::text::body(
+                    gpu.vbios_version.clone().unwrap_or_else(|| fl!("not-available")),
+                )));
+            }
+            column = column.push(section);
+        }
+
+        if !graphics.render_nodes.is_empty() {
+            let mut section = settings::section().title(fl!("render-nodes"));
+            for node in &graphics.render_nodes {
+                let mut description = [node.card.clone(), node.driver.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                description.push(fl!(
+                    "render-node-permissions",
+                    group = node.group.clone().unwrap_or_else(|| fl!("unknown")),
+                    mode = format!("{:04o}", node.mode)
+                ));
+                let access = row::with_capacity(2)
+                    .push(widget::text::body(if node.accessible {
+                        fl!("accessible")
+                    } else {
+                        fl!("not-accessible")
+                    }))
+                    .push(self.status_indicator(if node.accessible {
+                        Severity::Good
+                    } else {
+                        Severity::Warning
+                    }))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs);
+                section = section.add(
+                    settings::item::builder(format!("/dev/dri/{}", node.node))
+                        .description(description.join(" · "))
+                        .control(access),
+                );
+            }
+            column = column.push(section);
+        }
+
+        match &graphics.vulkan {
+            Ok(devices) => {
+                for device in devices {
+                    let mut section = settings::section()
+                        .title(fl!("vulkan-device", name = device.device_name.as_str()));
+                    for (title, value, key) in [
+                        (fl!("device-type"), &device.device_type, "deviceType"),
+                        (fl!("api-version"), &device.api_version, "apiVersion"),
+                        (fl!("driver-name"), &device.driver_name, "driverName"),
+                        (fl!("driver-info"), &device.driver_info, "driverInfo"),
+                    ] {
+                        let source = format!("vulkaninfo --summary: {key}");
+                        section = optional(section, title, value, &source);
+                    }
+                    column = column.push(section);
+                }
+            }
+            Err(e) => column = column.push(unavailable(fl!("vulkan"), e)),
+        }
+
+        match &graphics.opengl {
+            Ok(opengl) => {
+                let mut section = settings::section().title(fl!("opengl"));
+                section = optional(section, fl!("vendor"), &opengl.vendor, "glxinfo -B: OpenGL vendor string");
+                section = optional(section, fl!("renderer"), &opengl.renderer, "glxinfo -B: OpenGL renderer string");
+                section = optional(
+                    section,
+                    fl!("core-profile-version"),
+                    &opengl.core_version,
+                    "glxinfo -B: OpenGL core profile version string",
+                );
+                section = optional(
+                    section,
+                    fl!("compat-profile-version"),
+                    &opengl.compat_version,
+                    "glxinfo -B: OpenGL version string",
+                );
+                section = optional(
+                    section,
+                    fl!("es-profile-version"),
+                    &opengl.es_version,
+                    "glxinfo -B: OpenGL ES profile version string",
+                );
+                column = column.push(section);
+            }
+            Err(e) => column = column.push(unavailable(fl!("opengl"), e)),
+        }
+
+        match &graphics.vaapi {
+            Ok(vaapi) => {
+                let mut section = settings::section().title(fl!("vaapi"));
+                section = optional(section, fl!("api-version"), &vaapi.api_version, "vainfo: VA-API version");
+                section = optional(section, fl!("driver-name"), &vaapi.driver, "vainfo: Driver version");
+                section = section.add(value_item(
+                    fl!("decode-profiles"),
+                    vaapi.decode.join(", "),
+                    "vainfo: VAEntrypointVLD",
+                ));
+                section = section.add(value_item(
+                    fl!("encode-profiles"),
+                    vaapi.encode.join(", "),
+                    "vainfo: VAEntrypointEncSlice",
+                ));
+                column = column.push(section);
+            }
+            Err(e) => column = column.push(unavailable(fl!("vaapi"), e)),
+        }
+
+        match &graphics.vdpau {
+            Ok(vdpau) => {
+                let mut section = settings::section().title(fl!("vdpau"));
+                section = optional(section, fl!("driver-info"), &vdpau.information, "vdpauinfo: Information string");
+                section = section.add(value_item(
+                    fl!("decode-profiles"),
+                    vdpau.decode.join(", "),
+                    "vdpauinfo: Decoder capabilities",
+                ));
+                column = column.push(section);
+            }
+            Err(e) => column = column.push(unavailable(fl!("vdpau"), e)),
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The System Identity page, from systemd-hostnamed.
+
+use cosmic::widget::settings;
+use cosmic::{Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, identity};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = identity::Identity;
+
+    const PAGE: Page = Page::Identity;
+
+    fn id(&self) -> &'static str {
+        "identity"
+    }
+
+    fn name(&self) -> String {
+        fl!("system-identity")
+    }
+
+    fn icon(&self) -> &'static str {
+        "user-info-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn origin(&self) -> &'static str {
+        "hostnamed"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "hostname" => Some("hostnamed: Hostname"),
+            "static_hostname" => Some("hostnamed: StaticHostname"),
+            "pretty_hostname" => Some("hostnamed: PrettyHostname"),
+            "chassis" => Some("hostnamed: Chassis"),
+            "deployment" => Some("hostnamed: Deployment"),
+            "location" => Some("hostnamed: Location"),
+            "machine_id" => Some("/etc/machine-id"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<identity::Identity, host::Error> {
+        identity::collect().map_err(|e| host::bus_error("systemd-hostnamed", &e))
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a identity::Identity) -> Element<'a, Message> {
+        app.identity(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-identity-source"), fl!("help-identity-fields"), fl!("help-identity-problems"))
+    }
+}
+
+impl AppModel {
+    /// The system identity page, showing hostnames, chassis and machine ID from hostnamed.
+    pub fn identity<'a>(&'a self, identity: &'a identity::Identity) -> Element<'a, Message> {
+        let rows = [
+            (fl!("hostname"), identity.hostname.clone(), "hostnamed: Hostname"),
+            (fl!("static-hostname"), identity.static_hostname.clone(), "hostnamed: StaticHostname"),
+            (fl!("pretty-hostname"), identity.pretty_hostname.clone(), "hostnamed: PrettyHostname"),
+            (fl!("chassis-type"), identity.chassis.clone(), "hostnamed: Chassis"),
+            (fl!("machine-id"), identity.machine_id.clone(), "/etc/machine-id"),
+            (fl!("deployment"), identity.deployment.clone(), "hostnamed: Deployment"),
+            (fl!("location"), identity.location.clone(), "hostnamed: Location"),
+        ];
+
+        let mut section = settings::section().title(fl!("system-identity"));
+        for (title, value, source) in rows {
+            section = section.add(value_item(title, value.unwrap_or_else(|| fl!("not-provided")), source));
+        }
+
+        section.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The BMC page, from ipmitool. Without root only what the BMC shares with
+//! any user is read; the rest is read through the privileged helper on request.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+use log::warn;
+use serde_json::Value;
+
+use crate::app::{error_explanation, install_hint, page_scrollable, value_item, AppModel, Message, Severity};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{export, fl, host, ipmi};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Result<ipmi::Bmc, host::Error>;
+
+    const PAGE: Page = Page::Ipmi;
+
+    fn id(&self) -> &'static str {
+        "ipmi"
+    }
+
+    fn name(&self) -> String {
+        fl!("bmc")
+    }
+
+    fn icon(&self) -> &'static str {
+        "network-server-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn is_available(&self) -> bool {
+        ipmi::is_present()
+    }
+
+    fn requires_privilege(&self) -> bool {
+        true
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["ipmitool"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("firmware_revision", "firmware-version")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "ipmitool mc info"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "sensors" => Some("ipmitool sdr"),
+            "fru" => Some("ipmitool fru print"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Result<ipmi::Bmc, host::Error>, host::Error> {
+        Ok(ipmi::collect(false).inspect_err(|e| warn!("ipmitool failed: {}", e)))
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Result<ipmi::Bmc, host::Error>) -> Element<'a, Message> {
+        app.ipmi(section)
+    }
+
+    fn export(&self, _app: &AppModel, section: &Result<ipmi::Bmc, host::Error>) -> Value {
+        export::result(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-bmc-source"), fl!("help-bmc-fields"), fl!("help-bmc-problems"))
+    }
+}
+
+impl AppModel {
+    /// The BMC page, showing the management controller's identity, sensors and FRU data.
+    pub fn ipmi<'a>(&'a self, bmc: &'a Result<ipmi::Bmc, host::Error>) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let bmc = match bmc {
+            Ok(bmc) => bmc,
+            Err(e) => {
+                return settings::section()
+                    .title(fl!("bmc"))
+                    .add(
+                        settings::item::builder(error_explanation(e))
+                            .description(install_hint(e).unwrap_or_else(|| fl!("requires-root")))
+                            .control(
+                                widget::button::standard(fl!("read-as-administrator"))
+                                    .on_press(Message::ReadIpmiPrivileged),
+                            ),
+                    )
+                    .apply(page_scrollable)
+                    .into();
+            }
+        };
+
+        let mut identity = settings::section().title(fl!("bmc"));
+        let rows = [
+            (fl!("manufacturer"), bmc.manufacturer.clone(), "Manufacturer Name"),
+            (fl!("firmware-version"), bmc.firmware_revision.clone(), "Firmware Revision"),
+            (fl!("ipmi-version"), bmc.ipmi_version.clone(), "IPMI Version"),
+        ];
+        for (title, value, key) in rows {
+            if let Some(value) = value {
+                identity = identity.add(value_item(title, value, format!("ipmitool mc info: {key}")));
+            }
+        }
+
+        let mut sensors = settings::section().title(fl!("sensors"));
+        for sensor in &bmc.sensors {
+            let severity = match sensor.status.as_str() {
+                "ok" => Severity::Good,
+                "nc" => Severity::Warning,
+                "cr" | "nr" => Severity::Critical,
+                _ => Severity::Info,
+            };
+            let reading = row::with_capacity(2)
+                .push(widget::text::body(sensor.reading.clone()))
+                .push(self.status_indicator(severity))
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xxs);
+            sensors = sensors.add(settings::item(sensor.name.clone(), reading));
+        }
+
+        let mut column = widget::column::with_capacity(3)
+            .spacing(spacing.space_m)
+            .push(identity)
+            .push(sensors);
+
+        if !bmc.fru.is_empty() {
+            let mut fru = settings::section().title(fl!("fru"));
+            for (key, value) in &bmc.fru {
+                fru = fru.add(value_item(key.clone(), value.clone(), format!("ipmitool fru print: {key}")));
+            }
+            column = column.push(fru);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Memory page, from /proc/meminfo, NUMA nodes, pressure stall
+//! information, EDAC and the memory devices among the SMBIOS records.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{
+    error_explanation, install_hint, missing_tool, page_scrollable, table_header, table_row, usage_gauge, value_item,
+    AppModel, Message, Severity,
+};
+use crate::pages::{motherboard, Category, DataProvider, Page};
+use crate::{dmi, ecc, export, fl, host, memory, pressure, tools};
+
+/// The data of the Memory page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub memory: memory::Memory,
+    pub numa_nodes: Vec<memory::NumaNode>,
+    pub pressure: Vec<pressure::Pressure>,
+    pub ecc: ecc::Ecc,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Memory;
+
+    fn id(&self) -> &'static str {
+        "memory"
+    }
+
+    fn name(&self) -> String {
+        fl!("memory")
+    }
+
+    fn icon(&self) -> &'static str {
+        "memory-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn requires_privilege(&self) -> bool {
+        true
+    }
+
+    /// The memory slots and the ECC correction type come from the SMBIOS
+    /// records the Motherboard page reads.
+    fn origin(&self) -> &'static str {
+        "/proc/meminfo"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "memory.thp_enabled" => Some("/sys/kernel/mm/transparent_hugepage/enabled"),
+            "memory.thp_defrag" => Some("/sys/kernel/mm/transparent_hugepage/defrag"),
+            "numa_nodes" => Some("/sys/devices/system/node"),
+            "pressure" => Some("/proc/pressure"),
+            "ecc" => Some("/sys/devices/system/edac/mc"),
+            "correction_type" | "slots" => Some("dmidecode: Memory Device"),
+            _ => None,
+        }
+    }
+
+    fn sources(&self) -> &'static [Page] {
+        &[Page::Motherboard]
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["dmidecode"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("cpus", "numa-node")]
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            memory: memory::collect()?,
+            numa_nodes: memory::numa_nodes(),
+            pressure: pressure::collect(),
+            ecc: ecc::collect(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.memory(section)
+    }
+
+    fn export(&self, app: &AppModel, section: &Section) -> Value {
+        let records = app.dmi_records();
+        json!({
+            "memory": section.memory,
+            "numa_nodes": section.numa_nodes,
+            "pressure": section.pressure,
+            "ecc": section.ecc,
+            "correction_type": records.and_then(|records| records.as_deref().ok()).and_then(dmi::correction_type),
+            "slots": records.map(|records| export::result(&records.as_deref().map(dmi::memory_devices))),
+        })
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-memory-source"), fl!("help-memory-fields"), fl!("help-memory-problems"))
+    }
+}
+
+impl AppModel {
+    /// The memory page, showing usage and pressure stall information.
+    pub fn memory<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let memory = &section.memory;
+        let pressures = &section.pressure;
+
+        let mut column = widget::column::with_capacity(7).spacing(spacing.space_m);
+
+        let mut usage = settings::section().title(fl!("memory-usage"));
+        if let (Some(total), Some(available)) = (memory.total(), memory.available()) {
+            let used = total.saturating_sub(available);
+            usage = usage.add(settings::item(
+                fl!("memory"),
+                usage_gauge(
+                    used as f32,
+                    total as f32,
+                    fl!(
+                        "used-of-total",
+                        used = memory::format_kib(used),
+                        total = memory::format_kib(total)
+                    ),
+                ),
+            ));
+            usage = usage.add(value_item(
+                fl!("available"),
+                memory::format_kib(available),
+                "/proc/meminfo: MemAvailable",
+            ));
+        }
+        if let (Some(total), Some(free)) = (memory.swap_total(), memory.swap_free()) {
+            let used = total.saturating_sub(free);
+            usage = usage.add(settings::item(
+                fl!("swap"),
+                usage_gauge(
+                    used as f32,
+                    total as f32,
+                    fl!(
+                        "used-of-total",
+                        used = memory::format_kib(used),
+                        total = memory::format_kib(total)
+                    ),
+                ),
+            ));
+        }
+        column = column.push(usage);
+
+        column = column.push(self.memory_slots());
+
+        column = column.push(self.ecc_section(&section.ecc));
+
+        let mut hugepages = settings::section().title(fl!("hugepages"));
+        if let (Some(total), Some(free)) = (memory.hugepages_total(), memory.hugepages_free()) {
+            hugepages = hugepages.add(value_item(
+                fl!("hugepages-reserved"),
+                fl!("hugepages-free-of-total", free = free, total = total),
+                "/proc/meminfo: HugePages_Total, HugePages_Free",
+            ));
+        }
+        if let Some(size) = memory.hugepage_size() {
+            hugepages = hugepages.add(value_item(
+                fl!("hugepage-size"),
+                memory::format_kib(size),
+                "/proc/meminfo: Hugepagesize",
+            ));
+        }
+        hugepages = hugepages
+            .add(value_item(
+                fl!("thp-enabled"),
+                memory.thp_enabled.clone().unwrap_or_else(|| fl!("not-available")),
+                "/sys/kernel/mm/transparent_hugepage/enabled",
+            ))
+            .add(value_item(
+                fl!("thp-defrag"),
+                memory.thp_defrag.clone().unwrap_or_else(|| fl!("not-available")),
+                "/sys/kernel/mm/transparent_hugepage/defrag",
+            ));
+        column = column.push(hugepages);
+
+        // A single node is the common case and says nothing about the topology.
+        if section.numa_nodes.len() > 1 {
+            let mut nodes = settings::section().title(fl!("numa-nodes"));
+            for node in &section.numa_nodes {
+                let item = settings::item::builder(fl!("numa-node-id", id = node.id)).description(
+                    if node.cpus.is_empty() {
+                        fl!("numa-no-cpus")
+                    } else {
+                        fl!("numa-cpus", cpus = node.cpus.as_str())
+                    },
+                );
+                let control: Element<Message> = match (node.total_kib, node.free_kib) {
+                    (Some(total), Some(free)) => {
+                        let used = total.saturating_sub(free);
+                        usage_gauge(
+                            used as f32,
+                            total as f32,
+                            fl!(
+                                "used-of-total",
+                                used = memory::format_kib(used),
+                                total = memory::format_kib(total)
+                            ),
+                        )
+                    }
+                    _ => widget::text::body(fl!("unknown")).into(),
+                };
+                nodes = nodes.add(item.control(control));
+            }
+            column = column.push(nodes);
+
+            let mut distances = settings::section().title(fl!("numa-distances")).add(table_header(
+                std::iter::once(fl!("cpu-node")).chain(section.numa_nodes.iter().map(|node| node.id.to_string())),
+            ));
+            for node in &section.numa_nodes {
+                distances = distances.add(table_row(
+                    std::iter::once(node.id.to_string())
+                        .chain(node.distances.iter().map(ToString::to_string)),
+                ));
+            }
+            column = column.push(distances);
+        }
+
+        if !pressures.is_empty() {
+            let mut section = settings::section().title(fl!("pressure"));
+            for pressure in pressures {
+                let title = match pressure.resource {
+                    pressure::Resource::Cpu => fl!("pressure-cpu"),
+                    pressure::Resource::Memory => fl!("pressure-memory"),
+                    pressure::Resource::Io => fl!("pressure-io"),
+                };
+                let some = pressure.some;
+                section = section.add(
+                    settings::item::builder(title)
+                        .description(fl!(
+                            "pressure-averages",
+                            avg10 = format!("{:.2}", some.avg10),
+                            avg60 = format!("{:.2}", some.avg60),
+                            avg300 = format!("{:.2}", some.avg300)
+                        ))
+                        .control(usage_gauge(some.avg10, 100.0, format!("{:.2} %", some.avg10))),
+                );
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+
+    /// Whether ECC is active and the error counts of each memory controller.
+    fn ecc_section<'a>(&'a self, ecc: &'a ecc::Ecc) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let correction_type = self
+            .dmi_records()
+            .and_then(|records| records.as_deref().ok())
+            .and_then(dmi::correction_type);
+        let (ecc_label, ecc_severity) = match ecc.active(correction_type) {
+            Some(true) => (fl!("ecc-active"), Severity::Good),
+            Some(false) => (fl!("ecc-inactive"), Severity::Warning),
+            None => (fl!("unknown"), Severity::Info),
+        };
+        let mut ecc_section = settings::section().title(fl!("error-correction")).add(
+            settings::item::builder(fl!("ecc"))
+                .description(correction_type.unwrap_or_default())
+                .control(
+                    row::with_capacity(2)
+                        .push(widget::text::body(ecc_label))
+                        .push(self.status_indicator(ecc_severity))
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs),
+                ),
+        );
+        let error_counts = |corrected: u64, uncorrected: u64| {
+            let severity = if uncorrected > 0 {
+                Severity::Critical
+            } else if corrected > 0 {
+                Severity::Warning
+            } else {
+                Severity::Good
+            };
+            row::with_capacity(2)
+                .push(widget::text::body(fl!(
+                    "ecc-errors",
+                    corrected = corrected,
+                    uncorrected = uncorrected
+                )))
+                .push(self.status_indicator(severity))
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xxs)
+        };
+        for controller in &ecc.controllers {
+            ecc_section = ecc_section.add(settings::item(
+                controller.name.clone(),
+                error_counts(controller.corrected, controller.uncorrected),
+            ));
+            for dimm in &controller.dimms {
+                ecc_section = ecc_section.add(
+                    settings::item::builder(dimm.label.clone())
+                        .description(dimm.mode.clone().unwrap_or_default())
+                        .control(error_counts(dimm.corrected, dimm.uncorrected)),
+                );
+            }
+        }
+        ecc_section.into()
+    }
+
+    /// The DIMM slots laid out by channel, once the DMI tables have been read.
+    fn memory_slots(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let section = settings::section().title(fl!("memory-slots"));
+        if !tools::is_available("dmidecode") {
+            return section.add(missing_tool(fl!("memory-slots"), "dmidecode")).into();
+        }
+        let records = match self.dmi_records() {
+            Some(Ok(records)) => records,
+            Some(Err(e)) => {
+                return section
+                    .add(
+                        settings::item::builder(error_explanation(e))
+                            .description(install_hint(e).unwrap_or_else(|| fl!("requires-root")))
+                            .control(
+                                widget::button::standard(fl!("read-as-administrator"))
+                                    .on_press(Message::Motherboard(motherboard::Message::ReadDmi)),
+                            ),
+                    )
+                    .into();
+            }
+            None => {
+                return section
+                    .add(
+                        settings::item::builder(fl!("memory-slots-read"))
+                            .description(fl!("requires-root"))
+                            .control(
+                                widget::button::standard(fl!("read-as-administrator"))
+                                    .on_press(Message::Motherboard(motherboard::Message::ReadDmi)),
+                            ),
+                    )
+                    .into();
+            }
+        };
+
+        let devices = dmi::memory_devices(records);
+        if devices.is_empty() {
+            return section
+                .add(settings::item(fl!("memory-slots"), widget::text::body(fl!("not-available"))))
+                .into();
+        }
+
+        let populated = devices.iter().filter(|device| device.size.is_some()).count();
+        let mut section = section.add(value_item(
+            fl!("memory-slots-populated"),
+            fl!("slots-populated", populated = populated, total = devices.len()),
+            "dmidecode: Memory Device",
+        ));
+
+        for (channel, slots) in dmi::by_channel(&devices) {
+            let title = match channel {
+                Some(channel) => fl!("memory-channel", channel = channel),
+                None => fl!("memory-other-slots"),
+            };
+            let cards = slots.into_iter().map(|slot| {
+                let mut card = widget::column::with_capacity(3)
+                    .spacing(spacing.space_xxxs)
+                    .push(widget::text::heading(slot.locator.clone()));
+                let container = match &slot.size {
+                    Some(size) => {
+                        let speed = slot.configured_speed.as_deref().or(slot.speed.as_deref());
+                        let kind = [slot.memory_type.as_deref(), speed].into_iter().flatten().join(" · ");
+                        card = card.push(widget::text::body(size.clone()));
+                        if !kind.is_empty() {
+                            card = card.push(widget::text::caption(kind));
+                        }
+                        widget::container(card).class(theme::Container::Card)
+                    }
+                    None => widget::container(card.push(widget::text::caption(fl!("empty-slot")))),
+                };
+                let container = container.padding(spacing.space_xs).width(Length::Fixed(160.0));
+                let tooltip = [slot.manufacturer.as_deref(), slot.part_number.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .join(" ");
+                if tooltip.is_empty() {
+                    container.into()
+                } else {
+                    widget::tooltip(container, widget::text::body(tooltip), widget::tooltip::Position::Top).into()
+                }
+            });
+
+            section = section.add(
+                widget::column::with_capacity(2)
+                    .spacing(spacing.space_xxs)
+                    .push(widget::text::body(title))
+                    .push(
+                        widget::flex_row(cards.collect::<Vec<Element<Message>>>())
+                            .row_spacing(spacing.space_xxs)
+                            .column_spacing(spacing.space_xxs),
+                    ),
+            );
+        }
+
+        section.into()
+    }
+}
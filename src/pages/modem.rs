@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Modem page, listing the WWAN modems ModemManager knows.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, usage_gauge, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, modem};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<modem::Modem>;
+
+    const PAGE: Page = Page::Modem;
+
+    fn id(&self) -> &'static str {
+        "modem"
+    }
+
+    fn name(&self) -> String {
+        fl!("modem")
+    }
+
+    fn icon(&self) -> &'static str {
+        "network-cellular-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("revision", "firmware-version"), ("state", "status")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "ModemManager"
+    }
+
+    fn collect(&self) -> Result<Vec<modem::Modem>, host::Error> {
+        modem::collect().map_err(|e| host::bus_error("ModemManager", &e))
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<modem::Modem>) -> Element<'a, Message> {
+        app.modem(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-modem-source"), fl!("help-modem-fields"), fl!("help-modem-problems"))
+    }
+}
+
+impl AppModel {
+    /// The modem page, listing WWAN modems known to ModemManager.
+    pub fn modem<'a>(&'a self, modems: &'a [modem::Modem]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if modems.is_empty() {
+            return widget::text::title1(fl!("no-modems")).into();
+        }
+
+        let mut column = widget::column::with_capacity(modems.len()).spacing(spacing.space_m);
+        for modem in modems {
+            let title = [modem.manufacturer.as_deref(), modem.model.as_deref()]
+                .into_iter()
+                .flatten()
+                .join(" ");
+            let mut section = settings::section().title(title);
+            let rows = [
+                (fl!("firmware-version"), modem.revision.clone(), "Revision"),
+                (fl!("imei"), modem.imei.clone(), "Imei"),
+                (fl!("status"), modem.state.clone(), "State"),
+                (fl!("operator"), modem.operator.clone(), "OperatorName"),
+                (fl!("registration"), modem.registration.clone(), "RegistrationState"),
+            ];
+            for (title, value, property) in rows {
+                if let Some(value) = value {
+                    section = section.add(value_item(title, value, format!("ModemManager: {property}")));
+                }
+            }
+            if let Some(quality) = modem.signal_quality {
+                section = section.add(settings::item(
+                    fl!("signal"),
+                    usage_gauge(quality as f32, 100.0, format!("{quality} %")),
+                ));
+            }
+            let sim = match modem.sim {
+                modem::SimState::Missing => fl!("sim-missing"),
+                modem::SimState::Locked => fl!("sim-locked"),
+                modem::SimState::Ready => match &modem.sim_operator {
+                    Some(operator) => fl!("sim-ready-operator", operator = operator.as_str()),
+                    None => fl!("sim-ready"),
+                },
+            };
+            section = section.add(value_item(fl!("sim"), sim, "ModemManager: Sim, UnlockRequired"));
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Motherboard page, from sysfs DMI attributes and the full SMBIOS tables.
+
+use cosmic::app::Task;
+use cosmic::widget::{self, list_column, settings};
+use cosmic::{theme, Apply, Element};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{self, error_explanation, install_hint, missing_tool, page_scrollable, value_item, AppModel};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{board, dmi, export, fl, host, tools};
+
+/// The data of the Motherboard page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub board: board::Board,
+    /// SMBIOS records, read on request because dmidecode needs root. The
+    /// Memory page lists the memory devices among them.
+    pub dmi: Option<Result<Vec<dmi::Record>, host::Error>>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Motherboard;
+
+    fn id(&self) -> &'static str {
+        "motherboard"
+    }
+
+    fn name(&self) -> String {
+        fl!("motherboard")
+    }
+
+    fn icon(&self) -> &'static str {
+        "computer-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn requires_privilege(&self) -> bool {
+        true
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["dmidecode"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("system_vendor", "manufacturer"),
+            ("board_vendor", "manufacturer"),
+            ("bios_vendor", "manufacturer"),
+            ("board_name", "product-name"),
+            ("board_version", "product-version"),
+            ("dmi", "dmi-tables"),
+            ("family", "soc-family"),
+            ("machine", "soc-machine"),
+            ("revision", "soc-revision"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/dmi/id"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "board.device_tree" => Some("/proc/device-tree, /sys/devices/soc0"),
+            "dmi" => Some("dmidecode"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            board: board::collect(),
+            dmi: None,
+        })
+    }
+
+    /// The SMBIOS records are only read on request, and do not change.
+    fn carry_over(&self, old: Section, new: &mut Section) {
+        new.dmi = old.dmi;
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, app::Message> {
+        app.motherboard(section)
+    }
+
+    fn export(&self, _app: &AppModel, section: &Section) -> Value {
+        json!({
+            "board": section.board,
+            "dmi": section.dmi.as_ref().map(export::result),
+        })
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-motherboard-source"),
+            fl!("help-motherboard-fields"),
+            fl!("help-motherboard-problems"),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Reads the SMBIOS records through the privileged helper.
+    ReadDmi,
+    DmiRead(Result<Vec<dmi::Record>, host::Error>),
+}
+
+impl AppModel {
+    pub(crate) fn update_motherboard(&mut self, message: Message) -> Task<app::Message> {
+        match message {
+            Message::ReadDmi => {
+                let task = Task::perform(
+                    async {
+                        tokio::task::spawn_blocking(dmi::collect)
+                            .await
+                            .map_err(|e| host::Error::Failed(e.to_string()))?
+                    },
+                    |result| cosmic::app::Message::App(app::Message::Motherboard(Message::DmiRead(result))),
+                );
+                return self.start_operation(fl!("operation-dmi"), task);
+            }
+            Message::DmiRead(result) => {
+                if let Err(e) = &result {
+                    error!("failed to read the DMI tables: {}", e);
+                }
+                if let Some(motherboard) = self.sections.get_mut::<Provider>() {
+                    motherboard.dmi = Some(result);
+                }
+            }
+        }
+        Task::none()
+    }
+
+    /// The SMBIOS records, once they have been read.
+    pub(crate) fn dmi_records(&self) -> Option<&Result<Vec<dmi::Record>, host::Error>> {
+        self.sections.get::<Provider>()?.dmi.as_ref()
+    }
+
+    /// The motherboard page, from DMI on PCs and the device tree on ARM and RISC-V boards.
+    pub fn motherboard<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let board = &section.board;
+        // Each value is shown with the file or dmidecode field it came from.
+        let optional = |section: widget::settings::Section<'static, app::Message>,
+                        title: String,
+                        value: &Option<String>,
+                        source: String| {
+            match value {
+                Some(value) => section.add(value_item(title, value.clone(), source)),
+                None => section,
+            }
+        };
+        let dmi_source = |attribute: &str| format!("{}/{attribute}", board::DMI_DIR);
+        let device_tree_source = |property: &str| format!("{}/{property}", board::DEVICE_TREE_DIR);
+        let soc_source = |attribute: &str| format!("{}/{attribute}", board::SOC_DIR);
+        let serial_source = |dmi_type: u8| format!("dmidecode -t {dmi_type}: Serial Number");
+
+        let mut column = widget::column::with_capacity(6).spacing(spacing.space_m);
+
+        if let Some(dmi) = &board.dmi {
+            // Serial numbers are only readable by root, so they come from
+            // dmidecode once the DMI tables have been read.
+            let records = section.dmi.as_ref().and_then(|dmi| dmi.as_ref().ok());
+            let serial = |dmi_type: u8| {
+                records?
+                    .iter()
+                    .find(|record| record.dmi_type == dmi_type)?
+                    .get("Serial Number")
+                    .map(str::to_string)
+            };
+
+            let mut system = settings::section().title(fl!("system"));
+            system = optional(system, fl!("manufacturer"), &dmi.system_vendor, dmi_source("sys_vendor"));
+            system = optional(system, fl!("product-name"), &dmi.product_name, dmi_source("product_name"));
+            system = optional(system, fl!("product-version"), &dmi.product_version, dmi_source("product_version"));
+            system = optional(system, fl!("product-family"), &dmi.product_family, dmi_source("product_family"));
+            system = optional(system, fl!("product-sku"), &dmi.product_sku, dmi_source("product_sku"));
+            system = optional(system, fl!("serial-number"), &serial(1), serial_source(1));
+            system = optional(system, fl!("chassis-type"), &dmi.chassis_type, dmi_source("chassis_type"));
+            system = optional(system, fl!("chassis-vendor"), &dmi.chassis_vendor, dmi_source("chassis_vendor"));
+            column = column.push(system);
+
+            let mut motherboard = settings::section().title(fl!("motherboard"));
+            motherboard = optional(motherboard, fl!("manufacturer"), &dmi.board_vendor, dmi_source("board_vendor"));
+            motherboard = optional(motherboard, fl!("product-name"), &dmi.board_name, dmi_source("board_name"));
+            motherboard = optional(motherboard, fl!("product-version"), &dmi.board_version, dmi_source("board_version"));
+            motherboard = optional(motherboard, fl!("serial-number"), &serial(2), serial_source(2));
+            column = column.push(motherboard);
+
+            let mut bios = settings::section().title(fl!("bios"));
+            bios = optional(bios, fl!("manufacturer"), &dmi.bios_vendor, dmi_source("bios_vendor"));
+            bios = optional(bios, fl!("bios-version"), &dmi.bios_version, dmi_source("bios_version"));
+            bios = optional(bios, fl!("bios-date"), &dmi.bios_date, dmi_source("bios_date"));
+            bios = optional(bios, fl!("bios-release"), &dmi.bios_release, dmi_source("bios_release"));
+            column = column.push(bios);
+        }
+
+        if let Some(device_tree) = &board.device_tree {
+            let mut section = settings::section().title(fl!("device-tree"));
+            section = optional(section, fl!("model"), &device_tree.model, device_tree_source("model"));
+            if !device_tree.compatible.is_empty() {
+                section = section.add(value_item(
+                    fl!("compatible"),
+                    device_tree.compatible.join("\n"),
+                    device_tree_source("compatible"),
+                ));
+            }
+            section = optional(section, fl!("serial-number"), &device_tree.serial_number, device_tree_source("serial-number"));
+            column = column.push(section);
+
+            if let Some(soc) = &device_tree.soc {
+                let mut section = settings::section().title(fl!("soc"));
+                section = optional(section, fl!("soc-family"), &soc.family, soc_source("family"));
+                section = optional(section, fl!("soc-machine"), &soc.machine, soc_source("machine"));
+                section = optional(section, fl!("soc-id"), &soc.soc_id, soc_source("soc_id"));
+                section = optional(section, fl!("soc-revision"), &soc.revision, soc_source("revision"));
+                column = column.push(section);
+            }
+        }
+
+        if board.dmi.is_none() && board.device_tree.is_none() {
+            return widget::text::title1(fl!("no-board-info")).into();
+        }
+
+        if board.dmi.is_some() {
+            column = column.push(self.dmi_tables(section));
+        }
+
+        column.apply(page_scrollable).into()
+    }
+
+    /// Every SMBIOS record from dmidecode, each in its own collapsible section.
+    fn dmi_tables<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let read_button = |title: String, hint: Option<String>| {
+            settings::section()
+                .title(fl!("dmi-tables"))
+                .add(
+                    settings::item::builder(title)
+                        .description(hint.unwrap_or_else(|| fl!("requires-root")))
+                        .control(
+                            widget::button::standard(fl!("read-as-administrator"))
+                                .on_press(app::Message::Motherboard(Message::ReadDmi)),
+                        ),
+                )
+                .into()
+        };
+        if !tools::is_available("dmidecode") {
+            return settings::section()
+                .title(fl!("dmi-tables"))
+                .add(missing_tool(fl!("dmi-tables-read"), "dmidecode"))
+                .into();
+        }
+        let records = match &section.dmi {
+            Some(Ok(records)) => records,
+            Some(Err(e)) => return read_button(error_explanation(e), install_hint(e)),
+            None => return read_button(fl!("dmi-tables-read"), None),
+        };
+
+        let mut column = widget::column::with_capacity(records.len() + 1)
+            .spacing(spacing.space_xs)
+            .push(widget::text::heading(fl!("dmi-tables")));
+        for record in records {
+            let mut fields = list_column();
+            for (key, value) in &record.fields {
+                let source = format!("dmidecode -t {}: {key}", record.dmi_type);
+                fields = fields.add(value_item(key.clone(), value.clone(), source));
+            }
+            column = column.push(self.collapsible(
+                Page::Motherboard,
+                record.handle.clone(),
+                record.title.clone(),
+                fields,
+            ));
+        }
+        column.into()
+    }
+}
@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Overview page, summarizing the highlights of the other pages, with a
+//! fetch view in the style of neofetch.
+
+use std::fs;
+
+use cosmic::iced::Color;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{distribution, filesystems, graphics, memory, power, processor, Category, DataProvider, Page};
+use crate::{fl, host, load, storage};
+
+/// The logo drawn in the overview's fetch view.
+const FETCH_LOGO: &str = r"    .--.
+   |o_o |
+   |:_/ |
+  //   \ \
+ (|     | )
+/'\_   _/`\
+\___)=(___/";
+
+/// The data the overview reads itself. The rest it takes from the pages in
+/// its [`DataProvider::sources`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub kernel: Option<String>,
+    pub load: Result<load::Load, host::Error>,
+    /// The boot time in local time, from `uptime --since`.
+    pub boot_time: Option<String>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Overview;
+
+    fn id(&self) -> &'static str {
+        "overview"
+    }
+
+    fn name(&self) -> String {
+        fl!("overview")
+    }
+
+    fn icon(&self) -> &'static str {
+        "user-home-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn origin(&self) -> &'static str {
+        "/proc/uptime, /proc/loadavg"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        OverviewRow::ALL.into_iter().find(|row| row.key() == path).map(OverviewRow::source)
+    }
+
+    fn sources(&self) -> &'static [Page] {
+        &[Page::Distribution, Page::Processor, Page::Memory, Page::Graphics, Page::Filesystems, Page::Power]
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            kernel: fs::read_to_string(host::path("/proc/sys/kernel/osrelease"))
+                .ok()
+                .map(|release| release.trim().to_string()),
+            load: load::collect(),
+            boot_time: load::boot_time(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.overview(section)
+    }
+
+    fn export(&self, app: &AppModel, section: &Section) -> Value {
+        Value::Object(
+            app.overview_rows(Some(section))
+                .into_iter()
+                .map(|(row, _, value)| (row.key().to_string(), json!(value)))
+                .collect(),
+        )
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-overview-source"), fl!("help-overview-fields"), fl!("help-overview-problems"))
+    }
+}
+
+impl AppModel {
+    /// The overview page, summarizing the highlights of the other pages.
+    pub fn overview<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let os_release = self.sections.get::<distribution::Provider>();
+        let rows = self.overview_rows(Some(section));
+
+        let toggle = settings::section().add(
+            settings::item::builder(fl!("fetch-view"))
+                .description(fl!("fetch-view-description"))
+                .toggler(self.fetch_view, Message::ToggleFetchView),
+        );
+
+        let content: Element<Message> = if self.fetch_view {
+            let color = os_release
+                .and_then(|os_release| os_release.ansi_color.as_deref())
+                .and_then(ansi_color)
+                .unwrap_or_else(|| theme::active().cosmic().accent_color().into());
+            let logo = FETCH_LOGO.lines().fold(
+                widget::column::with_capacity(FETCH_LOGO.lines().count()),
+                |column, line| column.push(widget::text::monotext(line).class(theme::Text::Color(color))),
+            );
+            let specs = rows
+                .into_iter()
+                .filter_map(|(_, title, value)| Some((title, value?)))
+                .fold(widget::column::with_capacity(8), |column, (title, value)| {
+                    column.push(
+                        row::with_capacity(2)
+                            .push(widget::text::monotext(format!("{title}:")).class(theme::Text::Color(color)))
+                            .push(widget::text::monotext(value))
+                            .spacing(spacing.space_xs),
+                    )
+                });
+            row::with_capacity(2)
+                .push(logo)
+                .push(specs)
+                .spacing(spacing.space_l)
+                .padding(spacing.space_m)
+                .into()
+        } else {
+            let mut section = settings::section().title(fl!("overview"));
+            for (row, title, value) in rows {
+                if let Some(value) = value {
+                    section = section.add(value_item(title, value, row.source()));
+                }
+            }
+            section.into()
+        };
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(toggle)
+            .push(content)
+            .apply(page_scrollable)
+            .into()
+    }
+
+    /// The overview's summary of the system, each row with its title and
+    /// its value when known.
+    pub(crate) fn overview_rows(&self, section: Option<&Section>) -> [(OverviewRow, String, Option<String>); 10] {
+        let os_release = self.sections.get::<distribution::Provider>();
+
+        let cpu = self
+            .sections
+            .get::<processor::Provider>()
+            .and_then(|processor| processor.info.model_name.clone());
+
+        let memory = self
+            .sections
+            .get::<memory::Provider>()
+            .and_then(|memory| memory.memory.total())
+            .map(crate::memory::format_kib);
+
+        // Prefer a real GPU over software renderers such as llvmpipe.
+        let graphics = self.sections.get::<graphics::Provider>();
+        let gpu = graphics
+            .and_then(|graphics| graphics.vulkan.as_ref().ok())
+            .and_then(|devices| {
+                devices
+                    .iter()
+                    .find(|device| device.device_type.as_deref() != Some("PHYSICAL_DEVICE_TYPE_CPU"))
+                    .map(|device| device.device_name.clone())
+            })
+            .or_else(|| graphics?.opengl.as_ref().ok()?.renderer.clone());
+
+        let disk = self.sections.get::<filesystems::Provider>().and_then(|filesystems| {
+            let storage = &filesystems.storage;
+            let root = storage.mounts.iter().find(|mount| mount.mount_point == "/");
+            storage
+                .disks
+                .iter()
+                .find(|disk| root.is_some_and(|root| root.source.starts_with(&format!("/dev/{}", disk.name))))
+                .or_else(|| storage.disks.iter().find(|disk| !disk.removable))
+                .map(|disk| match &disk.model {
+                    Some(model) => format!("{model} ({})", storage::format_bytes(disk.size)),
+                    None => format!("{} ({})", disk.name, storage::format_bytes(disk.size)),
+                })
+        });
+
+        let battery = self
+            .sections
+            .get::<power::Provider>()
+            .and_then(|power| power.power.batteries.first())
+            .map(|battery| {
+                let capacity = battery
+                    .capacity
+                    .map(|capacity| format!("{capacity} %"))
+                    .unwrap_or_else(|| fl!("unknown"));
+                match battery.health {
+                    Some(health) => fl!("battery-summary", capacity = capacity, health = format!("{health:.0} %")),
+                    None => capacity,
+                }
+            });
+
+        let (uptime, load) = match section.map(|section| &section.load) {
+            Some(Ok(load)) => {
+                let minutes = load.uptime.as_secs() / 60;
+                let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+                let uptime = if days > 0 {
+                    fl!("uptime-days", days = days, hours = hours, minutes = minutes)
+                } else {
+                    fl!("uptime-hours", hours = hours, minutes = minutes)
+                };
+                let [one, five, fifteen] = load.averages;
+                (Some(uptime), Some(format!("{one:.2}, {five:.2}, {fifteen:.2}")))
+            }
+            _ => (None, None),
+        };
+
+        [
+            (
+                OverviewRow::Distribution,
+                fl!("distribution"),
+                os_release.map(|os_release| os_release.pretty_name.clone()),
+            ),
+            (OverviewRow::Kernel, fl!("kernel"), section.and_then(|section| section.kernel.clone())),
+            (OverviewRow::Uptime, fl!("uptime"), uptime),
+            (
+                OverviewRow::BootTime,
+                fl!("boot-time"),
+                section.and_then(|section| section.boot_time.clone()),
+            ),
+            (OverviewRow::LoadAverage, fl!("load-average"), load),
+            (OverviewRow::Processor, fl!("processor"), cpu),
+            (OverviewRow::Memory, fl!("memory"), memory),
+            (OverviewRow::Graphics, fl!("graphics"), gpu),
+            (OverviewRow::PrimaryDisk, fl!("primary-disk"), disk),
+            (OverviewRow::Battery, fl!("battery"), battery),
+        ]
+    }
+}
+
+/// Converts an os-release `ANSI_COLOR` SGR sequence, such as `0;38;2;60;110;180`
+/// or `1;34`, to a color.
+fn ansi_color(sgr: &str) -> Option<Color> {
+    let codes = sgr.split(';').filter_map(|code| code.parse::<u8>().ok()).collect::<Vec<_>>();
+    if let Some(index) = codes.windows(2).position(|pair| pair == [38, 2]) {
+        if let [r, g, b] = codes.get(index + 2..index + 5)? {
+            return Some(Color::from_rgb8(*r, *g, *b));
+        }
+    }
+
+    let rgb = match codes.iter().rev().find(|code| matches!(code, 30..=37 | 90..=97))? {
+        30 | 90 => (0x55, 0x57, 0x53),
+        31 | 91 => (0xe0, 0x1b, 0x24),
+        32 | 92 => (0x2e, 0xc2, 0x7e),
+        33 | 93 => (0xf5, 0xc2, 0x11),
+        34 | 94 => (0x35, 0x84, 0xe4),
+        35 | 95 => (0x91, 0x41, 0xac),
+        36 | 96 => (0x2a, 0xa1, 0xb3),
+        _ => (0xde, 0xdd, 0xda),
+    };
+    Some(Color::from_rgb8(rgb.0, rgb.1, rgb.2))
+}
+
+/// A row of the overview.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum OverviewRow {
+    Distribution,
+    Kernel,
+    Uptime,
+    BootTime,
+    LoadAverage,
+    Processor,
+    Memory,
+    Graphics,
+    PrimaryDisk,
+    Battery,
+}
+
+impl OverviewRow {
+    /// Every row, in the order the overview lists them.
+    pub(crate) const ALL: [OverviewRow; 10] = [
+        Self::Distribution,
+        Self::Kernel,
+        Self::Uptime,
+        Self::BootTime,
+        Self::LoadAverage,
+        Self::Processor,
+        Self::Memory,
+        Self::Graphics,
+        Self::PrimaryDisk,
+        Self::Battery,
+    ];
+
+    /// The row's stable name in exports.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Self::Distribution => "distribution",
+            Self::Kernel => "kernel",
+            Self::Uptime => "uptime",
+            Self::BootTime => "boot_time",
+            Self::LoadAverage => "load_average",
+            Self::Processor => "processor",
+            Self::Memory => "memory",
+            Self::Graphics => "graphics",
+            Self::PrimaryDisk => "primary_disk",
+            Self::Battery => "battery",
+        }
+    }
+
+    /// Where the row's value is read from.
+    pub(crate) fn source(self) -> &'static str {
+        match self {
+            Self::Distribution => crate::distribution::source(),
+            Self::Kernel => "/proc/sys/kernel/osrelease",
+            Self::Uptime => "/proc/uptime",
+            Self::BootTime => "uptime --since",
+            Self::LoadAverage => "/proc/loadavg",
+            Self::Processor => "/proc/cpuinfo: model name",
+            Self::Memory => "/proc/meminfo: MemTotal",
+            Self::Graphics => "vulkaninfo --summary, glxinfo -B",
+            Self::PrimaryDisk => "/sys/block, /proc/self/mounts",
+            Self::Battery => "/sys/class/power_supply",
+        }
+    }
+}
@@ -0,0 +1,337 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The PCI page and the detail drawer of a PCI device.
+
+use std::collections::BTreeMap;
+
+use cosmic::app::Task;
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, icon, list_column, row, settings};
+use cosmic::{theme, Apply, Element};
+use log::error;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{self, missing_tool, page_scrollable, value_item, AppModel, ContextPage};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{driver, fl, host, iommu, pci, tools};
+
+/// The data of the PCI page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub devices: Vec<pci::Device>,
+    pub iommu: iommu::Iommu,
+}
+
+/// How the PCI page lists the devices, and the one shown in the detail drawer.
+#[derive(Default)]
+pub struct State {
+    /// Whether the devices are grouped by IOMMU group.
+    pub by_iommu_group: bool,
+    pub selected: Option<String>,
+    /// `lspci -vvv` for the selected device, loaded when it is opened.
+    pub details: Option<Result<pci::Details, String>>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::PCIs;
+
+    fn id(&self) -> &'static str {
+        "pci"
+    }
+
+    fn name(&self) -> String {
+        fl!("pci-devices")
+    }
+
+    fn icon(&self) -> &'static str {
+        "drive-harddisk-usb-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["lspci"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("slot", "pci-slot"),
+            ("driver", "kernel-driver"),
+            ("modules", "kernel-modules"),
+            ("interrupt", "pci-interrupt"),
+            ("regions", "pci-regions"),
+            ("capabilities", "pci-capabilities"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/bus/pci/devices"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        (path == "iommu").then_some("/sys/kernel/iommu_groups")
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            devices: pci::devices(),
+            iommu: iommu::collect(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, app::Message> {
+        app.pcis(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-pci-source"), fl!("help-pci-fields"), fl!("help-pci-problems"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Opens the detail drawer of the device in this slot.
+    Select(String),
+    DetailsRead(String, Result<pci::Details, String>),
+    ToggleIommuGroups(bool),
+}
+
+impl AppModel {
+    pub(crate) fn update_pci(&mut self, message: Message) -> Task<app::Message> {
+        match message {
+            Message::Select(slot) => {
+                self.pci.selected = Some(slot.clone());
+                self.pci.details = None;
+                self.show_context_page(ContextPage::PciDevice);
+                if self.shows_snapshot() || !tools::is_available("lspci") {
+                    return Task::none();
+                }
+                return Task::perform(
+                    async move {
+                        let query = slot.clone();
+                        let result = tokio::task::spawn_blocking(move || pci::details(&query))
+                            .await
+                            .map_err(|e| e.to_string())
+                            .and_then(|result| result);
+                        (slot, result)
+                    },
+                    |(slot, result)| cosmic::app::Message::App(app::Message::Pci(Message::DetailsRead(slot, result))),
+                );
+            }
+            Message::DetailsRead(slot, result) => {
+                // Ignore results for a device that is no longer selected.
+                if self.pci.selected.as_ref() == Some(&slot) {
+                    if let Err(e) = &result {
+                        error!("lspci -vvv failed for {}: {}", slot, e);
+                    }
+                    self.pci.details = Some(result);
+                }
+            }
+            Message::ToggleIommuGroups(enabled) => self.pci.by_iommu_group = enabled,
+        }
+        Task::none()
+    }
+
+    /// The detail drawer for the selected PCI device.
+    pub fn pci_device(&self) -> Element<app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Some(slot) = &self.pci.selected else {
+            return widget::text::body(fl!("no-device-selected")).into();
+        };
+
+        let path = driver::pci_sysfs_path(slot);
+        let affinity = driver::affinity(&path);
+        let address = path.file_name().unwrap_or_default().to_string_lossy();
+        let sysfs = |file: &str| format!("/sys/bus/pci/devices/{address}/{file}");
+
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_m);
+        let mut device = settings::section().add(value_item(fl!("pci-slot"), slot.clone(), sysfs("")));
+        let devices = self.sections.get::<Provider>().map_or(&[][..], |section| &section.devices);
+        if let Some(pci) = devices.iter().find(|device| &device.slot == slot) {
+            device = device.add(value_item(
+                fl!("pci-ids"),
+                pci.ids(),
+                sysfs("vendor, device, subsystem_vendor, subsystem_device"),
+            ));
+        }
+        device = device.add(value_item(
+            fl!("numa-node"),
+            affinity.numa_node.map_or_else(|| fl!("none"), |node| node.to_string()),
+            sysfs("numa_node"),
+        ));
+        if let Some(local_cpus) = affinity.local_cpus {
+            device = device.add(value_item(fl!("local-cpus"), local_cpus, sysfs("local_cpulist")));
+        }
+
+        match driver::for_device(&path) {
+            Some(driver) => {
+                device = device.add(value_item(fl!("kernel-driver"), driver.name.clone(), sysfs("driver")));
+                device = device.add(value_item(
+                    fl!("kernel-module"),
+                    driver.module.clone().unwrap_or_else(|| fl!("built-in")),
+                    sysfs("driver/module"),
+                ));
+                column = column.push(device);
+
+                if !driver.parameters.is_empty() {
+                    let mut parameters = settings::section().title(fl!("module-parameters"));
+                    let module = driver.module.as_deref().unwrap_or(&driver.name);
+                    for (name, value) in driver.parameters {
+                        let source = format!("/sys/module/{module}/parameters/{name}");
+                        parameters = parameters.add(value_item(name, value, source));
+                    }
+                    column = column.push(parameters);
+                }
+            }
+            None => {
+                device = device.add(value_item(fl!("kernel-driver"), fl!("none"), sysfs("driver")));
+                column = column.push(device);
+            }
+        }
+
+        match &self.pci.details {
+            None if !tools::is_available("lspci") => {
+                column = column.push(missing_tool(fl!("device-details"), "lspci"));
+            }
+            Some(Ok(details)) => column = column.push(self.pci_verbose(slot, details)),
+            Some(Err(e)) => {
+                column = column.push(widget::text::body(fl!("error-occurred-with-msg", error = e.as_str())));
+            }
+            None => column = column.push(widget::text::body(fl!("loading"))),
+        }
+
+        column.into()
+    }
+
+    /// The parsed `lspci -vvv` sections of the PCI device drawer.
+    fn pci_verbose<'a>(&'a self, slot: &str, details: &'a pci::Details) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let mut column = widget::column::with_capacity(5)
+            .spacing(spacing.space_m)
+            .push(widget::text::body(details.description.as_str()));
+
+        let lspci = |key: &str| format!("lspci -vvv -s {slot}: {key}");
+        let mut general = settings::section().title(fl!("pci-general"));
+        for (key, value) in &details.fields {
+            general = general.add(value_item(key.as_str(), value.as_str(), lspci(key)));
+        }
+        if let Some(interrupt) = &details.interrupt {
+            general = general.add(value_item(fl!("pci-interrupt"), interrupt.as_str(), lspci("Interrupt")));
+        }
+        if !details.modules.is_empty() {
+            general = general.add(value_item(
+                fl!("kernel-modules"),
+                details.modules.join(", "),
+                lspci("Kernel modules"),
+            ));
+        }
+        column = column.push(general);
+
+        if !details.regions.is_empty() {
+            let mut regions = settings::section().title(fl!("pci-regions"));
+            for region in &details.regions {
+                regions = regions.add(widget::text::body(region.as_str()));
+            }
+            column = column.push(regions);
+        }
+
+        let mut capabilities = widget::column::with_capacity(details.capabilities.len() + 2)
+            .spacing(spacing.space_xxs)
+            .push(widget::text::heading(fl!("pci-capabilities")));
+        if details.capabilities_denied {
+            capabilities = capabilities.push(widget::text::caption(fl!("pci-capabilities-denied")));
+        }
+        for capability in &details.capabilities {
+            let mut registers = widget::column::with_capacity(capability.details.len());
+            for line in &capability.details {
+                registers = registers.push(widget::text::monotext(line.as_str()));
+            }
+            capabilities = capabilities.push(self.collapsible(
+                Page::PCIs,
+                format!("{slot}/{}", capability.offset),
+                format!("[{}] {}", capability.offset, capability.name),
+                registers,
+            ));
+        }
+        column.push(capabilities).into()
+    }
+
+    /// The PCI page, listing devices from sysfs.
+    pub fn pcis<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if section.devices.is_empty() {
+            return widget::text::title1(fl!("no-pci-devices")).into();
+        }
+
+        let devices = section.devices.iter().map(|device| {
+            let mut description = match &device.driver {
+                Some(driver) => fl!("driver-in-use", driver = driver.as_str()),
+                None => fl!("no-driver-in-use"),
+            };
+            if !device.modules.is_empty() {
+                description.push_str(" · ");
+                description.push_str(&fl!("available-modules", modules = device.modules.join(", ")));
+            }
+            let item: Element<app::Message> = settings::item::builder(device.name())
+                .description(description)
+                .control(
+                    row::with_capacity(2)
+                        .push(widget::text::body(format!("{} {}", device.slot, device.class_name())))
+                        .push(
+                            widget::button::icon(icon::from_name("go-next-symbolic"))
+                                .on_press(app::Message::Pci(Message::Select(device.slot.clone()))),
+                        )
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs),
+                )
+                .into();
+            (device.slot.as_str(), item)
+        });
+
+        let iommu: Element<app::Message> = if section.iommu.enabled {
+            settings::item::builder(fl!("group-by-iommu"))
+                .description(fl!("iommu-enabled", groups = section.iommu.groups.len()))
+                .toggler(self.pci.by_iommu_group, |enabled| {
+                    app::Message::Pci(Message::ToggleIommuGroups(enabled))
+                })
+                .into()
+        } else {
+            value_item(fl!("iommu"), fl!("iommu-disabled"), "/sys/kernel/iommu_groups").into()
+        };
+        let mut column = widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(settings::section().add(iommu));
+
+        if section.iommu.enabled && self.pci.by_iommu_group {
+            let mut groups = BTreeMap::<Option<u32>, Vec<Element<app::Message>>>::new();
+            for (slot, item) in devices {
+                groups.entry(section.iommu.group_of(slot)).or_default().push(item);
+            }
+            for (group, items) in groups {
+                let title = match group {
+                    Some(group) => fl!("iommu-group", group = group),
+                    None => fl!("iommu-no-group"),
+                };
+                let mut section = settings::section().title(title);
+                for item in items {
+                    section = section.add(item);
+                }
+                column = column.push(section);
+            }
+        } else {
+            let mut section = list_column();
+            for (_, item) in devices {
+                section = section.add(item);
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable)
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Power page, from sysfs power supplies, vendor battery care
+//! settings and power-profiles-daemon.
+
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{error_explanation, install_hint, page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{export, fl, host, power, power_profiles};
+
+const CHARGE_LIMITS: &[u8] = &[50, 60, 70, 80, 90, 100];
+const CHARGE_LIMIT_LABELS: &[&str] = &["50 %", "60 %", "70 %", "80 %", "90 %", "100 %"];
+
+/// The data of the Power page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub power: power::Power,
+    pub profiles: Result<power_profiles::Profiles, host::Error>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Power;
+
+    fn id(&self) -> &'static str {
+        "power"
+    }
+
+    fn name(&self) -> String {
+        fl!("power")
+    }
+
+    fn icon(&self) -> &'static str {
+        "battery-good-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("health", "battery-health"), ("capacity", "charge"), ("profiles", "power-profile")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/power_supply"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "power.conservation_mode" => Some("/sys/bus/platform/drivers/ideapad_acpi"),
+            "profiles" => Some("power-profiles-daemon"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            power: power::collect(),
+            profiles: power_profiles::collect().map_err(|e| host::bus_error("power-profiles-daemon", &e)),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.power(section)
+    }
+
+    fn export(&self, _app: &AppModel, section: &Section) -> Value {
+        json!({
+            "power": section.power,
+            "profiles": export::result(&section.profiles),
+        })
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-power-source"), fl!("help-power-fields"), fl!("help-power-problems"))
+    }
+}
+
+impl AppModel {
+    /// The power page, listing batteries and vendor battery care settings.
+    pub fn power<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let power = &section.power;
+
+        let mut column = widget::column::with_capacity(power.batteries.len() + 2)
+            .spacing(spacing.space_m);
+
+        if let Some(ac_online) = power.ac_online {
+            column = column.push(settings::section().add(value_item(
+                fl!("ac-adapter"),
+                if ac_online {
+                    fl!("connected")
+                } else {
+                    fl!("disconnected")
+                },
+                "/sys/class/power_supply/*/online",
+            )));
+        }
+
+        if let Ok(profiles) = &section.profiles {
+            let buttons = profiles.available.iter().fold(
+                row::with_capacity(profiles.available.len()).spacing(spacing.space_xxs),
+                |row, profile| {
+                    let label = match profile.as_str() {
+                        "power-saver" => fl!("profile-power-saver"),
+                        "balanced" => fl!("profile-balanced"),
+                        "performance" => fl!("profile-performance"),
+                        other => other.to_string(),
+                    };
+                    let button = if *profile == profiles.active {
+                        widget::button::suggested(label)
+                    } else {
+                        widget::button::standard(label).on_press(Message::SetPowerProfile(profile.clone()))
+                    };
+                    row.push(button)
+                },
+            );
+            let mut item = settings::item::builder(fl!("power-profile"));
+            if let Some(reason) = &profiles.performance_degraded {
+                item = item.description(fl!("performance-degraded", reason = reason.as_str()));
+            }
+            column = column.push(settings::section().add(item.control(buttons)));
+        } else if let Err(e) = &section.profiles {
+            let mut item = settings::item::builder(fl!("power-profile"));
+            if let Some(hint) = install_hint(e) {
+                item = item.description(hint);
+            }
+            column = column.push(settings::section().add(item.control(widget::text::body(error_explanation(e)))));
+        }
+
+        if let Some(mode) = &power.conservation_mode {
+            column = column.push(
+                settings::section().title(fl!("battery-care")).add(
+                    settings::item::builder(fl!("conservation-mode"))
+                        .description(fl!("conservation-mode-description"))
+                        .toggler(mode.enabled, Message::SetConservationMode),
+                ),
+            );
+        }
+
+        for battery in &power.batteries {
+            let mut section = settings::section().title(battery.name.clone());
+            let sysfs = |file: &str| format!("/sys/class/power_supply/{}/{file}", battery.name);
+            let optional = [
+                (fl!("manufacturer"), battery.manufacturer.clone(), sysfs("manufacturer")),
+                (fl!("model"), battery.model.clone(), sysfs("model_name")),
+                (fl!("technology"), battery.technology.clone(), sysfs("technology")),
+                (fl!("status"), battery.status.clone(), sysfs("status")),
+                (fl!("charge"), battery.capacity.map(|capacity| format!("{capacity} %")), sysfs("capacity")),
+                (fl!("cycle-count"), battery.cycle_count.map(|count| count.to_string()), sysfs("cycle_count")),
+                (
+                    fl!("battery-health"),
+                    battery.health.map(|health| format!("{health:.1} %")),
+                    sysfs("energy_full, energy_full_design"),
+                ),
+            ];
+            for (title, value, source) in optional {
+                if let Some(value) = value {
+                    section = section.add(value_item(title, value, source));
+                }
+            }
+            if let Some(limit) = battery.charge_limit {
+                let name = battery.name.clone();
+                section = section.add(settings::item(
+                    fl!("charge-limit"),
+                    widget::dropdown(
+                        CHARGE_LIMIT_LABELS,
+                        CHARGE_LIMITS.iter().position(|value| *value == limit),
+                        move |index| Message::SetChargeLimit(name.clone(), CHARGE_LIMITS[index]),
+                    ),
+                ));
+            }
+            column = column.push(section);
+        }
+
+        if power.batteries.is_empty() {
+            column = column.push(widget::text::body(fl!("no-batteries")));
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
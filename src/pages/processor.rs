@@ -0,0 +1,476 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Processor page, from /proc/cpuinfo and the CPU topology in sysfs,
+//! split into tabs so that the flags and vulnerabilities do not bury the
+//! summary.
+
+use std::collections::HashSet;
+
+use cosmic::app::Task;
+use cosmic::iced::widget::scrollable::{self, AbsoluteOffset};
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, list_column, row, segmented_button, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{self, page_scrollable, table_header, table_row, value_item, AppModel, Severity, PAGE_SCROLLABLE};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{cpu, fl, host};
+
+/// A tab of the Processor page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tab {
+    Overview,
+    Topology,
+    Caches,
+    Flags,
+    Vulnerabilities,
+}
+
+impl Tab {
+    const ALL: &'static [Tab] = &[
+        Self::Overview,
+        Self::Topology,
+        Self::Caches,
+        Self::Flags,
+        Self::Vulnerabilities,
+    ];
+
+    fn title(self) -> String {
+        match self {
+            Self::Overview => fl!("processor-overview"),
+            Self::Topology => fl!("processor-topology"),
+            Self::Caches => fl!("cpu-caches"),
+            Self::Flags => fl!("cpu-flags"),
+            Self::Vulnerabilities => fl!("cpu-vulnerabilities"),
+        }
+    }
+}
+
+/// The data of the Processor page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub info: cpu::Info,
+    pub caches: Vec<cpu::Cache>,
+    pub state: cpu::State,
+    pub vulnerabilities: Vec<cpu::Vulnerability>,
+    pub logical_cpus: Vec<cpu::LogicalCpu>,
+}
+
+/// Which of the Processor page's tabs is shown, and its flag filter.
+pub struct State {
+    /// The text the CPU flags are filtered by.
+    pub flag_search: String,
+    pub tabs: segmented_button::SingleSelectModel,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            flag_search: String::new(),
+            tabs: tabs(),
+        }
+    }
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Processor;
+
+    fn id(&self) -> &'static str {
+        "processor"
+    }
+
+    fn name(&self) -> String {
+        fl!("processor")
+    }
+
+    fn icon(&self) -> &'static str {
+        "system-run-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("info", "processor-overview"),
+            ("caches", "cpu-caches"),
+            ("state", "processor-state"),
+            ("vulnerabilities", "cpu-vulnerabilities"),
+            ("flags", "cpu-flags"),
+            ("vendor", "cpu-vendor"),
+            ("level", "cache-level"),
+            ("size_kib", "cache-size"),
+            ("ways", "cache-ways"),
+            ("line_size", "cache-line"),
+            ("shared_by", "cache-shared-by"),
+            ("id", "cpu-id"),
+            ("core", "cpu-core"),
+            ("socket", "cpu-socket"),
+            ("node", "cpu-node"),
+            ("min_mhz", "cpu-min-mhz"),
+            ("max_mhz", "cpu-max-mhz"),
+            ("smt_active", "smt"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/proc/cpuinfo"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "caches" => Some("/sys/devices/system/cpu/cpu*/cache"),
+            "state" => Some("/sys/devices/system/cpu"),
+            "vulnerabilities" => Some("/sys/devices/system/cpu/vulnerabilities"),
+            "logical_cpus" => Some("/sys/devices/system/cpu/cpu*/topology"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            info: cpu::info()?,
+            caches: cpu::caches(),
+            state: cpu::state(),
+            vulnerabilities: cpu::vulnerabilities(),
+            logical_cpus: cpu::logical_cpus(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, app::Message> {
+        app.processor(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-processor-source"), fl!("help-processor-fields"), fl!("help-processor-problems"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SearchFlags(String),
+    SelectTab(segmented_button::Entity),
+}
+
+/// The tabs of the Processor page, starting on the overview.
+fn tabs() -> segmented_button::SingleSelectModel {
+    let mut model = segmented_button::SingleSelectModel::default();
+    for &tab in Tab::ALL {
+        let entry = model.insert().text(tab.title()).data(tab);
+        if tab == Tab::Overview {
+            entry.activate();
+        }
+    }
+    model
+}
+
+/// Where each value was read from, shown in its tooltip.
+fn sysfs(path: &str) -> String {
+    format!("{}/{path}", cpu::CPU_DIR)
+}
+
+impl AppModel {
+    pub(crate) fn update_processor(&mut self, message: Message) -> Task<app::Message> {
+        match message {
+            Message::SearchFlags(query) => self.processor.flag_search = query,
+            Message::SelectTab(entity) => {
+                // Each tab starts at its top, rather than where the last one was scrolled to.
+                self.processor.tabs.activate(entity);
+                self.scroll_offsets.remove(&Page::Processor);
+                return scrollable::scroll_to(PAGE_SCROLLABLE.clone(), AbsoluteOffset::default());
+            }
+        }
+        Task::none()
+    }
+
+    /// The processor page, built from /proc/cpuinfo and /sys/devices/system/cpu.
+    pub fn processor<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let tab = self.processor.tabs.active_data::<Tab>().copied().unwrap_or(Tab::Overview);
+        let content = match tab {
+            Tab::Overview => self.processor_overview(section),
+            Tab::Topology => self.processor_topology(section),
+            Tab::Caches => self.processor_caches(section),
+            Tab::Flags => self.processor_flags(section),
+            Tab::Vulnerabilities => self.processor_vulnerabilities(section),
+        };
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(
+                widget::segmented_control::horizontal(&self.processor.tabs)
+                    .on_activate(|entity| app::Message::Processor(Message::SelectTab(entity))),
+            )
+            .push(content)
+            .apply(page_scrollable)
+    }
+
+    /// The model, frequencies and state of the processor.
+    fn processor_overview<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let info = &section.info;
+        let cpus = &section.logical_cpus;
+        let cpu_state = &section.state;
+        let online = cpus.iter().filter(|cpu| cpu.online).count();
+        let min_mhz = cpus.iter().filter_map(|cpu| cpu.min_mhz).min();
+        let max_mhz = cpus.iter().filter_map(|cpu| cpu.max_mhz).max();
+
+        // The field names of /proc/cpuinfo differ between architectures.
+        let cpuinfo = || "/proc/cpuinfo".to_string();
+
+        let mut rows = vec![
+            (fl!("cpu-model-name"), info.model_name.clone(), cpuinfo()),
+            (fl!("cpu-vendor"), info.vendor.clone(), cpuinfo()),
+            (fl!("architecture"), Some(std::env::consts::ARCH.to_string()), fl!("source-build-architecture")),
+        ];
+        if let (Some(family), Some(model)) = (&info.family, &info.model) {
+            rows.push((
+                fl!("cpu-family-model"),
+                Some(fl!(
+                    "cpu-family-model-value",
+                    family = family.as_str(),
+                    model = model.as_str(),
+                    stepping = info.stepping.clone().unwrap_or_else(|| fl!("unknown"))
+                )),
+                cpuinfo(),
+            ));
+        }
+        rows.push((
+            fl!("logical-cpus"),
+            Some(fl!("cpus-online", total = cpus.len(), online = online)),
+            sysfs("cpu*/online"),
+        ));
+        if let (Some(min), Some(max)) = (min_mhz, max_mhz) {
+            rows.push((
+                fl!("frequency-range"),
+                Some(fl!("mhz-range", min = min, max = max)),
+                sysfs("cpu*/cpufreq/cpuinfo_min_freq, cpuinfo_max_freq"),
+            ));
+        }
+        rows.push((
+            fl!("virtualization"),
+            Some(info.virtualization().map_or_else(|| fl!("not-available"), str::to_string)),
+            cpuinfo(),
+        ));
+        rows.push((fl!("bogomips"), info.bogomips.clone(), cpuinfo()));
+
+        let mut section = list_column();
+        for (title, value, source) in rows {
+            if let Some(value) = value {
+                section = section.add(value_item(title, value, source));
+            }
+        }
+        let spacing = theme::active().cosmic().spacing;
+
+        let unknown = || fl!("unknown");
+        let on_off = |value: bool| if value { fl!("enabled") } else { fl!("disabled") };
+        let smt = match cpu_state.smt_control.as_deref() {
+            Some("notsupported" | "notimplemented") => fl!("not-available"),
+            Some("forceoff") => fl!("smt-force-off"),
+            Some(_) => cpu_state.smt_active.map_or_else(unknown, on_off),
+            None => unknown(),
+        };
+        let state = settings::section()
+            .title(fl!("processor-state"))
+            .add(value_item(
+                fl!("microcode"),
+                cpu_state.microcode.clone().unwrap_or_else(unknown),
+                format!("{}/cpu0/microcode/version, /proc/cpuinfo", cpu::CPU_DIR),
+            ))
+            .add(value_item(
+                fl!("smt"),
+                smt,
+                format!("{0}/smt/control, {0}/smt/active", cpu::CPU_DIR),
+            ))
+            .add(value_item(
+                fl!("scaling-driver"),
+                cpu_state.scaling_driver.clone().unwrap_or_else(unknown),
+                format!("{}/cpu0/cpufreq/scaling_driver", cpu::CPU_DIR),
+            ))
+            .add(value_item(
+                fl!("governor"),
+                cpu_state.governor.clone().unwrap_or_else(unknown),
+                format!("{}/cpu0/cpufreq/scaling_governor", cpu::CPU_DIR),
+            ))
+            .add(value_item(
+                fl!("boost"),
+                cpu_state.boost.map_or_else(unknown, on_off),
+                format!("{0}/cpufreq/boost, {0}/intel_pstate/no_turbo", cpu::CPU_DIR),
+            ));
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(section)
+            .push(state)
+            .into()
+    }
+
+    /// The sockets, cores and threads, and every logical CPU.
+    fn processor_topology<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let cpus = &section.logical_cpus;
+        if cpus.is_empty() {
+            return widget::text::body(fl!("not-available")).into();
+        }
+        let spacing = theme::active().cosmic().spacing;
+        let unknown = || fl!("unknown");
+
+        let sockets = cpus.iter().filter_map(|cpu| cpu.socket).collect::<HashSet<_>>().len();
+        let cores = cpus
+            .iter()
+            .filter_map(|cpu| Some((cpu.socket?, cpu.core?)))
+            .collect::<HashSet<_>>()
+            .len();
+        let summary = (sockets > 0 && cores > 0).then(|| {
+            list_column()
+                .add(value_item(fl!("sockets"), sockets.to_string(), sysfs("cpu*/topology")))
+                .add(value_item(
+                    fl!("cores-per-socket"),
+                    (cores / sockets).to_string(),
+                    sysfs("cpu*/topology"),
+                ))
+                .add(value_item(
+                    fl!("threads-per-core"),
+                    (cpus.len() / cores).to_string(),
+                    sysfs("cpu*/topology"),
+                ))
+        });
+
+        let header = table_header([
+            fl!("cpu-id"),
+            fl!("cpu-core"),
+            fl!("cpu-socket"),
+            fl!("cpu-node"),
+            fl!("cpu-min-mhz"),
+            fl!("cpu-max-mhz"),
+        ]);
+        let mut table = settings::section().title(fl!("logical-cpus")).add(header);
+        for cpu in cpus {
+            let id = if cpu.online {
+                cpu.id.to_string()
+            } else {
+                format!("{} ({})", cpu.id, fl!("offline"))
+            };
+            table = table.add(table_row([
+                id,
+                cpu.core.map_or_else(unknown, |core| core.to_string()),
+                cpu.socket.map_or_else(unknown, |socket| socket.to_string()),
+                cpu.node.map_or_else(unknown, |node| node.to_string()),
+                cpu.min_mhz.map_or_else(unknown, |mhz| mhz.to_string()),
+                cpu.max_mhz.map_or_else(unknown, |mhz| mhz.to_string()),
+            ]));
+        }
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push_maybe(summary)
+            .push(table)
+            .into()
+    }
+
+    /// Every cache level, merged across the CPUs.
+    fn processor_caches<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        if section.caches.is_empty() {
+            return widget::text::body(fl!("not-available")).into();
+        }
+
+        let header = table_header([
+            fl!("cache-level"),
+            fl!("cache-size"),
+            fl!("cache-ways"),
+            fl!("cache-line"),
+            fl!("cache-shared-by"),
+        ]);
+        let mut caches = settings::section().add(header);
+        for cache in &section.caches {
+            let size = match cache.size() {
+                Some(size) if cache.instances > 1 => format!("{size} × {}", cache.instances),
+                Some(size) => size,
+                None => fl!("unknown"),
+            };
+            let ways = cache.ways.map_or_else(|| fl!("unknown"), |ways| ways.to_string());
+            let line = cache.line_size.map_or_else(|| fl!("unknown"), |size| format!("{size} B"));
+            let shared_by = fl!("cache-cpus", count = cache.shared_by);
+            caches = caches.add(table_row([cache.name(), size, ways, line, shared_by]));
+        }
+        caches.into()
+    }
+
+    /// The feature flags, searchable by name and description.
+    fn processor_flags<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let info = &section.info;
+        if info.flags.is_empty() {
+            return widget::text::body(fl!("not-available")).into();
+        }
+        let spacing = theme::active().cosmic().spacing;
+
+        let query = self.processor.flag_search.to_lowercase();
+        let chips = info
+            .flags
+            .iter()
+            .map(|flag| (flag.as_str(), cpu::flag_description(flag)))
+            .filter(|(flag, description)| {
+                flag.contains(&query) || description.is_some_and(|description| description.to_lowercase().contains(&query))
+            })
+            .map(|(flag, description)| {
+                let chip = widget::container(widget::text::caption(flag))
+                    .padding([spacing.space_xxxs, spacing.space_xs])
+                    .class(theme::Container::Card);
+                match description {
+                    Some(description) => {
+                        widget::tooltip(chip, widget::text::body(description), widget::tooltip::Position::Top).into()
+                    }
+                    None => chip.into(),
+                }
+            })
+            .collect::<Vec<Element<app::Message>>>();
+
+        settings::section()
+            .add(
+                widget::search_input(fl!("search-flags"), &self.processor.flag_search)
+                    .on_input(|query| app::Message::Processor(Message::SearchFlags(query)))
+                    .on_clear(app::Message::Processor(Message::SearchFlags(String::new()))),
+            )
+            .add(
+                widget::flex_row(chips)
+                    .row_spacing(spacing.space_xxs)
+                    .column_spacing(spacing.space_xxs),
+            )
+            .into()
+    }
+
+    /// Whether the processor is affected by each known hardware vulnerability.
+    fn processor_vulnerabilities<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        if section.vulnerabilities.is_empty() {
+            return widget::text::body(fl!("not-available")).into();
+        }
+        let spacing = theme::active().cosmic().spacing;
+
+        let mut vulnerabilities = settings::section();
+        for vulnerability in &section.vulnerabilities {
+            let (label, severity) = match vulnerability.exposure {
+                cpu::Exposure::NotAffected => (fl!("not-affected"), Severity::Good),
+                cpu::Exposure::Mitigated => (fl!("mitigated"), Severity::Info),
+                cpu::Exposure::Vulnerable => (fl!("vulnerable"), Severity::Critical),
+                cpu::Exposure::Unknown => (fl!("unknown"), Severity::Warning),
+            };
+            vulnerabilities = vulnerabilities.add(
+                settings::item::builder(vulnerability.display_name())
+                    .description(vulnerability.status.clone())
+                    .control(
+                        row::with_capacity(2)
+                            .push(widget::text::body(label))
+                            .push(self.status_indicator(severity))
+                            .align_y(Alignment::Center)
+                            .spacing(spacing.space_xxs),
+                    ),
+            );
+        }
+        vulnerabilities.into()
+    }
+}
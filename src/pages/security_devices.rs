@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Security Devices page, listing fingerprint readers from fprintd and
+//! smart card readers from pcscd.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{error_explanation, install_hint, page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{export, fingerprint, fl, host, smartcard};
+
+/// The data of the Security Devices page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub fingerprint_readers: Result<Vec<fingerprint::Reader>, host::Error>,
+    pub smartcard_readers: Vec<smartcard::Reader>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::SecurityDevices;
+
+    fn id(&self) -> &'static str {
+        "security-devices"
+    }
+
+    fn name(&self) -> String {
+        fl!("security-devices")
+    }
+
+    fn icon(&self) -> &'static str {
+        "auth-fingerprint-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["opensc-tool"]
+    }
+
+    fn origin(&self) -> &'static str {
+        "fprintd: GetDevices"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        (path == "smartcard_readers").then_some("/sys/bus/usb/devices, opensc-tool --list-readers")
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            fingerprint_readers: fingerprint::collect()
+                .map_err(|e| host::bus_error("fprintd", &e))
+                .inspect_err(|e| warn!("fprintd query failed: {}", e)),
+            smartcard_readers: smartcard::collect(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.security_devices(section)
+    }
+
+    fn export(&self, _app: &AppModel, section: &Section) -> Value {
+        json!({
+            "fingerprint_readers": export::result(&section.fingerprint_readers),
+            "smartcard_readers": section.smartcard_readers,
+        })
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-security-devices-source"),
+            fl!("help-security-devices-fields"),
+            fl!("help-security-devices-problems"),
+        )
+    }
+}
+
+impl AppModel {
+    /// The security devices page, listing authentication peripherals.
+    pub fn security_devices<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let (fingerprint_readers, smartcard_readers) = (&section.fingerprint_readers, &section.smartcard_readers);
+
+        let mut fingerprint = settings::section().title(fl!("fingerprint-readers"));
+        match fingerprint_readers {
+            Ok(readers) if readers.is_empty() => {
+                fingerprint = fingerprint.add(value_item(
+                    fl!("fingerprint-reader"),
+                    fl!("none"),
+                    "fprintd: GetDevices",
+                ));
+            }
+            Ok(readers) => {
+                for reader in readers {
+                    let mut description = fl!("enrolled-fingers", count = reader.enrolled_fingers);
+                    if let Some(scan_type) = &reader.scan_type {
+                        description = format!("{description} · {}", fl!("scan-type", kind = scan_type.as_str()));
+                    }
+                    fingerprint = fingerprint.add(
+                        settings::item::builder(fl!("fingerprint-reader"))
+                            .description(description)
+                            .control(widget::text::body(reader.driver.clone())),
+                    );
+                }
+            }
+            Err(e) => {
+                let mut item = settings::item::builder(fl!("fingerprint-reader"));
+                if let Some(hint) = install_hint(e) {
+                    item = item.description(hint);
+                }
+                fingerprint = fingerprint.add(item.control(widget::text::body(error_explanation(e))));
+            }
+        }
+
+        let mut smartcard = settings::section().title(fl!("smartcard-readers"));
+        if smartcard_readers.is_empty() {
+            smartcard = smartcard.add(value_item(
+                fl!("smartcard-reader"),
+                fl!("none"),
+                "/sys/bus/usb/devices: bInterfaceClass 0b",
+            ));
+        }
+        for reader in smartcard_readers {
+            let card = match reader.card_present {
+                Some(true) => fl!("card-present"),
+                Some(false) => fl!("card-absent"),
+                None => fl!("unknown"),
+            };
+            smartcard = smartcard.add(
+                settings::item::builder(reader.name.clone())
+                    .description(fl!("card"))
+                    .control(widget::text::body(card)),
+            );
+        }
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(fingerprint)
+            .push(smartcard)
+            .apply(page_scrollable)
+            .into()
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Desktop & Session page, from the session's environment and processes.
+
+use cosmic::widget::settings;
+use cosmic::{Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, session};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = session::Session;
+
+    const PAGE: Page = Page::Session;
+
+    fn id(&self) -> &'static str {
+        "session"
+    }
+
+    fn name(&self) -> String {
+        fl!("desktop-session")
+    }
+
+    fn icon(&self) -> &'static str {
+        "user-desktop-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn origin(&self) -> &'static str {
+        "/proc/*/comm"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "desktop" => Some("$XDG_CURRENT_DESKTOP, $DESKTOP_SESSION"),
+            "session_type" => Some("$XDG_SESSION_TYPE"),
+            "session_id" => Some("$XDG_SESSION_ID"),
+            "xwayland" => Some("/proc/*/comm: Xwayland"),
+            "wayland_display" => Some("$WAYLAND_DISPLAY"),
+            "x_display" => Some("$DISPLAY"),
+            "locale" => Some("$LC_ALL, $LANG"),
+            "runtime_dir" => Some("$XDG_RUNTIME_DIR"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<session::Session, host::Error> {
+        Ok(session::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a session::Session) -> Element<'a, Message> {
+        app.session(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-session-source"), fl!("help-session-fields"), fl!("help-session-problems"))
+    }
+}
+
+impl AppModel {
+    /// The desktop and session page, answering "Wayland or X11?" among others.
+    pub fn session<'a>(&'a self, session: &'a session::Session) -> Element<'a, Message> {
+        let session_type = session.session_type.as_deref().map(|session_type| match session_type {
+            "wayland" => fl!("session-wayland"),
+            "x11" => fl!("session-x11"),
+            other => other.to_string(),
+        });
+        let compositor = session.compositor.clone().map(|compositor| match &session.compositor_version {
+            Some(version) => format!("{compositor} ({version})"),
+            None => compositor,
+        });
+
+        let rows = [
+            (fl!("desktop-environment"), session.desktop.clone(), "$XDG_CURRENT_DESKTOP, $DESKTOP_SESSION"),
+            (fl!("session-type"), session_type, "$XDG_SESSION_TYPE"),
+            (fl!("compositor"), compositor, "/proc/*/comm"),
+            (
+                fl!("xwayland"),
+                (session.session_type.as_deref() == Some("wayland"))
+                    .then(|| if session.xwayland { fl!("active") } else { fl!("inactive") }),
+                "/proc/*/comm: Xwayland",
+            ),
+            (fl!("wayland-display"), session.wayland_display.clone(), "$WAYLAND_DISPLAY"),
+            (fl!("x-display"), session.x_display.clone(), "$DISPLAY"),
+            (fl!("session-id"), session.session_id.clone(), "$XDG_SESSION_ID"),
+            (fl!("locale"), session.locale.clone(), "$LC_ALL, $LANG"),
+            (fl!("runtime-dir"), session.runtime_dir.clone(), "$XDG_RUNTIME_DIR"),
+        ];
+
+        let mut section = settings::section().title(fl!("desktop-session"));
+        for (title, value, source) in rows {
+            section = section.add(value_item(title, value.unwrap_or_else(|| fl!("not-provided")), source));
+        }
+
+        section.apply(page_scrollable).into()
+    }
+}
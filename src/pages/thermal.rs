@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Thermal page, from the kernel's thermal zones and cooling devices.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message, Severity};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, thermal};
+
+/// The data of the Thermal page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub zones: Vec<thermal::Zone>,
+    /// Every cooling device, including those bound to a zone.
+    pub cooling_devices: Vec<thermal::CoolingDevice>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Thermal;
+
+    fn id(&self) -> &'static str {
+        "thermal"
+    }
+
+    fn name(&self) -> String {
+        fl!("thermal")
+    }
+
+    fn icon(&self) -> &'static str {
+        "temperature-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/thermal"
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        let (zones, cooling_devices) = thermal::collect();
+        Ok(Section { zones, cooling_devices })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.thermal(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-thermal-source"), fl!("help-thermal-fields"), fl!("help-thermal-problems"))
+    }
+}
+
+impl AppModel {
+    /// The thermal page, listing thermal zones with their trip points and cooling devices.
+    pub fn thermal<'a>(&'a self, sensors: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if sensors.zones.is_empty() && sensors.cooling_devices.is_empty() {
+            return widget::text::title1(fl!("no-thermal-zones")).into();
+        }
+
+        let cooling_state = |device: &thermal::CoolingDevice| {
+            fl!(
+                "cooling-state",
+                current = device.current_state,
+                max = device.max_state
+            )
+        };
+
+        let mut column = widget::column::with_capacity(sensors.zones.len() + 1).spacing(spacing.space_m);
+        for zone in &sensors.zones {
+            let mut section = settings::section().title(format!("{} ({})", zone.kind, zone.name));
+
+            if let Some(temperature) = zone.temperature {
+                let severity = match (zone.critical(), zone.passive()) {
+                    (Some(critical), _) if temperature >= critical - 5.0 => Severity::Critical,
+                    (_, Some(passive)) if temperature >= passive => Severity::Warning,
+                    _ => Severity::Good,
+                };
+                section = section.add(settings::item(
+                    fl!("temperature"),
+                    row::with_capacity(2)
+                        .push(widget::text::body(format!("{temperature:.1} °C")))
+                        .push(self.status_indicator(severity))
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs),
+                ));
+            }
+
+            for trip in &zone.trip_points {
+                section = section.add(value_item(
+                    fl!("trip-point", kind = trip.kind.as_str()),
+                    format!("{:.1} °C", trip.temperature),
+                    format!("/sys/class/thermal/{}/trip_point_*", zone.name),
+                ));
+            }
+
+            for device in &zone.cooling_devices {
+                section = section.add(
+                    settings::item::builder(device.kind.clone())
+                        .description(fl!("cooling-device", name = device.name.as_str()))
+                        .control(widget::text::body(cooling_state(device))),
+                );
+            }
+
+            column = column.push(section);
+        }
+
+        if !sensors.cooling_devices.is_empty() {
+            let mut section = settings::section().title(fl!("cooling-devices"));
+            for device in &sensors.cooling_devices {
+                section = section.add(
+                    settings::item::builder(device.kind.clone())
+                        .description(device.name.clone())
+                        .control(widget::text::body(cooling_state(device))),
+                );
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
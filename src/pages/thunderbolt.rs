@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Thunderbolt page, listing domains and their devices from sysfs.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, thunderbolt};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<thunderbolt::Domain>;
+
+    const PAGE: Page = Page::Thunderbolt;
+
+    fn id(&self) -> &'static str {
+        "thunderbolt"
+    }
+
+    fn name(&self) -> String {
+        fl!("thunderbolt")
+    }
+
+    fn icon(&self) -> &'static str {
+        "thunderbolt-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("security", "security-level"), ("id", "route")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/bus/thunderbolt/devices"
+    }
+
+    fn collect(&self) -> Result<Vec<thunderbolt::Domain>, host::Error> {
+        Ok(thunderbolt::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<thunderbolt::Domain>) -> Element<'a, Message> {
+        app.thunderbolt(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (
+            fl!("help-thunderbolt-source"),
+            fl!("help-thunderbolt-fields"),
+            fl!("help-thunderbolt-problems"),
+        )
+    }
+}
+
+impl AppModel {
+    /// The Thunderbolt page, listing domains and their connected devices.
+    pub fn thunderbolt<'a>(&'a self, domains: &'a [thunderbolt::Domain]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        if domains.is_empty() {
+            return widget::text::title1(fl!("no-thunderbolt")).into();
+        }
+
+        let mut column = widget::column::with_capacity(domains.len()).spacing(spacing.space_m);
+        for domain in &domains {
+            let mut section = settings::section().title(domain.name.clone());
+            section = section.add(value_item(
+                fl!("security-level"),
+                domain.security.clone().unwrap_or_else(|| fl!("unknown")),
+                format!("/sys/bus/thunderbolt/devices/{}/security", domain.name),
+            ));
+            if let Some(protection) = domain.iommu_dma_protection {
+                section = section.add(value_item(
+                    fl!("iommu-dma-protection"),
+                    if protection { fl!("enabled") } else { fl!("disabled") },
+                    format!("/sys/bus/thunderbolt/devices/{}/iommu_dma_protection", domain.name),
+                ));
+            }
+            column = column.push(section);
+
+            for device in &domain.devices {
+                let name = match (&device.vendor, &device.name) {
+                    (Some(vendor), Some(name)) => format!("{vendor} {name}"),
+                    (None, Some(name)) => name.clone(),
+                    _ => device.id.clone(),
+                };
+                let mut section = settings::section().title(if device.is_host {
+                    fl!("host-controller", name = name)
+                } else {
+                    name
+                });
+                section = section.add(value_item(
+                    fl!("route"),
+                    device.id.clone(),
+                    format!("/sys/bus/thunderbolt/devices/{}", device.id),
+                ));
+                if !device.is_host {
+                    section = section.add(value_item(
+                        fl!("authorization"),
+                        match device.authorization {
+                            Some(thunderbolt::Authorization::Authorized) => fl!("authorized"),
+                            Some(thunderbolt::Authorization::SecureKey) => fl!("authorized-key"),
+                            Some(thunderbolt::Authorization::Unauthorized) => fl!("unauthorized"),
+                            None => fl!("unknown"),
+                        },
+                        format!("/sys/bus/thunderbolt/devices/{}/authorized", device.id),
+                    ));
+                }
+                if let Some(generation) = &device.generation {
+                    section = section.add(value_item(
+                        fl!("generation"),
+                        generation.clone(),
+                        format!("/sys/bus/thunderbolt/devices/{}/generation", device.id),
+                    ));
+                }
+                if let Some(rx) = &device.rx_speed {
+                    section = section.add(value_item(
+                        fl!("rx-speed"),
+                        rx.clone(),
+                        format!("/sys/bus/thunderbolt/devices/{}/rx_speed, rx_lanes", device.id),
+                    ));
+                }
+                if let Some(tx) = &device.tx_speed {
+                    section = section.add(value_item(
+                        fl!("tx-speed"),
+                        tx.clone(),
+                        format!("/sys/bus/thunderbolt/devices/{}/tx_speed, tx_lanes", device.id),
+                    ));
+                }
+                column = column.push(section);
+            }
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
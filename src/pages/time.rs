@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Date & Time page, from timedatectl and the time synchronization daemon.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, row, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message, Severity};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, time};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = time::Time;
+
+    const PAGE: Page = Page::Time;
+
+    fn id(&self) -> &'static str {
+        "time"
+    }
+
+    fn name(&self) -> String {
+        fl!("date-time")
+    }
+
+    fn icon(&self) -> &'static str {
+        "preferences-system-time-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::System
+    }
+
+    fn origin(&self) -> &'static str {
+        "timedatectl show"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "timezone" => Some("timedatectl show: Timezone"),
+            "ntp_enabled" => Some("timedatectl show: NTP"),
+            "synchronized" => Some("timedatectl show: NTPSynchronized"),
+            "sync" => Some("timedatectl show-timesync, chronyc -c tracking"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<time::Time, host::Error> {
+        Ok(time::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a time::Time) -> Element<'a, Message> {
+        app.time(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-time-source"), fl!("help-time-fields"), fl!("help-time-problems"))
+    }
+}
+
+impl AppModel {
+    /// The date and time page, showing the time zone and network time synchronization.
+    pub fn time<'a>(&'a self, time: &'a time::Time) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let yes_no = |value: Option<bool>, yes: String, no: String| match value {
+            Some(true) => yes,
+            Some(false) => no,
+            None => fl!("unknown"),
+        };
+
+        let general = settings::section()
+            .title(fl!("date-time"))
+            .add(value_item(
+                fl!("timezone"),
+                time.timezone.clone().unwrap_or_else(|| fl!("unknown")),
+                "timedatectl show: Timezone",
+            ))
+            .add(value_item(
+                fl!("network-time"),
+                yes_no(time.ntp_enabled, fl!("enabled"), fl!("disabled")),
+                "timedatectl show: NTP",
+            ))
+            .add(settings::item(
+                fl!("clock-synchronized"),
+                row::with_capacity(2)
+                    .push(widget::text::body(yes_no(time.synchronized, fl!("yes"), fl!("no"))))
+                    .push(self.status_indicator(if time.synchronized == Some(true) {
+                        Severity::Good
+                    } else {
+                        Severity::Warning
+                    }))
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs),
+            ));
+
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_m).push(general);
+
+        if let Some(sync) = &time.sync {
+            let (servers_source, status_source) = if sync.daemon == "chrony" {
+                ("chronyc -c sources", "chronyc -c tracking")
+            } else {
+                ("timedatectl show-timesync", "timedatectl timesync-status")
+            };
+            let mut section = settings::section()
+                .title(fl!("time-sync-daemon", daemon = sync.daemon.as_str()))
+                .add(value_item(
+                    fl!("ntp-servers"),
+                    if sync.servers.is_empty() {
+                        fl!("none")
+                    } else {
+                        sync.servers.join("\n")
+                    },
+                    servers_source,
+                ));
+            if let Some(server) = &sync.current_server {
+                section = section.add(value_item(fl!("current-server"), server.clone(), status_source));
+            }
+            if let Some(stratum) = sync.stratum {
+                section = section.add(value_item(fl!("stratum"), stratum.to_string(), status_source));
+            }
+            if let Some(offset) = &sync.offset {
+                section = section.add(value_item(fl!("clock-offset"), offset.clone(), status_source));
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
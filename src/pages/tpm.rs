@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The TPM page, from `/sys/class/tpm` and the IMA state in securityfs.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, tpm};
+
+/// The data of the TPM page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    /// `None` without a TPM.
+    pub tpm: Option<tpm::Tpm>,
+    pub ima: tpm::Ima,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::Tpm;
+
+    fn id(&self) -> &'static str {
+        "tpm"
+    }
+
+    fn name(&self) -> String {
+        fl!("tpm")
+    }
+
+    fn icon(&self) -> &'static str {
+        "channel-secure-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["tpm2_getcap"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("kind", "tpm-kind"),
+            ("driver", "driver-name"),
+            ("version", "tpm-version"),
+            ("policy", "ima-policy"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/tpm/tpm0"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "tpm.manufacturer" => Some("tpm2_getcap properties-fixed: TPM2_PT_MANUFACTURER"),
+            "tpm.pcr_banks" => Some("/sys/class/tpm/tpm0/pcr-*"),
+            "ima" => Some("/sys/kernel/security/ima"),
+            "ima.policy" => Some("/proc/cmdline: ima_policy"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            tpm: tpm::collect(),
+            ima: tpm::ima(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, Message> {
+        app.tpm(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-tpm-source"), fl!("help-tpm-fields"), fl!("help-tpm-problems"))
+    }
+}
+
+impl AppModel {
+    /// The TPM page, showing the kind of TPM, its PCR banks and IMA.
+    pub fn tpm<'a>(&'a self, section: &'a Section) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let Section { tpm, ima } = section;
+        let Some(tpm) = tpm else {
+            return widget::text::title1(fl!("no-tpm")).into();
+        };
+
+        let kind = match tpm.kind {
+            Some(tpm::Kind::Firmware) => fl!("tpm-firmware"),
+            Some(tpm::Kind::Discrete) => fl!("tpm-discrete"),
+            None => fl!("unknown"),
+        };
+        let mut section = settings::section()
+            .title(fl!("tpm"))
+            .add(value_item(fl!("tpm-kind"), kind, "/sys/class/tpm/tpm0/device/driver, tpm2_getcap properties-fixed"));
+        let rows = [
+            (fl!("tpm-version"), tpm.version.clone(), "/sys/class/tpm/tpm0/tpm_version_major"),
+            (fl!("manufacturer"), tpm.manufacturer.clone(), "tpm2_getcap properties-fixed: TPM2_PT_MANUFACTURER"),
+            (fl!("driver-name"), tpm.driver.clone(), "/sys/class/tpm/tpm0/device/driver"),
+        ];
+        for (title, value, source) in rows {
+            if let Some(value) = value {
+                section = section.add(value_item(title, value, source));
+            }
+        }
+        let banks = if tpm.pcr_banks.is_empty() {
+            fl!("not-available")
+        } else {
+            tpm.pcr_banks.iter().map(|bank| bank.to_uppercase()).join(", ")
+        };
+        section = section.add(value_item(fl!("pcr-banks"), banks, "/sys/class/tpm/tpm0/pcr-*"));
+
+        let ima = settings::section()
+            .title(fl!("ima"))
+            .add(value_item(
+                fl!("status"),
+                if ima.active { fl!("active") } else { fl!("inactive") },
+                "/sys/kernel/security/ima",
+            ))
+            .add(value_item(
+                fl!("ima-policy"),
+                ima.policy.clone().unwrap_or_else(|| fl!("none")),
+                "/proc/cmdline: ima_policy",
+            ));
+
+        widget::column::with_capacity(2)
+            .spacing(spacing.space_m)
+            .push(section)
+            .push(ima)
+            .apply(page_scrollable)
+            .into()
+    }
+}
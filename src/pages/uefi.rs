@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The UEFI page, from sysfs and the EFI variables.
+
+use cosmic::widget::list_column;
+use cosmic::{Apply, Element};
+
+use crate::app::{page_scrollable, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, uefi};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = uefi::Uefi;
+
+    const PAGE: Page = Page::Uefi;
+
+    fn id(&self) -> &'static str {
+        "uefi"
+    }
+
+    fn name(&self) -> String {
+        fl!("uefi")
+    }
+
+    fn icon(&self) -> &'static str {
+        "security-high-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Hardware
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/firmware/efi"
+    }
+
+    fn field_origin(&self, path: &str) -> Option<&'static str> {
+        match path {
+            "secure_boot" => Some("/sys/firmware/efi/efivars: SecureBoot"),
+            "setup_mode" => Some("/sys/firmware/efi/efivars: SetupMode"),
+            "platform_size" => Some("/sys/firmware/efi/fw_platform_size"),
+            "firmware_vendor" => Some("/sys/class/dmi/id/bios_vendor"),
+            "firmware_version" => Some("/sys/class/dmi/id/bios_version"),
+            "firmware_date" => Some("/sys/class/dmi/id/bios_date"),
+            _ => None,
+        }
+    }
+
+    fn collect(&self) -> Result<uefi::Uefi, host::Error> {
+        Ok(uefi::collect())
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a uefi::Uefi) -> Element<'a, Message> {
+        app.uefi(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-uefi-source"), fl!("help-uefi-fields"), fl!("help-uefi-problems"))
+    }
+}
+
+impl AppModel {
+    /// The UEFI page, showing the boot mode and Secure Boot state.
+    pub fn uefi<'a>(&'a self, uefi: &'a uefi::Uefi) -> Element<'a, Message> {
+        let enabled = |value: Option<bool>| match value {
+            Some(true) => fl!("enabled"),
+            Some(false) => fl!("disabled"),
+            None => fl!("unknown"),
+        };
+
+        let mut list = list_column();
+
+        list = list.add(value_item(
+            fl!("boot-mode"),
+            match uefi.boot_mode {
+                uefi::BootMode::Uefi => fl!("boot-mode-uefi"),
+                uefi::BootMode::Legacy => fl!("boot-mode-legacy"),
+            },
+            "/sys/firmware/efi",
+        ));
+        if uefi.boot_mode == uefi::BootMode::Uefi {
+            list = list.add(value_item(
+                fl!("secure-boot"),
+                enabled(uefi.secure_boot),
+                "/sys/firmware/efi/efivars: SecureBoot",
+            ));
+            list = list.add(value_item(
+                fl!("setup-mode"),
+                enabled(uefi.setup_mode),
+                "/sys/firmware/efi/efivars: SetupMode",
+            ));
+            if let Some(platform_size) = &uefi.platform_size {
+                list = list.add(value_item(
+                    fl!("platform-size"),
+                    fl!("bits", bits = platform_size.as_str()),
+                    "/sys/firmware/efi/fw_platform_size",
+                ));
+            }
+        }
+        if let Some(vendor) = &uefi.firmware_vendor {
+            list = list.add(value_item(fl!("firmware-vendor"), vendor.clone(), "/sys/class/dmi/id/bios_vendor"));
+        }
+        if let Some(version) = &uefi.firmware_version {
+            list = list.add(value_item(fl!("firmware-version"), version.clone(), "/sys/class/dmi/id/bios_version"));
+        }
+        if let Some(date) = &uefi.firmware_date {
+            list = list.add(value_item(fl!("firmware-date"), date.clone(), "/sys/class/dmi/id/bios_date"));
+        }
+
+        list.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,293 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The USB page and the detail drawer of a USB device.
+
+use cosmic::app::Task;
+use cosmic::iced::Alignment;
+use cosmic::widget::{self, icon, list_column, row, settings};
+use cosmic::{theme, Apply, Element};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::{self, page_scrollable, value_item, AppModel, ContextPage, Severity};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, hwdata, usb, usb_pm};
+
+/// The data of the USB page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Section {
+    pub devices: Vec<usb::UsbDevice>,
+    /// Runtime power management of each device by bus and device number, as
+    /// a list since JSON keys are strings.
+    pub power: Vec<((u16, u16), usb_pm::PowerState)>,
+}
+
+impl Section {
+    /// The power management of the device with this bus and device number.
+    pub fn power(&self, bus: u16, dev: u16) -> Option<&usb_pm::PowerState> {
+        self.power.iter().find(|(device, _)| *device == (bus, dev)).map(|(_, power)| power)
+    }
+}
+
+/// The device shown in the detail drawer.
+#[derive(Default)]
+pub struct State {
+    /// Bus and device number of the device shown in the detail drawer.
+    pub selected: Option<(u16, u16)>,
+}
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Section;
+
+    const PAGE: Page = Page::USBs;
+
+    fn id(&self) -> &'static str {
+        "usb"
+    }
+
+    fn name(&self) -> String {
+        fl!("usb-devices")
+    }
+
+    fn icon(&self) -> &'static str {
+        "media-removable-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("port", "usb-port"),
+            ("class", "usb-class"),
+            ("speed", "usb-speed"),
+            ("version", "usb-version"),
+            ("max_power", "usb-max-power"),
+            ("interfaces", "usb-interfaces"),
+            ("product", "product-name"),
+        ]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/bus/usb/devices"
+    }
+
+    fn collect(&self) -> Result<Section, host::Error> {
+        Ok(Section {
+            devices: usb::devices(),
+            power: usb_pm::collect().into_iter().collect(),
+        })
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Section) -> Element<'a, app::Message> {
+        app.usbs(section)
+    }
+
+    /// Each device with its power management state.
+    fn export(&self, _app: &AppModel, section: &Section) -> Value {
+        Value::Array(
+            section
+                .devices
+                .iter()
+                .map(|device| {
+                    let mut value = json!(device);
+                    value["power"] = json!(section.power(device.bus, device.dev));
+                    value
+                })
+                .collect(),
+        )
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-usb-source"), fl!("help-usb-fields"), fl!("help-usb-problems"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Opens the detail drawer of the device with this bus and device number.
+    Select(u16, u16),
+}
+
+impl AppModel {
+    pub(crate) fn update_usb(&mut self, message: Message) -> Task<app::Message> {
+        match message {
+            Message::Select(bus, dev) => {
+                self.usb.selected = Some((bus, dev));
+                self.show_context_page(ContextPage::UsbDevice);
+            }
+        }
+        Task::none()
+    }
+
+    /// The detail drawer for the selected USB device.
+    pub fn usb_device(&self) -> Element<app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let devices = self.sections.get::<Provider>().map_or(&[][..], |section| &section.devices);
+        let selected = self
+            .usb
+            .selected
+            .and_then(|(bus, dev)| devices.iter().find(|device| device.bus == bus && device.dev == dev));
+        let Some(device) = selected else {
+            return widget::text::body(fl!("no-device-selected")).into();
+        };
+
+        let class = |class: Option<u8>, subclass: Option<u8>, protocol: Option<u8>| {
+            let Some(class) = class else {
+                return fl!("unknown");
+            };
+            let name = usb::class_name(class).map_or_else(|| format!("{class:02x}"), str::to_string);
+            format!(
+                "{name} ({class:02x}:{:02x}:{:02x})",
+                subclass.unwrap_or_default(),
+                protocol.unwrap_or_default()
+            )
+        };
+
+        let ids = match (&device.vendor_id, &device.product_id) {
+            (Some(vendor), Some(product)) => Some(format!("{vendor}:{product}")),
+            _ => None,
+        };
+        // Names come from usb.ids when it knows the device, and every other
+        // value from the device's sysfs attributes.
+        let sysfs = |attribute: &str| format!("{}/{}/{attribute}", usb::USB_DEVICES_DIR, device.port);
+        let known = |lookup: fn(u16, u16) -> bool| device.ids().is_some_and(|(vendor, product)| lookup(vendor, product));
+        let product_source = if known(|vendor, product| hwdata::usb_device(vendor, product).is_some()) {
+            "usb.ids".to_string()
+        } else {
+            sysfs("product")
+        };
+        let vendor_source = if known(|vendor, _| hwdata::usb_vendor(vendor).is_some()) {
+            "usb.ids".to_string()
+        } else {
+            sysfs("manufacturer")
+        };
+        let rows = [
+            (fl!("product-name"), device.product_name(), product_source),
+            (fl!("manufacturer"), device.vendor_name(), vendor_source),
+            (fl!("usb-ids"), ids, sysfs("idVendor, idProduct")),
+            (fl!("serial-number"), device.serial.clone(), sysfs("serial")),
+            (fl!("usb-port"), Some(device.port.clone()), sysfs("")),
+            (fl!("usb-version"), device.version.clone(), sysfs("version")),
+            (
+                fl!("usb-speed"),
+                device.speed.as_ref().map(|speed| fl!("usb-speed-mbps", speed = speed.as_str())),
+                sysfs("speed"),
+            ),
+            (fl!("usb-max-power"), device.max_power.clone(), sysfs("bMaxPower")),
+            (
+                fl!("usb-class"),
+                Some(class(device.class, device.subclass, device.protocol)),
+                sysfs("bDeviceClass, bDeviceSubClass, bDeviceProtocol"),
+            ),
+        ];
+        let mut section = settings::section();
+        for (title, value, source) in rows {
+            if let Some(value) = value {
+                section = section.add(value_item(title, value, source));
+            }
+        }
+
+        let mut column = widget::column::with_capacity(2).spacing(spacing.space_m).push(section);
+        if !device.interfaces.is_empty() {
+            let mut interfaces = settings::section().title(fl!("usb-interfaces"));
+            for interface in &device.interfaces {
+                let mut description = class(interface.class, interface.subclass, interface.protocol);
+                if let Some(text) = &interface.description {
+                    description = format!("{text} · {description}");
+                }
+                interfaces = interfaces.add(
+                    settings::item::builder(interface.name.clone())
+                        .description(description)
+                        .control(widget::text::body(
+                            interface.driver.clone().unwrap_or_else(|| fl!("none")),
+                        )),
+                );
+            }
+            column = column.push(interfaces);
+        }
+
+        column.into()
+    }
+
+    /// The USB page, listing devices from sysfs along with their power management state.
+    pub fn usbs<'a>(&'a self, section: &'a Section) -> Element<'a, app::Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if section.devices.is_empty() {
+            return widget::text::title1(fl!("no-usb-devices")).into();
+        }
+
+        let devices = section
+            .devices
+            .iter()
+            .map(|device| {
+                let location = fl!(
+                    "usb-location",
+                    bus = format!("{:03}", device.bus),
+                    dev = format!("{:03}", device.dev),
+                    vendor = device.vendor_id.as_deref().unwrap_or("0000"),
+                    product = device.product_id.as_deref().unwrap_or("0000")
+                );
+                let details_button = widget::button::icon(icon::from_name("go-next-symbolic"))
+                    .on_press(app::Message::Usb(Message::Select(device.bus, device.dev)));
+                let Some(power) = section.power(device.bus, device.dev) else {
+                    let details = row::with_capacity(2)
+                        .push(widget::text::body(location))
+                        .push(details_button)
+                        .align_y(Alignment::Center)
+                        .spacing(spacing.space_xxs);
+                    return settings::item(device.name(), details).into();
+                };
+
+                let mut status = fl!(
+                    "runtime-pm",
+                    status = power.runtime_status.as_deref().unwrap_or("unknown")
+                );
+                match (power.control.as_deref(), power.autosuspend_delay_ms) {
+                    (Some("auto"), Some(delay)) => {
+                        status.push_str(" · ");
+                        status.push_str(&fl!("autosuspend-delay", delay = delay));
+                    }
+                    (Some("on"), _) => {
+                        status.push_str(" · ");
+                        status.push_str(&fl!("autosuspend-disabled"));
+                    }
+                    _ => {}
+                }
+
+                let mut details = row::with_capacity(3)
+                    .push(
+                        widget::column::with_capacity(2)
+                            .push(widget::text::body(location))
+                            .push(widget::text::caption(status))
+                            .align_x(Alignment::End),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs);
+                if power.at_risk {
+                    details = details.push(widget::tooltip(
+                        self.status_indicator(Severity::Warning),
+                        widget::text::body(fl!("autosuspend-warning")),
+                        widget::tooltip::Position::Left,
+                    ));
+                }
+                details = details.push(details_button);
+
+                settings::item(device.name(), details).into()
+            })
+            .collect::<Vec<Element<app::Message>>>();
+
+        let mut section = list_column();
+        for item in devices {
+            section = section.add(item);
+        }
+        section.apply(page_scrollable)
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The Wi-Fi page, from `iw` and the wireless adapters in sysfs.
+
+use cosmic::widget::{self, settings};
+use cosmic::{theme, Apply, Element};
+
+use crate::app::{missing_tool, page_scrollable, usage_gauge, value_item, AppModel, Message};
+use crate::pages::{Category, DataProvider, Page};
+use crate::{fl, host, wifi};
+
+pub struct Provider;
+
+impl DataProvider for Provider {
+    type Section = Vec<wifi::Adapter>;
+
+    const PAGE: Page = Page::WiFi;
+
+    fn id(&self) -> &'static str {
+        "wifi"
+    }
+
+    fn name(&self) -> String {
+        fl!("wifi")
+    }
+
+    fn icon(&self) -> &'static str {
+        "network-wireless-symbolic"
+    }
+
+    fn category(&self) -> Category {
+        Category::Devices
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+
+    fn tools(&self) -> &'static [&'static str] {
+        &["iw"]
+    }
+
+    fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        &[("address", "mac-address"), ("driver", "kernel-driver")]
+    }
+
+    fn origin(&self) -> &'static str {
+        "/sys/class/net, iw"
+    }
+
+    fn collect(&self) -> Result<Vec<wifi::Adapter>, host::Error> {
+        wifi::collect()
+    }
+
+    fn view<'a>(&self, app: &'a AppModel, section: &'a Vec<wifi::Adapter>) -> Element<'a, Message> {
+        app.wifi(section)
+    }
+
+    fn help(&self) -> (String, String, String) {
+        (fl!("help-wifi-source"), fl!("help-wifi-fields"), fl!("help-wifi-problems"))
+    }
+}
+
+impl AppModel {
+    /// The Wi-Fi page, listing wireless adapters, their capabilities and current link.
+    pub fn wifi<'a>(&'a self, adapters: &'a [wifi::Adapter]) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        if adapters.is_empty() {
+            return widget::text::title1(fl!("no-wifi")).into();
+        }
+
+        let mut column = widget::column::with_capacity(adapters.len()).spacing(spacing.space_m);
+        for adapter in adapters {
+            let mut section = settings::section().title(adapter.interface.clone());
+            if let Some(driver) = &adapter.driver {
+                section = section.add(value_item(
+                    fl!("kernel-driver"),
+                    driver.clone(),
+                    format!("/sys/class/net/{}/device/driver", adapter.interface),
+                ));
+            }
+            if let Some(address) = &adapter.address {
+                section = section.add(value_item(
+                    fl!("mac-address"),
+                    address.clone(),
+                    format!("/sys/class/net/{}/address", adapter.interface),
+                ));
+            }
+            if !tools::is_available("iw") {
+                column = column.push(section.add(missing_tool(fl!("wifi-capabilities"), "iw")));
+                continue;
+            }
+            section = section.add(value_item(
+                fl!("bands"),
+                adapter.bands.join(", "),
+                format!("iw phy {} info: Band", adapter.phy),
+            ));
+            section = section.add(value_item(
+                fl!("standards"),
+                adapter.standards.join(", "),
+                format!("iw phy {} info: HT, VHT and HE capabilities", adapter.phy),
+            ));
+
+            match &adapter.link {
+                Some(link) => {
+                    if let Some(ssid) = &link.ssid {
+                        section = section.add(value_item(
+                            fl!("ssid"),
+                            ssid.clone(),
+                            format!("iw dev {} link: SSID", adapter.interface),
+                        ));
+                    }
+                    if let Some(bssid) = &link.bssid {
+                        section = section.add(value_item(
+                            fl!("bssid"),
+                            bssid.clone(),
+                            format!("iw dev {} link: Connected to", adapter.interface),
+                        ));
+                    }
+                    if let Some(frequency) = link.frequency {
+                        section = section.add(value_item(
+                            fl!("frequency"),
+                            format!("{frequency} MHz"),
+                            format!("iw dev {} link: freq", adapter.interface),
+                        ));
+                    }
+                    if let Some(signal) = link.signal {
+                        // Map the usable range of -90 to -30 dBm onto the gauge.
+                        let quality = (signal + 90).clamp(0, 60) as f32;
+                        section = section.add(settings::item(
+                            fl!("signal"),
+                            usage_gauge(quality, 60.0, format!("{signal} dBm")),
+                        ));
+                    }
+                    if let Some(rx) = &link.rx_bitrate {
+                        section = section.add(value_item(
+                            fl!("rx-bitrate"),
+                            rx.clone(),
+                            format!("iw dev {} link: rx bitrate", adapter.interface),
+                        ));
+                    }
+                    if let Some(tx) = &link.tx_bitrate {
+                        section = section.add(value_item(
+                            fl!("tx-bitrate"),
+                            tx.clone(),
+                            format!("iw dev {} link: tx bitrate", adapter.interface),
+                        ));
+                    }
+                }
+                None => {
+                    section = section.add(value_item(
+                        fl!("status"),
+                        fl!("disconnected"),
+                        format!("iw dev {} link", adapter.interface),
+                    ));
+                }
+            }
+            column = column.push(section);
+        }
+
+        column.apply(page_scrollable).into()
+    }
+}
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Enumerates PCI devices from sysfs, and parses the verbose `lspci -vvv`
+//! description of a single device when lspci is installed.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{driver, host, hwdata};
+
+const PCI_DEVICES_DIR: &str = "/sys/bus/pci/devices";
+
+/// A PCI capability, such as `Express (v2) Endpoint` or `MSI-X`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Capability {
+    /// The offset in configuration space, e.g. `40`.
+    pub offset: String,
+    pub name: String,
+    /// The decoded capability registers, one line each.
+    pub details: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Details {
+    /// The summary line, e.g. `VGA compatible controller: Intel Corporation …`.
+    pub description: String,
+    /// Top-level fields such as `Subsystem`, `Control` and `Status`.
+    pub fields: Vec<(String, String)>,
+    pub interrupt: Option<String>,
+    /// Memory and I/O port regions, including the expansion ROM.
+    pub regions: Vec<String>,
+    pub capabilities: Vec<Capability>,
+    /// Set when lspci could not read the extended configuration space,
+    /// which needs root.
+    pub capabilities_denied: bool,
+    pub driver: Option<String>,
+    /// Modules able to drive the device, not necessarily loaded.
+    pub modules: Vec<String>,
+}
+
+/// Parses lspci output, where fields are indented by one tab and the
+/// registers of a capability by two.
+fn parse(output: &str) -> Details {
+    let mut details = Details::default();
+    let mut lines = output.lines();
+    details.description = lines.next().unwrap_or_default().to_string();
+
+    for line in lines {
+        if let Some(detail) = line.strip_prefix("\t\t") {
+            if let Some(capability) = details.capabilities.last_mut() {
+                capability.details.push(detail.trim().to_string());
+            }
+            continue;
+        }
+        let Some(line) = line.strip_prefix('\t') else {
+            continue;
+        };
+
+        if line.starts_with("Region ") || line.starts_with("Expansion ROM") {
+            details.regions.push(line.to_string());
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "Capabilities" if value == "<access denied>" => details.capabilities_denied = true,
+            "Capabilities" => {
+                // `[40] Power Management version 3`
+                let (offset, name) = value
+                    .strip_prefix('[')
+                    .and_then(|value| value.split_once(']'))
+                    .unwrap_or(("", value));
+                details.capabilities.push(Capability {
+                    offset: offset.to_string(),
+                    name: name.trim().to_string(),
+                    details: Vec::new(),
+                });
+            }
+            "Interrupt" => details.interrupt = Some(value.to_string()),
+            "Kernel driver in use" => details.driver = Some(value.to_string()),
+            "Kernel modules" => details.modules = value.split(", ").map(str::to_string).collect(),
+            _ => details.fields.push((key.to_string(), value.to_string())),
+        }
+    }
+    details
+}
+
+/// Runs `lspci -vvv` for the device in `slot`.
+pub fn details(slot: &str) -> Result<Details, String> {
+    let output = host::output(host::command("lspci").args(["-vvv", "-s", slot]))
+        .map_err(|e| host::spawn_error("lspci", &e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(parse(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// A device on the PCI bus, read from sysfs.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Device {
+    /// The slot as lspci prints it, without the domain when it is zero.
+    pub slot: String,
+    /// Class, subclass and programming interface, e.g. `0x030000`.
+    pub class: u32,
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub subsystem_vendor_id: Option<u16>,
+    pub subsystem_device_id: Option<u16>,
+    pub revision: Option<u8>,
+    pub driver: Option<String>,
+    /// Modules whose aliases match the device, not necessarily loaded.
+    pub modules: Vec<String>,
+}
+
+impl Device {
+    /// The most specific class name, e.g. `VGA compatible controller`.
+    pub fn class_name(&self) -> String {
+        hwdata::pci_class((self.class >> 16) as u8, (self.class >> 8) as u8)
+            .map_or_else(|| format!("Class {:04x}", self.class >> 8), str::to_string)
+    }
+
+    /// The vendor and device name, e.g. `Intel Corporation Alder Lake-P GT2`.
+    pub fn name(&self) -> String {
+        let vendor = hwdata::pci_vendor(self.vendor_id)
+            .map_or_else(|| format!("Vendor {:04x}", self.vendor_id), str::to_string);
+        let device = hwdata::pci_device(self.vendor_id, self.device_id)
+            .map_or_else(|| format!("Device {:04x}", self.device_id), str::to_string);
+        match self.revision.filter(|revision| *revision != 0) {
+            Some(revision) => format!("{vendor} {device} (rev {revision:02x})"),
+            None => format!("{vendor} {device}"),
+        }
+    }
+
+    /// The vendor, device and subsystem IDs, e.g. `8086:46a6 (17aa:22e6)`.
+    pub fn ids(&self) -> String {
+        let ids = format!("{:04x}:{:04x}", self.vendor_id, self.device_id);
+        match (self.subsystem_vendor_id, self.subsystem_device_id) {
+            (Some(vendor), Some(device)) => format!("{ids} ({vendor:04x}:{device:04x})"),
+            _ => ids,
+        }
+    }
+}
+
+/// Lists PCI devices from `/sys/bus/pci/devices`, sorted by slot.
+pub fn devices() -> Vec<Device> {
+    let mut devices = fs::read_dir(host::path(PCI_DEVICES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let read = |name: &str| fs::read_to_string(path.join(name)).ok();
+            let read_hex = |name: &str| u32::from_str_radix(read(name)?.trim().trim_start_matches("0x"), 16).ok();
+
+            let address = entry.file_name().to_string_lossy().into_owned();
+            Some(Device {
+                slot: address.strip_prefix("0000:").map(str::to_string).unwrap_or(address),
+                class: read_hex("class")?,
+                vendor_id: read_hex("vendor")? as u16,
+                device_id: read_hex("device")? as u16,
+                subsystem_vendor_id: read_hex("subsystem_vendor").map(|id| id as u16),
+                subsystem_device_id: read_hex("subsystem_device").map(|id| id as u16),
+                revision: read_hex("revision").map(|revision| revision as u8),
+                driver: fs::read_link(path.join("driver"))
+                    .ok()
+                    .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned())),
+                modules: read("modalias")
+                    .map(|modalias| driver::modules_for_alias(modalias.trim()))
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    devices.sort_by(|a, b| a.slot.cmp(&b.slot));
+    devices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GPU: &str = "00:02.0 VGA compatible controller: Intel Corporation Alder Lake-P GT2 [Iris Xe Graphics] (rev 0c) (prog-if 00 [VGA controller])
+\tSubsystem: Lenovo Device 22e6
+\tControl: I/O+ Mem+ BusMaster+ SpecCycle- MemWINV- VGASnoop- ParErr- Stepping- SERR- FastB2B- DisINTx+
+\tStatus: Cap+ 66MHz- UDF- FastB2B- ParErr- DEVSEL=fast >TAbort- <TAbort- <MAbort- >SERR- <PERR- INTx-
+\tLatency: 0, Cache Line Size: 64 bytes
+\tInterrupt: pin A routed to IRQ 147
+\tIOMMU group: 0
+\tRegion 0: Memory at 603c000000 (64-bit, non-prefetchable) [size=16M]
+\tRegion 2: Memory at 4000000000 (64-bit, prefetchable) [size=256M]
+\tExpansion ROM at 000c0000 [virtual] [disabled] [size=128K]
+\tCapabilities: [40] Vendor Specific Information: Len=0c <?>
+\tCapabilities: [70] Express (v2) Root Complex Integrated Endpoint, MSI 00
+\t\tDevCap:\tMaxPayload 128 bytes, PhantFunc 0
+\t\tDevCtl:\tCorrErr- NonFatalErr- FatalErr- UnsupReq-
+\tKernel driver in use: i915
+\tKernel modules: i915, xe
+";
+
+    #[test]
+    fn parses_fields_regions_and_capabilities() {
+        let details = parse(GPU);
+        assert!(details.description.starts_with("00:02.0 VGA compatible controller"));
+        assert_eq!(details.fields[0], ("Subsystem".to_string(), "Lenovo Device 22e6".to_string()));
+        assert_eq!(details.interrupt.as_deref(), Some("pin A routed to IRQ 147"));
+        assert_eq!(details.regions.len(), 3);
+        assert!(details.regions[2].starts_with("Expansion ROM"));
+        assert_eq!(details.capabilities.len(), 2);
+        assert_eq!(details.capabilities[0].offset, "40");
+        assert_eq!(details.capabilities[1].name, "Express (v2) Root Complex Integrated Endpoint, MSI 00");
+        assert_eq!(details.capabilities[1].details.len(), 2);
+        assert!(!details.capabilities_denied);
+        assert_eq!(details.driver.as_deref(), Some("i915"));
+        assert_eq!(details.modules, ["i915", "xe"]);
+    }
+
+    #[test]
+    fn notes_denied_capabilities() {
+        let details = parse("00:1f.3 Audio device: Intel Corporation Device 51c8\n\tCapabilities: <access denied>\n");
+        assert!(details.capabilities_denied);
+        assert!(details.capabilities.is_empty());
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        let details = parse("");
+        assert!(details.description.is_empty());
+        assert!(details.fields.is_empty());
+
+        // Registers without a capability and lines without a key are dropped.
+        let details = parse("summary\n\t\tDevCap: orphan\n\tno key here\nunindented: line\n\tCapabilities: bare name\n");
+        assert!(details.fields.is_empty());
+        assert_eq!(details.capabilities[0].offset, "");
+        assert_eq!(details.capabilities[0].name, "bare name");
+    }
+
+    #[test]
+    fn formats_ids() {
+        let device = Device {
+            slot: "00:02.0".to_string(),
+            class: 0x030000,
+            vendor_id: 0x8086,
+            device_id: 0x46a6,
+            subsystem_vendor_id: Some(0x17aa),
+            subsystem_device_id: Some(0x22e6),
+            revision: Some(0x0c),
+            driver: None,
+            modules: Vec::new(),
+        };
+        assert_eq!(device.ids(), "8086:46a6 (17aa:22e6)");
+
+        let device = Device {
+            subsystem_device_id: None,
+            ..device
+        };
+        assert_eq!(device.ids(), "8086:46a6");
+    }
+}
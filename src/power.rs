@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads batteries and vendor battery care settings from sysfs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, privileged};
+
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+/// Lenovo IdeaPad laptops expose a fixed ~60% charge limit here.
+const IDEAPAD_DIR: &str = "/sys/bus/platform/drivers/ideapad_acpi";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Battery {
+    pub name: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub technology: Option<String>,
+    pub status: Option<String>,
+    pub capacity: Option<u8>,
+    pub cycle_count: Option<u32>,
+    /// Current full capacity relative to its design capacity, in percent.
+    pub health: Option<f32>,
+    /// `charge_control_end_threshold`, as exposed by asus-wmi, thinkpad_acpi and others.
+    pub charge_limit: Option<u8>,
+}
+
+/// The sysfs attribute holding the charge limit of the battery called `name`.
+pub fn charge_limit_path(name: &str) -> PathBuf {
+    host::path(POWER_SUPPLY_DIR)
+        .join(name)
+        .join("charge_control_end_threshold")
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConservationMode {
+    pub path: PathBuf,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Power {
+    pub ac_online: Option<bool>,
+    pub batteries: Vec<Battery>,
+    pub conservation_mode: Option<ConservationMode>,
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn read_battery(path: &Path, name: String) -> Battery {
+    let read = |file: &str| read_trimmed(path.join(file));
+    let number = |file: &str| read(file).and_then(|value| value.parse::<f32>().ok());
+
+    let health = number("energy_full")
+        .zip(number("energy_full_design"))
+        .or_else(|| number("charge_full").zip(number("charge_full_design")))
+        .filter(|(_, design)| *design > 0.0)
+        .map(|(full, design)| full / design * 100.0);
+
+    Battery {
+        manufacturer: read("manufacturer"),
+        model: read("model_name"),
+        technology: read("technology"),
+        status: read("status"),
+        capacity: read("capacity").and_then(|value| value.parse().ok()),
+        cycle_count: read("cycle_count").and_then(|value| value.parse().ok()),
+        health,
+        charge_limit: read("charge_control_end_threshold").and_then(|value| value.parse().ok()),
+        name,
+    }
+}
+
+fn conservation_mode() -> Option<ConservationMode> {
+    fs::read_dir(host::path(IDEAPAD_DIR))
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join("conservation_mode"))
+        .find_map(|path| {
+            let enabled = read_trimmed(&path)? == "1";
+            Some(ConservationMode { path, enabled })
+        })
+}
+
+pub fn collect() -> Power {
+    let mut power = Power {
+        conservation_mode: conservation_mode(),
+        ..Default::default()
+    };
+
+    for entry in fs::read_dir(host::path(POWER_SUPPLY_DIR)).into_iter().flatten().filter_map(Result::ok) {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        match read_trimmed(path.join("type")).as_deref() {
+            Some("Battery") if read_trimmed(path.join("scope")).as_deref() != Some("Device") => {
+                power.batteries.push(read_battery(&path, name));
+            }
+            Some("Mains") => {
+                power.ac_online = read_trimmed(path.join("online")).map(|online| online == "1");
+            }
+            _ => {}
+        }
+    }
+
+    power.batteries.sort_by(|a, b| a.name.cmp(&b.name));
+    power
+}
+
+/// Sets the charge limit of the battery called `name` to `limit` percent,
+/// through the privileged helper, which checks both.
+pub fn set_charge_limit(name: &str, limit: &str) -> Result<(), host::Error> {
+    // The sample data is a writable copy.
+    if host::is_demo() {
+        return fs::write(charge_limit_path(name), limit).map_err(|e| host::Error::Failed(e.to_string()));
+    }
+    privileged::run(&["charge-limit", name, limit]).map(drop)
+}
+
+/// Turns the IdeaPad conservation mode on (`"1"`) or off (`"0"`) through the
+/// privileged helper, which locates the attribute itself.
+pub fn set_conservation_mode(path: &Path, enabled: &str) -> Result<(), host::Error> {
+    if host::is_demo() {
+        return fs::write(path, enabled).map_err(|e| host::Error::Failed(e.to_string()));
+    }
+    privileged::run(&["conservation-mode", enabled]).map(drop)
+}
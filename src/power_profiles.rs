@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads and switches the power profile through power-profiles-daemon.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use zbus::zvariant::OwnedValue;
+
+use crate::host;
+
+/// The interface name since power-profiles-daemon 0.20, and the older one.
+const SERVICES: &[(&str, &str)] = &[
+    ("org.freedesktop.UPower.PowerProfiles", "/org/freedesktop/UPower/PowerProfiles"),
+    ("net.hadess.PowerProfiles", "/net/hadess/PowerProfiles"),
+];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Profiles {
+    /// `power-saver`, `balanced` or `performance`.
+    pub active: String,
+    pub available: Vec<String>,
+    /// Why performance is currently degraded, e.g. `lap-detected` or `high-operating-temperature`.
+    pub performance_degraded: Option<String>,
+}
+
+fn proxy<'a>(connection: &'a zbus::blocking::Connection) -> zbus::Result<zbus::blocking::Proxy<'a>> {
+    let mut last_error = None;
+    for (service, path) in SERVICES {
+        let proxy = zbus::blocking::Proxy::new(connection, *service, *path, *service)?;
+        // Creating a proxy does not check that the service exists, so probe it.
+        match proxy.get_property::<String>("ActiveProfile") {
+            Ok(_) => return Ok(proxy),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| zbus::Error::Failure("power-profiles-daemon not found".to_string())))
+}
+
+/// Collects the profiles. Fails when power-profiles-daemon is not running.
+pub fn collect() -> zbus::Result<Profiles> {
+    let connection = host::system_bus()?;
+    let proxy = proxy(&connection)?;
+
+    let available = proxy
+        .get_property::<Vec<HashMap<String, OwnedValue>>>("Profiles")?
+        .iter()
+        .filter_map(|profile| {
+            profile
+                .get("Profile")
+                .and_then(|value| value.downcast_ref::<&str>().ok())
+                .map(str::to_string)
+        })
+        .collect();
+
+    Ok(Profiles {
+        active: proxy.get_property("ActiveProfile")?,
+        available,
+        performance_degraded: proxy
+            .get_property::<String>("PerformanceDegraded")
+            .ok()
+            .filter(|reason| !reason.is_empty()),
+    })
+}
+
+/// Switches the active profile. Active sessions may do so without authentication.
+pub fn set(profile: &str) -> zbus::Result<()> {
+    let connection = host::system_bus()?;
+    proxy(&connection)?.set_property("ActiveProfile", profile)?;
+    Ok(())
+}
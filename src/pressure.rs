@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads pressure stall information (PSI) from `/proc/pressure`.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct Averages {
+    /// Share of wall time stalled over the last 10 seconds, in percent.
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Resource {
+    Cpu,
+    Memory,
+    Io,
+}
+
+impl Resource {
+    pub const ALL: [Resource; 3] = [Resource::Cpu, Resource::Memory, Resource::Io];
+
+    fn file(self) -> &'static str {
+        match self {
+            Resource::Cpu => "/proc/pressure/cpu",
+            Resource::Memory => "/proc/pressure/memory",
+            Resource::Io => "/proc/pressure/io",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Pressure {
+    pub resource: Resource,
+    /// Time in which at least one task was stalled.
+    pub some: Averages,
+    /// Time in which all non-idle tasks were stalled at once.
+    pub full: Option<Averages>,
+}
+
+/// Parses a line such as `some avg10=0.00 avg60=0.12 avg300=0.05 total=1234`.
+fn parse_line(line: &str) -> Averages {
+    let mut averages = Averages::default();
+    for field in line.split_whitespace() {
+        let Some((key, value)) = field.split_once('=') else {
+            continue;
+        };
+        let value = value.parse().unwrap_or_default();
+        match key {
+            "avg10" => averages.avg10 = value,
+            "avg60" => averages.avg60 = value,
+            "avg300" => averages.avg300 = value,
+            _ => {}
+        }
+    }
+    averages
+}
+
+/// Collects PSI for every resource. Empty when the kernel was built without
+/// `CONFIG_PSI` or booted with `psi=0`.
+pub fn collect() -> Vec<Pressure> {
+    Resource::ALL
+        .into_iter()
+        .filter_map(|resource| {
+            let contents = fs::read_to_string(host::path(resource.file())).ok()?;
+            let mut pressure = Pressure {
+                resource,
+                some: Averages::default(),
+                full: None,
+            };
+            for line in contents.lines() {
+                if line.starts_with("some") {
+                    pressure.some = parse_line(line);
+                } else if line.starts_with("full") {
+                    pressure.full = Some(parse_line(line));
+                }
+            }
+            Some(pressure)
+        })
+        .collect()
+}
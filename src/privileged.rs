@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Runs the privileged helper through `pkexec`. The polkit action for reads
+//! keeps the authorization for a few minutes, so reading several pages
+//! prompts once. Changing a setting and uploading a hardware probe have
+//! actions of their own that prompt every time.
+
+use crate::host;
+
+/// The helper, installed under the prefix the app was built for, where the
+/// polkit policy expects it.
+const HELPER: &str = concat!(env!("EXAMINE_PREFIX"), "/libexec/examine-helper");
+
+/// The hardware probe uploader, installed next to the helper.
+const PROBE: &str = concat!(env!("EXAMINE_PREFIX"), "/libexec/examine-probe");
+
+/// Helper operations that change a setting. pkexec picks the polkit action
+/// from the helper's first argument, so these are run as `write <operation>`.
+const WRITES: &[&str] = &["charge-limit", "conservation-mode"];
+
+/// Runs a helper operation, such as `["dmi"]` or `["smart", "/dev/sda"]`,
+/// and returns its standard output. This does not go through
+/// [`host::output`] since pkexec waits on the user to authenticate; the
+/// helper limits how long the tool itself may run instead.
+pub fn run(args: &[&str]) -> Result<String, host::Error> {
+    let operation = args.first().copied().unwrap_or("helper");
+    if WRITES.contains(&operation) {
+        let args = std::iter::once("write").chain(args.iter().copied()).collect::<Vec<_>>();
+        return pkexec(HELPER, operation, &args);
+    }
+    pkexec(HELPER, operation, args)
+}
+
+/// Runs the hardware probe uploader and returns its standard output.
+pub fn probe() -> Result<String, host::Error> {
+    pkexec(PROBE, "probe", &[])
+}
+
+/// Runs `program` as root, naming the failed `operation` in errors.
+fn pkexec(program: &str, operation: &str, args: &[&str]) -> Result<String, host::Error> {
+    let mut command = host::command("pkexec");
+    command.arg(program).args(args);
+    // Demo mode replays captured output rather than prompting.
+    let output = if host::is_demo() { host::output(&mut command) } else { command.output() };
+    let output = output.map_err(|e| host::spawn_error("pkexec", &e))?;
+
+    let operation = operation.to_string();
+    // pkexec reserves 126 for a dismissed prompt and 127 for a refusal.
+    match output.status.code() {
+        Some(126 | 127) => return Err(host::Error::PermissionDenied(operation)),
+        // The `timeout` wrapper stopped the tool.
+        Some(124 | 137) => return Err(host::Error::TimedOut(operation)),
+        _ => {}
+    }
+    if !output.status.success() && output.stdout.is_empty() {
+        return Err(host::Error::Failed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    host::record(&command, &output.stdout);
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
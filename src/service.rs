@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! The D-Bus service, `examine --service`, through which other utilities and
+//! scripts read the same parsed data as the app shows rather than parsing
+//! the tools' output themselves. It is started on demand by the session bus.
+
+use std::pin::pin;
+
+use futures_util::StreamExt;
+use zbus::{fdo, interface, SignalContext};
+
+use crate::app::AppModel;
+use crate::export::{self, Format};
+use crate::hotplug;
+use crate::pages::{self, Page};
+
+/// The well-known name, shared with the interface.
+pub const NAME: &str = "io.github.cosmic_utils.Examine";
+
+const PATH: &str = "/io/github/cosmic_utils/Examine";
+
+struct Examine;
+
+#[interface(name = "io.github.cosmic_utils.Examine")]
+impl Examine {
+    /// The data of the page named `name`, such as `processor`, as the JSON
+    /// document of `examine --page <name> --format json`.
+    async fn get_page(&self, name: String) -> fdo::Result<String> {
+        let page = Page::from_id(&name).ok_or_else(|| fdo::Error::InvalidArgs(format!("unknown page {name}")))?;
+        tokio::task::spawn_blocking(move || {
+            let data = AppModel::headless(page).export_data(page);
+            export::document(Format::Json, &[data], None)
+        })
+        .await
+        .map_err(|e| fdo::Error::Failed(e.to_string()))
+    }
+
+    /// The names of the pages that apply to this system.
+    fn list_pages(&self) -> Vec<String> {
+        pages::PAGES
+            .iter()
+            .filter(|provider| provider.is_available())
+            .map(|provider| provider.id().to_string())
+            .collect()
+    }
+
+    /// A device was plugged in or removed, changing the data of `page`.
+    #[zbus(signal)]
+    async fn hardware_changed(context: &SignalContext<'_>, page: &str, added: bool) -> zbus::Result<()>;
+}
+
+/// Serves the interface on the session bus until the process is stopped.
+pub fn run() -> zbus::Result<()> {
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let connection = zbus::connection::Builder::session()?
+            .name(NAME)?
+            .serve_at(PATH, Examine)?
+            .build()
+            .await?;
+        let interface = connection.object_server().interface::<_, Examine>(PATH).await?;
+
+        let mut events = pin!(hotplug::events());
+        while let Some(event) = events.next().await {
+            if let Some(page) = event.page() {
+                let added = event.action == hotplug::Action::Added;
+                Examine::hardware_changed(interface.signal_context(), page.id(), added).await?;
+            }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Describes the desktop session from the environment and the running compositor.
+
+use std::env;
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+/// Process names of compositors and display servers, with a display name.
+const COMPOSITORS: &[(&str, &str)] = &[
+    ("cosmic-comp", "COSMIC"),
+    ("kwin_wayland", "KWin (Wayland)"),
+    ("kwin_x11", "KWin (X11)"),
+    ("gnome-shell", "GNOME Shell"),
+    ("sway", "Sway"),
+    ("Hyprland", "Hyprland"),
+    ("niri", "niri"),
+    ("river", "river"),
+    ("labwc", "labwc"),
+    ("wayfire", "Wayfire"),
+    ("weston", "Weston"),
+    ("Xorg", "X.Org"),
+];
+
+/// Compositors that print their version without side effects, with the flag to ask.
+const VERSIONED: &[(&str, &str)] = &[
+    ("sway", "--version"),
+    ("Hyprland", "--version"),
+    ("niri", "--version"),
+    ("labwc", "--version"),
+    ("wayfire", "--version"),
+    ("weston", "--version"),
+    ("Xorg", "-version"),
+];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Session {
+    pub desktop: Option<String>,
+    /// `wayland`, `x11` or `tty`.
+    pub session_type: Option<String>,
+    pub session_id: Option<String>,
+    pub compositor: Option<String>,
+    pub compositor_version: Option<String>,
+    /// Whether Xwayland is running for X11 applications.
+    pub xwayland: bool,
+    pub wayland_display: Option<String>,
+    pub x_display: Option<String>,
+    pub locale: Option<String>,
+    pub runtime_dir: Option<String>,
+}
+
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// Names of all running processes this user may see.
+fn process_names() -> Vec<String> {
+    fs::read_dir(host::path("/proc"))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().bytes().all(|byte| byte.is_ascii_digit()))
+        .filter_map(|entry| fs::read_to_string(entry.path().join("comm")).ok())
+        .map(|name| name.trim().to_string())
+        .collect()
+}
+
+fn version(program: &str, flag: &str) -> Option<String> {
+    let output = host::output(host::command(program).arg(flag)).ok()?;
+    // Xorg prints its version on stderr.
+    let text = if output.stdout.is_empty() { output.stderr } else { output.stdout };
+    String::from_utf8_lossy(&text)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+pub fn collect() -> Session {
+    let processes = process_names();
+    let compositor = COMPOSITORS
+        .iter()
+        .find(|(process, _)| processes.iter().any(|name| name == process));
+
+    Session {
+        desktop: var("XDG_CURRENT_DESKTOP").or_else(|| var("DESKTOP_SESSION")),
+        session_type: var("XDG_SESSION_TYPE"),
+        session_id: var("XDG_SESSION_ID"),
+        compositor: compositor.map(|(_, name)| name.to_string()),
+        compositor_version: compositor.and_then(|(process, _)| {
+            let (program, flag) = VERSIONED.iter().find(|(program, _)| program == process)?;
+            version(program, flag)
+        }),
+        xwayland: processes.iter().any(|name| name == "Xwayland"),
+        wayland_display: var("WAYLAND_DISPLAY"),
+        x_display: var("DISPLAY"),
+        locale: var("LC_ALL").or_else(|| var("LANG")),
+        runtime_dir: var("XDG_RUNTIME_DIR"),
+    }
+}
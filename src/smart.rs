@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads disk health from `smartctl`, or from `nvme smart-log` when smartctl
+//! is missing, through the privileged helper.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, privileged};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Health {
+    /// The drive's overall self-assessment, if it reports one.
+    pub passed: Option<bool>,
+    /// Attributes worth showing, in the order the tool printed them.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// ATA attributes that predict failure or describe wear.
+const ATA_ATTRIBUTES: &[&str] = &[
+    "Reallocated_Sector_Ct",
+    "Power_On_Hours",
+    "Power_Cycle_Count",
+    "Wear_Leveling_Count",
+    "Media_Wearout_Indicator",
+    "Reported_Uncorrect",
+    "Temperature_Celsius",
+    "Current_Pending_Sector",
+    "Offline_Uncorrectable",
+    "UDMA_CRC_Error_Count",
+];
+
+/// Parses `smartctl --all` for ATA, SCSI and NVMe drives.
+fn parse_smartctl(output: &str) -> Health {
+    let mut health = Health::default();
+    let mut in_nvme_log = false;
+
+    for line in output.lines() {
+        if let Some(result) = line
+            .strip_prefix("SMART overall-health self-assessment test result:")
+            .or_else(|| line.strip_prefix("SMART Health Status:"))
+        {
+            let result = result.trim();
+            health.passed = Some(result == "PASSED" || result == "OK");
+        } else if line.starts_with("SMART/Health Information") {
+            in_nvme_log = true;
+        } else if in_nvme_log {
+            match line.split_once(':') {
+                Some((key, value)) => {
+                    health.attributes.push((key.trim().to_string(), value.trim().to_string()));
+                }
+                None => in_nvme_log = false,
+            }
+        } else {
+            // `  5 Reallocated_Sector_Ct   0x0033   100   100   010    Pre-fail  Always       -       0`
+            let columns = line.split_whitespace().collect::<Vec<_>>();
+            if columns.len() >= 10
+                && columns[0].parse::<u8>().is_ok()
+                && ATA_ATTRIBUTES.contains(&columns[1])
+            {
+                health.attributes.push((columns[1].replace('_', " "), columns[9..].join(" ")));
+            }
+        }
+    }
+    health
+}
+
+/// Parses `nvme smart-log`, whose fields look like `critical_warning : 0`.
+fn parse_nvme(output: &str) -> Health {
+    let mut health = Health::default();
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(" : ").or_else(|| line.split_once(':')) else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if key == "critical_warning" {
+            health.passed = Some(value == "0" || value == "0x0");
+        }
+        if !key.is_empty() && !value.is_empty() {
+            health.attributes.push((key.replace('_', " "), value.to_string()));
+        }
+    }
+    health
+}
+
+/// Reads the health of the disk `name`, e.g. `sda` or `nvme0n1`.
+pub fn collect(name: &str) -> Result<Health, host::Error> {
+    let device = format!("/dev/{name}");
+    match privileged::run(&["smart", &device]) {
+        Ok(output) => Ok(parse_smartctl(&output)),
+        // A refused prompt or a timeout applies to nvme-cli as much as to
+        // smartctl, while plain failures of both are worth reporting together.
+        Err(e) if name.starts_with("nvme") => privileged::run(&["nvme", &device])
+            .map(|output| parse_nvme(&output))
+            .map_err(|nvme_error| match nvme_error {
+                host::Error::Failed(message) => host::Error::Failed(format!("{e}; {message}")),
+                nvme_error => nvme_error,
+            }),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ATA: &str = "smartctl 7.4 2023-08-01 r5530 [x86_64-linux-6.9.7] (local build)
+
+=== START OF READ SMART DATA SECTION ===
+SMART overall-health self-assessment test result: PASSED
+
+ID# ATTRIBUTE_NAME          FLAG     VALUE WORST THRESH TYPE      UPDATED  WHEN_FAILED RAW_VALUE
+  5 Reallocated_Sector_Ct   0x0033   100   100   010    Pre-fail  Always       -       0
+  9 Power_On_Hours          0x0032   095   095   000    Old_age   Always       -       21843
+ 12 Power_Cycle_Count       0x0032   099   099   000    Old_age   Always       -       1187
+190 Airflow_Temperature_Cel 0x0032   067   051   000    Old_age   Always       -       33
+194 Temperature_Celsius     0x0022   033   049   000    Old_age   Always       -       33 (Min/Max 14/49)
+";
+
+    const NVME: &str = "=== START OF SMART DATA SECTION ===
+SMART overall-health self-assessment test result: FAILED!
+
+SMART/Health Information (NVMe Log 0x02)
+Critical Warning:                   0x04
+Temperature:                        38 Celsius
+Percentage Used:                    3%
+
+Error Information (NVMe Log 0x01, 16 of 64 entries)
+No Errors Logged
+";
+
+    const NVME_CLI: &str = "Smart Log for NVME device:nvme0n1 namespace-id:ffffffff
+critical_warning                        : 0
+temperature                             : 38 °C (311 K)
+available_spare                         : 100%
+percentage_used                         : 3%
+";
+
+    #[test]
+    fn parses_ata_attributes() {
+        let health = parse_smartctl(ATA);
+        assert_eq!(health.passed, Some(true));
+        let names = health.attributes.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["Reallocated Sector Ct", "Power On Hours", "Power Cycle Count", "Temperature Celsius"]);
+        assert_eq!(health.attributes[3].1, "33 (Min/Max 14/49)");
+    }
+
+    #[test]
+    fn parses_nvme_log_from_smartctl() {
+        let health = parse_smartctl(NVME);
+        assert_eq!(health.passed, Some(false));
+        assert_eq!(
+            health.attributes,
+            [
+                ("Critical Warning".to_string(), "0x04".to_string()),
+                ("Temperature".to_string(), "38 Celsius".to_string()),
+                ("Percentage Used".to_string(), "3%".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_nvme_cli() {
+        let health = parse_nvme(NVME_CLI);
+        assert_eq!(health.passed, Some(true));
+        assert_eq!(health.attributes[1], ("critical warning".to_string(), "0".to_string()));
+        assert_eq!(health.attributes[2], ("temperature".to_string(), "38 °C (311 K)".to_string()));
+        assert_eq!(parse_nvme("critical_warning : 0x1").passed, Some(false));
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        for health in [parse_smartctl(""), parse_nvme("")] {
+            assert_eq!(health.passed, None);
+            assert!(health.attributes.is_empty());
+        }
+
+        // Too few columns, or an ID that is not a number.
+        let health = parse_smartctl("  5 Reallocated_Sector_Ct 0x0033\nxx Power_On_Hours 0x0032 095 095 000 Old_age Always - 21843\n");
+        assert!(health.attributes.is_empty());
+
+        let health = parse_nvme("no separator\n : value\nkey :\n");
+        assert_eq!(health.passed, None);
+        assert!(health.attributes.is_empty());
+    }
+}
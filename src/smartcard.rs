@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Lists smart-card readers through pcscd, falling back to USB CCID devices.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, usb_pm};
+
+const USB_DEVICES_DIR: &str = "/sys/bus/usb/devices";
+
+/// The USB interface class of CCID smart-card readers.
+const CLASS_SMART_CARD: u8 = 0x0b;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Reader {
+    pub name: String,
+    /// Unknown when pcscd could not be asked.
+    pub card_present: Option<bool>,
+}
+
+/// Parses `opensc-tool --list-readers`, whose name column is aligned with the
+/// `Name` header and may contain spaces.
+fn parse_readers(output: &str) -> Option<Vec<Reader>> {
+    let mut lines = output.lines().skip_while(|line| !line.starts_with("Nr."));
+    let name_column = lines.next()?.find("Name")?;
+
+    let readers = lines
+        .filter_map(|line| {
+            let card = line.split_whitespace().nth(1)?;
+            Some(Reader {
+                name: line.get(name_column..)?.trim().to_string(),
+                card_present: Some(card == "Yes"),
+            })
+        })
+        .collect();
+
+    Some(readers)
+}
+
+/// USB devices with a smart-card interface, for when pcscd is not running.
+fn usb_readers() -> Vec<Reader> {
+    fs::read_dir(host::path(USB_DEVICES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| usb_pm::interface_classes(path).contains(&CLASS_SMART_CARD))
+        .map(|path| {
+            let read = |name: &str| {
+                fs::read_to_string(path.join(name))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+            };
+            let name = [read("manufacturer"), read("product")]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            Reader {
+                name,
+                card_present: None,
+            }
+        })
+        .collect()
+}
+
+/// Collects smart-card readers.
+pub fn collect() -> Vec<Reader> {
+    host::output(host::command("opensc-tool").arg("--list-readers"))
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_readers(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_else(usb_readers)
+}
@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Snapshots hold every value collected from a system in one versioned JSON
+//! file, so that a technician can capture a machine and review it later on
+//! another computer, where the app shows it read-only.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use cosmic::dialog::file_chooser;
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::config::SnapshotSchedule;
+use crate::pages::Sections;
+use crate::{export, fl, host};
+
+/// The version of the file format, raised whenever a change to the data
+/// stops older snapshots from loading.
+pub const VERSION: u32 = 2;
+
+/// How many recorded snapshots the history keeps.
+const HISTORY_LENGTH: usize = 90;
+
+/// The data of every page, as held by the app. Pages whose data had not
+/// been collected when the snapshot was taken have no section, and data
+/// collected on request, such as the privileged reads the user did not ask
+/// for, stays `None` within its page's section.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Snapshot {
+    pub version: u32,
+    /// When the snapshot was taken, in UTC.
+    pub taken: String,
+    /// The ids of the pages that applied to the system.
+    pub pages: Vec<String>,
+    /// The data of each page by id, or why collecting it failed.
+    pub sections: Sections,
+}
+
+/// The suggested name of a snapshot file.
+pub fn file_name() -> String {
+    "examine-snapshot.json".to_string()
+}
+
+/// Asks for a snapshot file through the file chooser portal and reads it.
+/// Returns its file name and contents, or `None` when the user cancelled.
+pub async fn open() -> Result<Option<(String, Box<Snapshot>)>, host::Error> {
+    let response = file_chooser::open::Dialog::new()
+        .title(fl!("open-snapshot"))
+        .filter(file_chooser::FileFilter::new("JSON").glob("*.json"))
+        .open_file()
+        .await;
+    let path = match response {
+        Ok(response) => match response.url().to_file_path() {
+            Ok(path) => path,
+            Err(()) => return Ok(None),
+        },
+        Err(file_chooser::Error::Cancelled) => return Ok(None),
+        Err(e) => return Err(host::Error::Failed(e.to_string())),
+    };
+    read(path).await.map(Some)
+}
+
+/// Reads the snapshot at `path`, returning its file name and contents.
+pub async fn read(path: PathBuf) -> Result<(String, Box<Snapshot>), host::Error> {
+    let contents = tokio::fs::read(&path).await.map_err(|e| host::read_error(&path, &e))?;
+    let invalid = |e: serde_json::Error| host::Error::Failed(format!("{}: {e}", path.display()));
+
+    // Checks the version first, so that a newer snapshot fails with a clear
+    // message rather than whichever field changed.
+    let version = serde_json::from_slice::<serde_json::Value>(&contents).map_err(invalid)?["version"].as_u64();
+    if version != Some(u64::from(VERSION)) {
+        return Err(host::Error::Failed(fl!(
+            "snapshot-unsupported",
+            path = path.display().to_string(),
+            version = version.map_or_else(|| "?".to_string(), |version| version.to_string())
+        )));
+    }
+    let snapshot = serde_json::from_slice(&contents).map_err(invalid)?;
+
+    let name = path
+        .file_name()
+        .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+    Ok((name, Box::new(snapshot)))
+}
+
+/// A snapshot recorded in the history.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub path: PathBuf,
+    /// When it was taken, in UTC, e.g. `2024-05-01 14:03:09`.
+    pub taken: String,
+}
+
+/// Where recorded snapshots are kept, in the app's data directory.
+fn history_dir() -> Option<PathBuf> {
+    let data = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))?;
+    Some(data.join("io.github.cosmic_utils.Examine").join("snapshots"))
+}
+
+/// The recorded snapshots, newest first. Their file names hold the time
+/// they were taken, so that listing them does not read every file.
+pub fn history() -> Vec<Entry> {
+    let Some(dir) = history_dir() else {
+        return Vec::new();
+    };
+    let mut entries = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let stamp = name.strip_prefix("snapshot-")?.strip_suffix(".json")?;
+            let (date, time) = stamp.split_once('_')?;
+            Some(Entry {
+                path: entry.path(),
+                taken: format!("{date} {}", time.replace('-', ":")),
+            })
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| b.taken.cmp(&a.taken));
+    entries
+}
+
+/// Whether `schedule` asks for a snapshot now, given the newest one recorded.
+pub fn is_due(schedule: SnapshotSchedule, history: &[Entry]) -> bool {
+    match schedule {
+        SnapshotSchedule::Off => false,
+        SnapshotSchedule::Launch => true,
+        SnapshotSchedule::Daily => {
+            let today = export::timestamp();
+            !history.first().is_some_and(|newest| newest.taken.get(..10) == today.get(..10))
+        }
+    }
+}
+
+/// Adds `snapshot` to the history, dropping the oldest snapshots beyond
+/// [`HISTORY_LENGTH`].
+pub fn record(snapshot: &Snapshot) -> Result<PathBuf, String> {
+    let dir = history_dir().ok_or_else(|| "no data directory".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    // `2024-05-01 14:03:09 UTC` becomes `snapshot-2024-05-01_14-03-09.json`.
+    let stamp = snapshot.taken.trim_end_matches(" UTC").replacen(' ', "_", 1).replace(':', "-");
+    let path = dir.join(format!("snapshot-{stamp}.json"));
+    let contents = serde_json::to_string(snapshot).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    for entry in history().iter().skip(HISTORY_LENGTH) {
+        if let Err(e) = fs::remove_file(&entry.path) {
+            warn!("cannot remove old snapshot {}: {}", entry.path.display(), e);
+        }
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::{usb, Page};
+    use serde_json::{json, Value};
+
+    fn saved(version: u32, sections: Value) -> Value {
+        json!({ "version": version, "taken": "2026-10-16 12:00:00 UTC", "pages": ["usb", "tpm"], "sections": sections })
+    }
+
+    /// Writes `value` to a file of its own and reads it back as a snapshot.
+    async fn read_back(name: &str, value: &Value) -> Result<(String, Box<Snapshot>), host::Error> {
+        let path = env::temp_dir().join(format!("examine-snapshot-{}-{name}.json", std::process::id()));
+        fs::write(&path, value.to_string()).unwrap();
+        let result = read(path.clone()).await;
+        fs::remove_file(&path).unwrap();
+        result
+    }
+
+    #[tokio::test]
+    async fn reads_back_saved_snapshot() {
+        let snapshot: Snapshot = serde_json::from_value(saved(
+            VERSION,
+            json!({
+                "usb": { "Ok": {
+                    "devices": [{ "port": "1-2", "bus": 1, "dev": 3, "product": "Cruzer Blade", "interfaces": [] }],
+                    "power": [],
+                } },
+                "tpm": { "Err": { "Missing": "tpm2_getcap" } },
+            }),
+        ))
+        .unwrap();
+        let value = serde_json::to_value(&snapshot).unwrap();
+
+        let (name, restored) = read_back("saved", &value).await.unwrap();
+        assert!(name.ends_with("-saved.json"));
+        assert_eq!(serde_json::to_value(&*restored).unwrap(), value);
+        let devices = &restored.sections.get::<usb::Provider>().unwrap().devices;
+        assert_eq!(devices[0].product.as_deref(), Some("Cruzer Blade"));
+        assert!(matches!(
+            restored.sections.result(Page::Tpm),
+            Some(Err(host::Error::Missing(tool))) if tool == "tpm2_getcap"
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_page() {
+        let result = read_back("unknown", &saved(VERSION, json!({ "teleporter": { "Ok": {} } }))).await;
+        assert!(matches!(result, Err(host::Error::Failed(e)) if e.contains("unknown page teleporter")));
+    }
+
+    #[tokio::test]
+    async fn rejects_other_version() {
+        let path = env::temp_dir().join(format!("examine-snapshot-{}-version.json", std::process::id()));
+        let result = read_back("version", &saved(VERSION + 1, json!({}))).await;
+        let message = fl!(
+            "snapshot-unsupported",
+            path = path.display().to_string(),
+            version = (VERSION + 1).to_string()
+        );
+        assert!(matches!(result, Err(host::Error::Failed(e)) if e == message));
+    }
+}
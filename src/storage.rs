@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads mounted filesystems from `/proc/self/mounts` and the partition layout
+//! of each disk from `/sys/block`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use nix::sys::statvfs::statvfs;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ByteUnits;
+use crate::host;
+
+const SYS_BLOCK_DIR: &str = "/sys/block";
+const UDEV_DATA_DIR: &str = "/run/udev/data";
+/// The kernel always reports block device sizes in 512 byte sectors.
+const SECTOR_SIZE: u64 = 512;
+
+/// Whether [`format_bytes`] counts in powers of 1000 rather than 1024.
+static DECIMAL_UNITS: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Mount {
+    pub source: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub options: String,
+    pub total: u64,
+    pub free: u64,
+    /// Space available to unprivileged users, excluding reserved blocks.
+    pub available: u64,
+}
+
+impl Disk {
+    /// Whether a partition is aligned to the physical block size of the disk.
+    pub fn is_aligned(&self, partition: &Partition) -> bool {
+        partition.start % self.physical_block_size.max(SECTOR_SIZE) == 0
+    }
+
+    /// Whether a partition follows the 1 MiB alignment convention.
+    pub fn is_optimally_aligned(&self, partition: &Partition) -> bool {
+        partition.start % RECOMMENDED_ALIGNMENT == 0
+    }
+}
+
+impl Mount {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+
+    /// Whether the filesystem issues discards continuously (`discard` or `discard=async`).
+    pub fn continuous_discard(&self) -> bool {
+        self.options
+            .split(',')
+            .any(|option| option == "discard" || option.starts_with("discard="))
+    }
+}
+
+/// Well known GPT partition type GUIDs.
+const GPT_TYPES: &[(&str, &str)] = &[
+    ("c12a7328-f81f-11d2-ba4b-00a0c93ec93b", "EFI System"),
+    ("21686148-6449-6e6f-744e-656564454649", "BIOS Boot"),
+    ("0fc63daf-8483-4772-8e79-3d69d8477de4", "Linux Filesystem"),
+    ("4f68bce3-e8cd-4db1-96e7-fbcaf984b709", "Linux Root (x86-64)"),
+    ("b921b045-1df0-41c3-af44-4c6f280d3fae", "Linux Root (ARM64)"),
+    ("933ac7e1-2eb4-4f13-b844-0e14e2aef915", "Linux Home"),
+    ("0657fd6d-a4ab-43c4-84e5-0933c84b4f4f", "Linux Swap"),
+    ("e6d6d379-f507-44c2-a23c-238f2a3df928", "Linux LVM"),
+    ("a19d880f-05fc-4d3b-a006-743f0f84911e", "Linux RAID"),
+    ("ca7d7ccb-63ed-4c53-861c-1742536059cc", "Linux LUKS"),
+    ("bc13c2ff-59e6-4262-a352-b275fd6f7172", "Linux Extended Boot"),
+    ("ebd0a0a2-b9e5-4433-87c0-68b6b72699c7", "Microsoft Basic Data"),
+    ("e3c9e316-0b5c-4db8-817d-f92df00215ae", "Microsoft Reserved"),
+    ("de94bba4-06d1-4d40-a16a-bfd50179d6ac", "Windows Recovery"),
+    ("48465300-0000-11aa-aa11-00306543ecac", "Apple HFS+"),
+    ("7c3457ef-0000-11aa-aa11-00306543ecac", "Apple APFS"),
+];
+
+/// Well known MBR partition type IDs.
+const MBR_TYPES: &[(&str, &str)] = &[
+    ("0x7", "NTFS / exFAT"),
+    ("0xb", "FAT32"),
+    ("0xc", "FAT32 (LBA)"),
+    ("0xef", "EFI System"),
+    ("0x82", "Linux Swap"),
+    ("0x83", "Linux"),
+    ("0x8e", "Linux LVM"),
+    ("0xfd", "Linux RAID"),
+    ("0x5", "Extended"),
+    ("0xf", "Extended (LBA)"),
+];
+
+/// Partitions should start on a multiple of this many bytes, the common 1 MiB
+/// boundary used by modern partitioning tools.
+const RECOMMENDED_ALIGNMENT: u64 = 1024 * 1024;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Partition {
+    pub name: String,
+    pub start: u64,
+    pub size: u64,
+    pub label: Option<String>,
+    /// GPT type GUID or MBR type ID.
+    pub type_id: Option<String>,
+    pub fs_type: Option<String>,
+}
+
+impl Partition {
+    /// A human readable name for [`Self::type_id`], if it is a known type.
+    pub fn type_name(&self) -> Option<&'static str> {
+        let type_id = self.type_id.as_deref()?.to_lowercase();
+        GPT_TYPES
+            .iter()
+            .chain(MBR_TYPES)
+            .find(|(id, _)| *id == type_id)
+            .map(|(_, name)| *name)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Disk {
+    pub name: String,
+    pub model: Option<String>,
+    pub size: u64,
+    pub rotational: bool,
+    pub removable: bool,
+    /// Whether the device accepts discard (TRIM/UNMAP) requests.
+    pub discard: bool,
+    /// `gpt` or `dos`, as detected by udev's blkid builtin.
+    pub partition_table: Option<String>,
+    pub physical_block_size: u64,
+    pub partitions: Vec<Partition>,
+}
+
+/// State of the periodic `fstrim.timer` systemd unit.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TrimTimer {
+    /// `None` when systemd could not be queried.
+    pub enabled: Option<bool>,
+    pub last_run: Option<String>,
+    pub next_run: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Storage {
+    pub mounts: Vec<Mount>,
+    pub disks: Vec<Disk>,
+    pub trim_timer: TrimTimer,
+    pub raid: Vec<crate::mdraid::Array>,
+    pub lvm: Result<crate::lvm::Lvm, String>,
+    pub encryption: Vec<crate::luks::Container>,
+}
+
+/// `/proc/self/mounts` escapes spaces and other special characters as octal.
+fn unescape(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let octal = chars.by_ref().take(3).collect::<String>();
+            if let Ok(byte) = u8::from_str_radix(&octal, 8) {
+                result.push(char::from(byte));
+                continue;
+            }
+            result.push(c);
+            result.push_str(&octal);
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn read_number(path: impl AsRef<Path>) -> Option<u64> {
+    read_trimmed(path)?.parse().ok()
+}
+
+/// Reads the `E:` properties of a block device from the udev database.
+fn udev_properties(device: &Path) -> HashMap<String, String> {
+    let Some(dev) = read_trimmed(device.join("dev")) else {
+        return HashMap::new();
+    };
+
+    fs::read_to_string(host::path(UDEV_DATA_DIR).join(format!("b{dev}")))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.strip_prefix("E:")?.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn mounts() -> io::Result<Vec<Mount>> {
+    let contents = fs::read_to_string(host::path("/proc/self/mounts"))?;
+    let mut mounts = contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let source = unescape(fields.next()?);
+            let mount_point = unescape(fields.next()?);
+            let fs_type = fields.next()?.to_string();
+            let options = fields.next()?.to_string();
+
+            // Only list filesystems backed by a block device, skipping
+            // pseudo filesystems such as proc, sysfs and tmpfs.
+            if !source.starts_with("/dev/") {
+                return None;
+            }
+
+            let stat = statvfs(mount_point.as_str()).ok()?;
+            let fragment = stat.fragment_size() as u64;
+            Some(Mount {
+                total: stat.blocks() as u64 * fragment,
+                free: stat.blocks_free() as u64 * fragment,
+                available: stat.blocks_available() as u64 * fragment,
+                source,
+                mount_point,
+                fs_type,
+                options,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Bind mounts show up once per mount point; keep the shortest one.
+    mounts.sort_by(|a, b| (&a.source, a.mount_point.len()).cmp(&(&b.source, b.mount_point.len())));
+    mounts.dedup_by(|a, b| a.source == b.source && a.fs_type == b.fs_type && a.fs_type != "btrfs");
+    mounts.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    Ok(mounts)
+}
+
+fn disks() -> Vec<Disk> {
+    let mut disks = fs::read_dir(host::path(SYS_BLOCK_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // Skip RAM disks, loop and zram devices.
+            if ["ram", "loop", "zram"].iter().any(|prefix| name.starts_with(prefix)) {
+                return None;
+            }
+            let path = entry.path();
+            let size = read_number(path.join("size"))? * SECTOR_SIZE;
+            if size == 0 {
+                return None;
+            }
+
+            let mut partitions = fs::read_dir(&path)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .filter(|child| child.path().join("partition").exists())
+                .filter_map(|child| {
+                    let mut properties = udev_properties(&child.path());
+                    Some(Partition {
+                        name: child.file_name().to_string_lossy().into_owned(),
+                        start: read_number(child.path().join("start"))? * SECTOR_SIZE,
+                        size: read_number(child.path().join("size"))? * SECTOR_SIZE,
+                        label: properties.remove("ID_PART_ENTRY_NAME"),
+                        type_id: properties.remove("ID_PART_ENTRY_TYPE"),
+                        fs_type: properties.remove("ID_FS_TYPE"),
+                    })
+                })
+                .collect::<Vec<_>>();
+            partitions.sort_by_key(|partition| partition.start);
+
+            Some(Disk {
+                model: read_trimmed(path.join("device/model")),
+                rotational: read_trimmed(path.join("queue/rotational")).as_deref() == Some("1"),
+                removable: read_trimmed(path.join("removable")).as_deref() == Some("1"),
+                discard: read_number(path.join("queue/discard_max_bytes")).is_some_and(|max| max > 0),
+                partition_table: udev_properties(&path).remove("ID_PART_TABLE_TYPE"),
+                physical_block_size: read_number(path.join("queue/physical_block_size")).unwrap_or(SECTOR_SIZE),
+                name,
+                size,
+                partitions,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    disks.sort_by(|a, b| a.name.cmp(&b.name));
+    disks
+}
+
+fn trim_timer() -> TrimTimer {
+    let systemctl = |args: &[&str]| {
+        host::output(host::command("systemctl").args(args))
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|value| !value.is_empty() && value != "n/a")
+    };
+
+    TrimTimer {
+        enabled: systemctl(&["is-enabled", "fstrim.timer"]).map(|state| state == "enabled"),
+        last_run: systemctl(&["show", "fstrim.timer", "--property=LastTriggerUSec", "--value"]),
+        next_run: systemctl(&["show", "fstrim.timer", "--property=NextElapseUSecRealtime", "--value"]),
+    }
+}
+
+/// Formats a size in bytes using binary units.
+/// Sets the units [`format_bytes`] uses.
+pub fn set_units(units: ByteUnits) {
+    DECIMAL_UNITS.store(units == ByteUnits::Decimal, Ordering::Relaxed);
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    let (base, units): (f64, &[&str]) = if DECIMAL_UNITS.load(Ordering::Relaxed) {
+        (1000.0, &["B", "kB", "MB", "GB", "TB", "PB"])
+    } else {
+        (1024.0, &["B", "KiB", "MiB", "GiB", "TiB", "PiB"])
+    };
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= base && unit < units.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", units[unit])
+    }
+}
+
+pub fn collect() -> Result<Storage, host::Error> {
+    let disks = disks();
+    Ok(Storage {
+        mounts: mounts().map_err(|e| host::read_error("/proc/self/mounts", &e))?,
+        encryption: crate::luks::collect(&disks),
+        disks,
+        trim_timer: trim_timer(),
+        raid: crate::mdraid::collect(),
+        lvm: crate::lvm::collect(),
+    })
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads ACPI and platform thermal zones and cooling devices from `/sys/class/thermal`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const THERMAL_DIR: &str = "/sys/class/thermal";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TripPoint {
+    /// `active`, `passive`, `hot` or `critical`.
+    pub kind: String,
+    /// In degrees Celsius.
+    pub temperature: f32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CoolingDevice {
+    pub name: String,
+    /// E.g. `Fan`, `Processor` or `intel_powerclamp`.
+    pub kind: String,
+    pub current_state: u32,
+    pub max_state: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Zone {
+    pub name: String,
+    /// E.g. `acpitz`, `x86_pkg_temp` or `cpu-thermal`.
+    pub kind: String,
+    /// In degrees Celsius.
+    pub temperature: Option<f32>,
+    pub trip_points: Vec<TripPoint>,
+    /// Cooling devices bound to this zone.
+    pub cooling_devices: Vec<CoolingDevice>,
+}
+
+impl Zone {
+    /// The temperature at which the system shuts down to protect the hardware.
+    pub fn critical(&self) -> Option<f32> {
+        self.trip_points
+            .iter()
+            .find(|trip| trip.kind == "critical")
+            .map(|trip| trip.temperature)
+    }
+
+    /// The lowest temperature at which the zone starts throttling.
+    pub fn passive(&self) -> Option<f32> {
+        self.trip_points
+            .iter()
+            .filter(|trip| trip.kind == "passive")
+            .map(|trip| trip.temperature)
+            .reduce(f32::min)
+    }
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+/// Reads a millidegree Celsius attribute.
+fn read_celsius(path: impl AsRef<Path>) -> Option<f32> {
+    read_trimmed(path)?.parse::<i64>().ok().map(|millidegrees| millidegrees as f32 / 1000.0)
+}
+
+fn cooling_device(path: &Path) -> Option<CoolingDevice> {
+    Some(CoolingDevice {
+        name: path.file_name()?.to_string_lossy().into_owned(),
+        kind: read_trimmed(path.join("type"))?,
+        current_state: read_trimmed(path.join("cur_state"))?.parse().ok()?,
+        max_state: read_trimmed(path.join("max_state"))?.parse().ok()?,
+    })
+}
+
+fn entries(dir: &Path, prefix: &str) -> Vec<std::path::PathBuf> {
+    let mut entries = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.strip_prefix(prefix)
+                .is_some_and(|index| index.bytes().all(|byte| byte.is_ascii_digit()))
+        })
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    // Sort numerically so that zone10 follows zone9.
+    entries.sort_by_key(|path| {
+        path.file_name()
+            .and_then(|name| name.to_string_lossy().strip_prefix(prefix)?.parse::<u32>().ok())
+    });
+    entries
+}
+
+fn zone(path: &Path) -> Option<Zone> {
+    let trip_points = (0..)
+        .map_while(|index| {
+            Some(TripPoint {
+                kind: read_trimmed(path.join(format!("trip_point_{index}_type")))?,
+                temperature: read_celsius(path.join(format!("trip_point_{index}_temp")))?,
+            })
+        })
+        .collect();
+
+    // Bound cooling devices appear as cdevN links to the device.
+    let cooling_devices = entries(path, "cdev")
+        .iter()
+        .filter_map(|link| fs::canonicalize(link).ok())
+        .filter_map(|device| cooling_device(&device))
+        .collect();
+
+    Some(Zone {
+        name: path.file_name()?.to_string_lossy().into_owned(),
+        kind: read_trimmed(path.join("type"))?,
+        temperature: read_celsius(path.join("temp")),
+        trip_points,
+        cooling_devices,
+    })
+}
+
+/// Collects all thermal zones and all cooling devices.
+pub fn collect() -> (Vec<Zone>, Vec<CoolingDevice>) {
+    let dir = host::path(THERMAL_DIR);
+    let zones = entries(&dir, "thermal_zone")
+        .iter()
+        .filter_map(|path| zone(path))
+        .collect();
+    let cooling_devices = entries(&dir, "cooling_device")
+        .iter()
+        .filter_map(|path| cooling_device(path))
+        .collect();
+    (zones, cooling_devices)
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads Thunderbolt / USB4 domains and devices from `/sys/bus/thunderbolt`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const THUNDERBOLT_DIR: &str = "/sys/bus/thunderbolt/devices";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Authorization {
+    Unauthorized,
+    Authorized,
+    /// Authorized using a secure key challenge.
+    SecureKey,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Device {
+    /// Route string such as `0-1`.
+    pub id: String,
+    pub name: Option<String>,
+    pub vendor: Option<String>,
+    pub authorization: Option<Authorization>,
+    pub generation: Option<String>,
+    pub rx_speed: Option<String>,
+    pub tx_speed: Option<String>,
+    /// The host router of a domain is listed as device `<domain>-0`.
+    pub is_host: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Domain {
+    pub name: String,
+    /// Security level: `none`, `user`, `secure`, `dponly`, `usbonly` or `nopcie`.
+    pub security: Option<String>,
+    pub iommu_dma_protection: Option<bool>,
+    pub devices: Vec<Device>,
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn read_device(path: &Path, id: String) -> Device {
+    let read = |file: &str| read_trimmed(path.join(file));
+    let speed = |prefix: &str| {
+        let speed = read(&format!("{prefix}_speed"))?;
+        Some(match read(&format!("{prefix}_lanes")) {
+            Some(lanes) => format!("{speed} × {lanes}"),
+            None => speed,
+        })
+    };
+
+    Device {
+        name: read("device_name"),
+        vendor: read("vendor_name"),
+        authorization: read("authorized").and_then(|value| match value.as_str() {
+            "0" => Some(Authorization::Unauthorized),
+            "1" => Some(Authorization::Authorized),
+            "2" => Some(Authorization::SecureKey),
+            _ => None,
+        }),
+        generation: read("generation"),
+        rx_speed: speed("rx"),
+        tx_speed: speed("tx"),
+        is_host: id.ends_with("-0"),
+        id,
+    }
+}
+
+pub fn collect() -> Vec<Domain> {
+    let entries = fs::read_dir(host::path(THUNDERBOLT_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+        .collect::<Vec<_>>();
+
+    let mut domains = entries
+        .iter()
+        .filter(|(name, _)| name.starts_with("domain"))
+        .map(|(name, path)| Domain {
+            name: name.clone(),
+            security: read_trimmed(path.join("security")),
+            iommu_dma_protection: read_trimmed(path.join("iommu_dma_protection")).map(|value| value == "1"),
+            devices: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    // Devices are named `<domain>-<route>`; XDomain links and retimers contain
+    // a `.` and are skipped.
+    for (name, path) in entries.iter().filter(|(name, _)| !name.starts_with("domain") && !name.contains('.')) {
+        let Some((domain, _)) = name.split_once('-') else {
+            continue;
+        };
+        if let Some(domain) = domains.iter_mut().find(|d| d.name == format!("domain{domain}")) {
+            domain.devices.push(read_device(path, name.clone()));
+        }
+    }
+
+    domains.sort_by(|a, b| a.name.cmp(&b.name));
+    for domain in &mut domains {
+        domain.devices.sort_by(|a, b| a.id.cmp(&b.id));
+    }
+    domains
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads the time zone and network time synchronization state from
+//! `timedatectl`, and server details from chrony or systemd-timesyncd.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Sync {
+    /// `chrony` or `systemd-timesyncd`.
+    pub daemon: String,
+    /// Configured NTP servers.
+    pub servers: Vec<String>,
+    /// The server the clock is currently synchronized to.
+    pub current_server: Option<String>,
+    pub stratum: Option<u32>,
+    /// Offset of the system clock from the server, e.g. `+1.234 ms`.
+    pub offset: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Time {
+    pub timezone: Option<String>,
+    pub ntp_enabled: Option<bool>,
+    pub synchronized: Option<bool>,
+    pub sync: Option<Sync>,
+}
+
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = host::output(host::command(program).args(args)).ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses `key=value` lines from `timedatectl show`.
+fn properties(output: &str) -> HashMap<&str, &str> {
+    output.lines().filter_map(|line| line.split_once('=')).collect()
+}
+
+/// Reads chrony's tracking data and sources in CSV form.
+fn chrony() -> Option<Sync> {
+    let tracking = run("chronyc", &["-c", "tracking"])?;
+    let sources = run("chronyc", &["-c", "sources"]).unwrap_or_default();
+    Some(parse_chrony(&tracking, &sources))
+}
+
+/// Parses `chronyc -c tracking` and `chronyc -c sources`.
+fn parse_chrony(tracking: &str, sources: &str) -> Sync {
+    let tracking = tracking.trim().split(',').collect::<Vec<_>>();
+    let servers = sources
+        .lines()
+        .filter_map(|line| line.split(',').nth(2).map(str::to_string))
+        .collect();
+
+    Sync {
+        daemon: "chrony".to_string(),
+        servers,
+        current_server: tracking.get(1).filter(|name| !name.is_empty()).map(|name| name.to_string()),
+        stratum: tracking.get(2).and_then(|stratum| stratum.parse().ok()),
+        offset: tracking
+            .get(4)
+            .and_then(|offset| offset.parse::<f64>().ok())
+            .map(|seconds| format!("{:+.3} ms", seconds * 1000.0)),
+    }
+}
+
+/// Reads systemd-timesyncd through `timedatectl`.
+fn timesyncd() -> Option<Sync> {
+    let show = run(
+        "timedatectl",
+        &[
+            "show-timesync",
+            "-p",
+            "SystemNTPServers",
+            "-p",
+            "LinkNTPServers",
+            "-p",
+            "FallbackNTPServers",
+        ],
+    )?;
+    let status = run("timedatectl", &["timesync-status"]).unwrap_or_default();
+    Some(parse_timesyncd(&show, &status))
+}
+
+/// Parses `timedatectl show-timesync` and `timedatectl timesync-status`.
+fn parse_timesyncd(show: &str, status: &str) -> Sync {
+    let show = properties(show);
+    let mut servers = ["LinkNTPServers", "SystemNTPServers"]
+        .iter()
+        .filter_map(|key| show.get(key))
+        .flat_map(|value| value.split_whitespace().map(str::to_string))
+        .collect::<Vec<_>>();
+    // The fallback servers are only used when nothing else is configured.
+    if servers.is_empty() {
+        servers = show
+            .get("FallbackNTPServers")
+            .map(|value| value.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+    }
+
+    let field = |name: &str| {
+        status.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == name).then(|| value.trim().to_string())
+        })
+    };
+
+    Sync {
+        daemon: "systemd-timesyncd".to_string(),
+        servers,
+        current_server: field("Server"),
+        stratum: field("Stratum").and_then(|stratum| stratum.parse().ok()),
+        offset: field("Offset"),
+    }
+}
+
+pub fn collect() -> Time {
+    let show = run("timedatectl", &["show"]).unwrap_or_default();
+    let show = properties(&show);
+    let flag = |key: &str| show.get(key).map(|value| *value == "yes");
+
+    Time {
+        timezone: show.get("Timezone").map(|value| value.to_string()),
+        ntp_enabled: flag("NTP"),
+        synchronized: flag("NTPSynchronized"),
+        sync: chrony().or_else(timesyncd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACKING: &str = "A29FC87B,ntp.example.org,3,1718000000.123456789,0.000012345,-0.000001,0.000002,-11.234,0.012,0.045,0.001,0.002,64.5,Normal\n";
+
+    const SOURCES: &str = "^,*,ntp.example.org,2,6,377,12,0.000012,0.000010,0.000300
+^,-,time.example.net,2,6,377,14,0.000410,0.000400,0.000500
+";
+
+    const SHOW_TIMESYNC: &str = "SystemNTPServers=
+LinkNTPServers=192.168.1.1
+FallbackNTPServers=0.fedora.pool.ntp.org 1.fedora.pool.ntp.org
+";
+
+    const STATUS: &str = "       Server: 192.168.1.1 (192.168.1.1)
+Poll interval: 34min 8s (min: 32s; max 34min 8s)
+         Leap: normal
+      Version: 4
+      Stratum: 2
+    Reference: C0A80101
+       Offset: -1.027ms
+";
+
+    #[test]
+    fn parses_properties() {
+        let show = properties("Timezone=Europe/Berlin\nNTP=yes\nNTPSynchronized=no\nLocalRTC=\n");
+        assert_eq!(show.get("Timezone"), Some(&"Europe/Berlin"));
+        assert_eq!(show.get("LocalRTC"), Some(&""));
+        assert!(properties("no equals sign").is_empty());
+    }
+
+    #[test]
+    fn parses_chrony() {
+        let sync = parse_chrony(TRACKING, SOURCES);
+        assert_eq!(sync.servers, ["ntp.example.org", "time.example.net"]);
+        assert_eq!(sync.current_server.as_deref(), Some("ntp.example.org"));
+        assert_eq!(sync.stratum, Some(3));
+        assert_eq!(sync.offset.as_deref(), Some("+0.012 ms"));
+    }
+
+    #[test]
+    fn parses_timesyncd() {
+        let sync = parse_timesyncd(SHOW_TIMESYNC, STATUS);
+        assert_eq!(sync.servers, ["192.168.1.1"]);
+        assert_eq!(sync.current_server.as_deref(), Some("192.168.1.1 (192.168.1.1)"));
+        assert_eq!(sync.stratum, Some(2));
+        assert_eq!(sync.offset.as_deref(), Some("-1.027ms"));
+
+        let sync = parse_timesyncd("LinkNTPServers=\nFallbackNTPServers=a.pool b.pool\n", "");
+        assert_eq!(sync.servers, ["a.pool", "b.pool"]);
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        let sync = parse_chrony("", "");
+        assert!(sync.servers.is_empty());
+        assert_eq!((sync.current_server, sync.stratum, sync.offset), (None, None, None));
+
+        let sync = parse_chrony("A29FC87B,,x,0,soon\n", "no commas\n");
+        assert!(sync.servers.is_empty());
+        assert_eq!((sync.current_server, sync.stratum, sync.offset), (None, None, None));
+
+        let sync = parse_timesyncd("garbage", "Stratum: high\nServer");
+        assert!(sync.servers.is_empty());
+        assert_eq!((sync.current_server, sync.stratum), (None, None));
+    }
+}
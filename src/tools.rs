@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Probes which external tools are installed on the host, so that pages can
+//! leave out what a missing tool would provide instead of failing.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use itertools::Itertools;
+use log::{info, warn};
+
+use crate::host;
+use crate::pages::{self, Page};
+
+/// The tools the app runs other than those of a page's
+/// [`DataProvider::tools`](crate::pages::DataProvider::tools).
+const OTHER_TOOLS: &[&str] = &[
+    // Submits hardware probes from the File menu rather than showing a page.
+    "hw-probe",
+];
+
+/// Every tool the app runs, each once. The helper's tools are probed too,
+/// although they run as root, since they are installed on the same host.
+pub fn all() -> impl Iterator<Item = &'static str> {
+    pages::PAGES
+        .iter()
+        .flat_map(|provider| provider.tools())
+        .chain(OTHER_TOOLS)
+        .copied()
+        .unique()
+}
+
+/// The pages showing the output of `program`.
+pub fn used_by(program: &str) -> impl Iterator<Item = Page> + '_ {
+    pages::PAGES
+        .iter()
+        .filter(move |provider| provider.tools().contains(&program))
+        .map(|provider| provider.page())
+}
+
+/// The tools found installed, once [`detect`] has probed for them.
+static AVAILABLE: OnceLock<HashSet<&'static str>> = OnceLock::new();
+
+/// Checks every tool with a single shell on the host, since each command run
+/// through `flatpak-spawn` is slow. The sbin directories are searched as well
+/// because the helper finds its tools there.
+fn probe() -> HashSet<&'static str> {
+    // The sample data stands in for every tool.
+    if host::is_demo() {
+        return all().collect();
+    }
+
+    let script = r#"PATH="$PATH:/usr/sbin:/sbin"; for tool; do command -v "$tool" >/dev/null && echo "$tool"; done"#;
+    let output = host::output(
+        host::command("sh")
+            .args(["-c", script, "sh"])
+            .args(all()),
+    );
+
+    let found = match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_string)
+            .collect::<HashSet<_>>(),
+        Err(e) => {
+            // Without an answer, let each page try its tools and fail on its own.
+            warn!("cannot probe for tools: {}", e);
+            return all().collect();
+        }
+    };
+    let (available, missing): (HashSet<_>, Vec<_>) = all().partition(|program| found.contains(*program));
+    if !missing.is_empty() {
+        info!("missing tools: {}", missing.join(", "));
+    }
+    available
+}
+
+/// Probes for the tools, unless that has been done already. It runs a shell
+/// on the host, so the app does it in the background.
+pub fn detect() {
+    if AVAILABLE.get().is_none() {
+        _ = AVAILABLE.set(probe());
+    }
+}
+
+/// Whether [`detect`] has finished.
+pub fn is_detected() -> bool {
+    AVAILABLE.get().is_some()
+}
+
+/// Whether `program`, one of [`all`], is installed. Until [`detect`] has
+/// finished every tool counts as installed, and a page missing one fails on
+/// its own.
+pub fn is_available(program: &str) -> bool {
+    AVAILABLE.get().map_or(true, |available| available.contains(program))
+}
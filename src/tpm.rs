@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads the TPM from `/sys/class/tpm` and the IMA state from securityfs.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const TPM_DIR: &str = "/sys/class/tpm/tpm0";
+const IMA_DIR: &str = "/sys/kernel/security/ima";
+
+/// TPM vendor IDs of firmware TPMs running inside the CPU or chipset.
+const FIRMWARE_VENDORS: &[&str] = &["AMD", "INTC", "QCOM", "MSFT"];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Kind {
+    /// Implemented in CPU firmware, e.g. AMD fTPM or Intel PTT.
+    Firmware,
+    /// A separate chip on the board.
+    Discrete,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Tpm {
+    pub version: Option<String>,
+    /// The four character vendor ID, e.g. `IFX`, from `tpm2_getcap`.
+    pub manufacturer: Option<String>,
+    pub kind: Option<Kind>,
+    pub driver: Option<String>,
+    /// Hash algorithms of the active PCR banks, e.g. `sha256`.
+    pub pcr_banks: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Ima {
+    pub active: bool,
+    /// The policy requested with `ima_policy=` on the kernel command line.
+    pub policy: Option<String>,
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Reads `TPM2_PT_MANUFACTURER` with tpm2-tools, which needs access to `/dev/tpmrm0`.
+fn manufacturer() -> Option<String> {
+    let output = host::output(host::command("tpm2_getcap").arg("properties-fixed")).ok()?;
+    let output = String::from_utf8_lossy(&output.stdout);
+    let mut lines = output.lines().skip_while(|line| !line.starts_with("TPM2_PT_MANUFACTURER"));
+    lines.next()?;
+    lines
+        .find_map(|line| line.trim().strip_prefix("value:"))
+        .map(|value| value.trim().trim_matches('"').trim().to_string())
+}
+
+/// Collects the first TPM, if the system has one.
+pub fn collect() -> Option<Tpm> {
+    let path = host::path(TPM_DIR);
+    if !path.exists() {
+        return None;
+    }
+
+    let driver = fs::read_link(path.join("device/driver"))
+        .ok()
+        .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned()));
+    let manufacturer = manufacturer();
+    let kind = match (&manufacturer, driver.as_deref()) {
+        (Some(manufacturer), _) if FIRMWARE_VENDORS.contains(&manufacturer.as_str()) => Some(Kind::Firmware),
+        (Some(_), _) => Some(Kind::Discrete),
+        // Without the vendor, fall back on the interface: firmware TPMs use
+        // the command response buffer, most discrete chips use TIS.
+        (None, Some("tpm_crb")) => Some(Kind::Firmware),
+        (None, Some(driver)) if driver.starts_with("tpm_tis") => Some(Kind::Discrete),
+        (None, _) => None,
+    };
+
+    let mut pcr_banks = fs::read_dir(&path)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .strip_prefix("pcr-")
+                .map(str::to_string)
+        })
+        .collect::<Vec<_>>();
+    pcr_banks.sort();
+
+    Some(Tpm {
+        version: read_trimmed(path.join("tpm_version_major")),
+        manufacturer,
+        kind,
+        driver,
+        pcr_banks,
+    })
+}
+
+/// Reads whether the Integrity Measurement Architecture is running.
+pub fn ima() -> Ima {
+    let policy = read_trimmed(host::path("/proc/cmdline")).and_then(|cmdline| {
+        cmdline
+            .split_whitespace()
+            .find_map(|arg| arg.strip_prefix("ima_policy="))
+            .map(str::to_string)
+    });
+
+    Ima {
+        active: host::path(IMA_DIR).exists(),
+        policy,
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads the boot mode and Secure Boot state from sysfs and efivarfs.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const EFI_DIR: &str = "/sys/firmware/efi";
+const EFIVARS_DIR: &str = "/sys/firmware/efi/efivars";
+const DMI_DIR: &str = "/sys/class/dmi/id";
+
+/// Vendor GUID of the UEFI global variables.
+const GLOBAL_VARIABLE_GUID: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum BootMode {
+    Uefi,
+    Legacy,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Uefi {
+    pub boot_mode: BootMode,
+    /// `None` when the variable could not be read, e.g. on legacy boots.
+    pub secure_boot: Option<bool>,
+    pub setup_mode: Option<bool>,
+    /// Whether the firmware is 32 or 64 bit.
+    pub platform_size: Option<String>,
+    pub firmware_vendor: Option<String>,
+    pub firmware_version: Option<String>,
+    pub firmware_date: Option<String>,
+}
+
+/// Reads a boolean UEFI global variable. The first four bytes of an efivarfs
+/// file hold the variable attributes, followed by the one byte payload.
+fn global_variable(name: &str) -> Option<bool> {
+    let path = host::path(EFIVARS_DIR).join(format!("{name}-{GLOBAL_VARIABLE_GUID}"));
+    fs::read(path).ok()?.get(4).map(|value| *value == 1)
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+pub fn collect() -> Uefi {
+    let boot_mode = if host::path(EFI_DIR).exists() {
+        BootMode::Uefi
+    } else {
+        BootMode::Legacy
+    };
+
+    Uefi {
+        boot_mode,
+        secure_boot: global_variable("SecureBoot"),
+        setup_mode: global_variable("SetupMode"),
+        platform_size: read_trimmed(host::path(EFI_DIR).join("fw_platform_size")),
+        firmware_vendor: read_trimmed(host::path(DMI_DIR).join("bios_vendor")),
+        firmware_version: read_trimmed(host::path(DMI_DIR).join("bios_version")),
+        firmware_date: read_trimmed(host::path(DMI_DIR).join("bios_date")),
+    }
+}
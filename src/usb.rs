@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads USB device descriptors and interfaces from `/sys/bus/usb/devices`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, hwdata};
+
+pub const USB_DEVICES_DIR: &str = "/sys/bus/usb/devices";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Interface {
+    /// The sysfs name, `<device>:<config>.<interface>`.
+    pub name: String,
+    pub class: Option<u8>,
+    pub subclass: Option<u8>,
+    pub protocol: Option<u8>,
+    /// The interface string descriptor, if the device provides one.
+    pub description: Option<String>,
+    pub driver: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UsbDevice {
+    /// The sysfs name, which is the port path, e.g. `1-2.4`.
+    pub port: String,
+    pub bus: u16,
+    /// The device number on the bus, which changes on every reconnect.
+    pub dev: u16,
+    pub vendor_id: Option<String>,
+    pub product_id: Option<String>,
+    pub manufacturer: Option<String>,
+    pub product: Option<String>,
+    pub serial: Option<String>,
+    pub class: Option<u8>,
+    pub subclass: Option<u8>,
+    pub protocol: Option<u8>,
+    /// The USB version, e.g. `2.00`.
+    pub version: Option<String>,
+    /// The negotiated speed in Mbit/s.
+    pub speed: Option<String>,
+    /// The maximum power the active configuration draws, e.g. `500mA`.
+    pub max_power: Option<String>,
+    pub interfaces: Vec<Interface>,
+}
+
+impl UsbDevice {
+    /// The vendor and product name, e.g. `Logitech, Inc. Unifying Receiver`.
+    pub fn name(&self) -> String {
+        let name = [self.vendor_name(), self.product_name()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if name.is_empty() {
+            format!(
+                "{}:{}",
+                self.vendor_id.as_deref().unwrap_or("0000"),
+                self.product_id.as_deref().unwrap_or("0000")
+            )
+        } else {
+            name.join(" ")
+        }
+    }
+
+    fn ids(&self) -> Option<(u16, u16)> {
+        let vendor = u16::from_str_radix(self.vendor_id.as_deref()?, 16).ok()?;
+        let product = u16::from_str_radix(self.product_id.as_deref()?, 16).ok()?;
+        Some((vendor, product))
+    }
+
+    /// The vendor name from usb.ids, falling back to the device's own string.
+    pub fn vendor_name(&self) -> Option<String> {
+        self.ids()
+            .and_then(|(vendor, _)| hwdata::usb_vendor(vendor))
+            .map(str::to_string)
+            .or_else(|| self.manufacturer.clone())
+    }
+
+    /// The product name from usb.ids, falling back to the device's own string.
+    pub fn product_name(&self) -> Option<String> {
+        self.ids()
+            .and_then(|(vendor, product)| hwdata::usb_device(vendor, product))
+            .map(str::to_string)
+            .or_else(|| self.product.clone())
+    }
+}
+
+/// Names for the USB class codes, as assigned by the USB-IF.
+pub fn class_name(class: u8) -> Option<&'static str> {
+    let name = match class {
+        // Class 0 means each interface declares its own.
+        0x00 => "Defined per interface",
+        0x01 => "Audio",
+        0x02 => "Communications",
+        0x03 => "Human Interface Device",
+        0x05 => "Physical",
+        0x06 => "Image",
+        0x07 => "Printer",
+        0x08 => "Mass Storage",
+        0x09 => "Hub",
+        0x0a => "CDC Data",
+        0x0b => "Smart Card",
+        0x0d => "Content Security",
+        0x0e => "Video",
+        0x0f => "Personal Healthcare",
+        0x10 => "Audio/Video",
+        0x11 => "Billboard",
+        0x12 => "USB Type-C Bridge",
+        0xdc => "Diagnostic",
+        0xe0 => "Wireless Controller",
+        0xef => "Miscellaneous",
+        0xfe => "Application Specific",
+        0xff => "Vendor Specific",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn read_hex(path: impl AsRef<Path>) -> Option<u8> {
+    u8::from_str_radix(&read_trimmed(path)?, 16).ok()
+}
+
+fn collect_interfaces(device: &Path, port: &str) -> Vec<Interface> {
+    let mut interfaces = fs::read_dir(device)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&format!("{port}:")))
+        .map(|entry| {
+            let path = entry.path();
+            Interface {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                class: read_hex(path.join("bInterfaceClass")),
+                subclass: read_hex(path.join("bInterfaceSubClass")),
+                protocol: read_hex(path.join("bInterfaceProtocol")),
+                description: read_trimmed(path.join("interface")),
+                driver: fs::read_link(path.join("driver"))
+                    .ok()
+                    .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned())),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    interfaces
+}
+
+fn read_device(path: &Path, port: String) -> Option<UsbDevice> {
+    Some(UsbDevice {
+        bus: read_trimmed(path.join("busnum"))?.parse().ok()?,
+        dev: read_trimmed(path.join("devnum"))?.parse().ok()?,
+        vendor_id: read_trimmed(path.join("idVendor")),
+        product_id: read_trimmed(path.join("idProduct")),
+        manufacturer: read_trimmed(path.join("manufacturer")),
+        product: read_trimmed(path.join("product")),
+        serial: read_trimmed(path.join("serial")),
+        class: read_hex(path.join("bDeviceClass")),
+        subclass: read_hex(path.join("bDeviceSubClass")),
+        protocol: read_hex(path.join("bDeviceProtocol")),
+        version: read_trimmed(path.join("version")),
+        speed: read_trimmed(path.join("speed")),
+        max_power: read_trimmed(path.join("bMaxPower")),
+        interfaces: collect_interfaces(path, &port),
+        port,
+    })
+}
+
+/// Lists USB devices, including root hubs, sorted by bus and device number.
+/// Interfaces, which share the directory, have no bus number and are skipped.
+pub fn devices() -> Vec<UsbDevice> {
+    let mut devices = fs::read_dir(host::path(USB_DEVICES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| read_device(&entry.path(), entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Vec<_>>();
+
+    devices.sort_by_key(|device| (device.bus, device.dev));
+    devices
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Writes a device directory laid out like sysfs under the temp dir.
+    fn fixture(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("examine-usb-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (file, contents) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn reads_device_and_interfaces() {
+        let dir = fixture(
+            "receiver",
+            &[
+                ("busnum", "1\n"),
+                ("devnum", "4\n"),
+                ("idVendor", "046d\n"),
+                ("idProduct", "c52b\n"),
+                ("manufacturer", "Logitech\n"),
+                ("product", "USB Receiver\n"),
+                ("serial", "\n"),
+                ("bDeviceClass", "00\n"),
+                ("version", " 2.00\n"),
+                ("speed", "12\n"),
+                ("bMaxPower", "98mA\n"),
+                ("1-2:1.1/bInterfaceClass", "03\n"),
+                ("1-2:1.0/bInterfaceClass", "03\n"),
+                ("1-2:1.0/bInterfaceProtocol", "01\n"),
+            ],
+        );
+        let device = read_device(&dir, "1-2".to_string()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!((device.bus, device.dev), (1, 4));
+        assert_eq!(device.vendor_id.as_deref(), Some("046d"));
+        assert_eq!(device.serial, None);
+        assert_eq!(device.class, Some(0));
+        assert_eq!(device.version.as_deref(), Some("2.00"));
+        assert_eq!(device.max_power.as_deref(), Some("98mA"));
+        let interfaces = device.interfaces.iter().map(|interface| interface.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(interfaces, ["1-2:1.0", "1-2:1.1"]);
+        assert_eq!(device.interfaces[0].protocol, Some(1));
+        assert_eq!(device.interfaces[1].protocol, None);
+    }
+
+    #[test]
+    fn skips_interfaces_and_malformed_devices() {
+        // Interface directories have no bus number.
+        let dir = fixture("interface", &[("bInterfaceClass", "09\n")]);
+        assert!(read_device(&dir, "usb1:1.0".to_string()).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let dir = fixture("malformed", &[("busnum", "one\n"), ("devnum", "2\n")]);
+        assert!(read_device(&dir, "1-3".to_string()).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let dir = fixture("bad-class", &[("busnum", "1\n"), ("devnum", "2\n"), ("bDeviceClass", "zz\n")]);
+        assert_eq!(read_device(&dir, "1-3".to_string()).unwrap().class, None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_device_strings() {
+        let dir = fixture(
+            "strings",
+            &[("busnum", "2\n"), ("devnum", "3\n"), ("manufacturer", "ACME\n"), ("product", "Widget\n")],
+        );
+        let device = read_device(&dir, "2-1".to_string()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(device.name(), "ACME Widget");
+
+        let device = UsbDevice {
+            manufacturer: None,
+            product: None,
+            ..device
+        };
+        assert_eq!(device.name(), "0000:0000");
+    }
+
+    #[test]
+    fn names_classes() {
+        assert_eq!(class_name(0x09), Some("Hub"));
+        assert_eq!(class_name(0xff), Some("Vendor Specific"));
+        assert_eq!(class_name(0x04), None);
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads runtime power management state of USB devices from sysfs.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::host;
+
+const USB_DEVICES_DIR: &str = "/sys/bus/usb/devices";
+
+/// Interface classes that commonly misbehave when autosuspended: HID devices
+/// drop input events or disconnect, audio devices glitch on resume.
+const SENSITIVE_CLASSES: &[u8] = &[0x01, 0x03];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PowerState {
+    /// `active`, `suspended`, … from `power/runtime_status`.
+    pub runtime_status: Option<String>,
+    /// `auto` when runtime suspend is allowed, `on` when it is blocked.
+    pub control: Option<String>,
+    pub autosuspend_delay_ms: Option<i64>,
+    /// Set when autosuspend is allowed on a device of a class known to misbehave with it.
+    pub at_risk: bool,
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+/// Interface classes of a device, read from its `<device>:<config>.<interface>` children.
+pub fn interface_classes(device: &Path) -> Vec<u8> {
+    let Some(name) = device.file_name().map(|name| name.to_string_lossy().into_owned()) else {
+        return Vec::new();
+    };
+
+    fs::read_dir(device)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&format!("{name}:")))
+        .filter_map(|entry| read_trimmed(entry.path().join("bInterfaceClass")))
+        .filter_map(|class| u8::from_str_radix(&class, 16).ok())
+        .collect()
+}
+
+/// Collects the power state of every USB device, keyed by bus and device number
+/// as printed by `lsusb`.
+pub fn collect() -> HashMap<(u16, u16), PowerState> {
+    fs::read_dir(host::path(USB_DEVICES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let bus = read_trimmed(path.join("busnum"))?.parse().ok()?;
+            let dev = read_trimmed(path.join("devnum"))?.parse().ok()?;
+
+            let control = read_trimmed(path.join("power/control"));
+            let at_risk = control.as_deref() == Some("auto")
+                && interface_classes(&path)
+                    .iter()
+                    .any(|class| SENSITIVE_CLASSES.contains(class));
+
+            Some((
+                (bus, dev),
+                PowerState {
+                    runtime_status: read_trimmed(path.join("power/runtime_status")),
+                    control,
+                    autosuspend_delay_ms: read_trimmed(path.join("power/autosuspend_delay_ms"))
+                        .and_then(|delay| delay.parse().ok()),
+                    at_risk,
+                },
+            ))
+        })
+        .collect()
+}
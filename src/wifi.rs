@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Reads wireless adapter capabilities and the current link with `iw`.
+//! Without `iw` only the adapters themselves are listed, from sysfs.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{host, tools};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Link {
+    pub ssid: Option<String>,
+    pub bssid: Option<String>,
+    /// Frequency in MHz.
+    pub frequency: Option<u32>,
+    /// Signal strength in dBm.
+    pub signal: Option<i32>,
+    pub rx_bitrate: Option<String>,
+    pub tx_bitrate: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Adapter {
+    pub interface: String,
+    pub phy: String,
+    pub driver: Option<String>,
+    pub address: Option<String>,
+    /// Supported bands, such as `2.4 GHz` and `5 GHz`.
+    pub bands: Vec<String>,
+    /// Supported standards, such as `Wi-Fi 6 (802.11ax)`.
+    pub standards: Vec<String>,
+    /// `None` when not connected.
+    pub link: Option<Link>,
+}
+
+fn iw(args: &[&str]) -> Result<String, host::Error> {
+    let output = host::output(host::command("iw").args(args))
+        .map_err(|e| host::spawn_error("iw", &e))?;
+    if !output.status.success() {
+        return Err(host::Error::Failed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses `iw dev` into `(phy, interface, address)` tuples.
+fn parse_dev(output: &str) -> Vec<(String, String, Option<String>)> {
+    let mut interfaces = Vec::new();
+    let mut phy = String::new();
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(index) = line.strip_prefix("phy#") {
+            phy = format!("phy{index}");
+        } else if let Some(interface) = line.strip_prefix("Interface ") {
+            interfaces.push((phy.clone(), interface.to_string(), None));
+        } else if let Some(address) = line.strip_prefix("addr ") {
+            if let Some(last) = interfaces.last_mut() {
+                last.2 = Some(address.to_string());
+            }
+        }
+    }
+    interfaces
+}
+
+/// Derives bands and standards from `iw phy <phy> info`.
+fn parse_phy(output: &str) -> (Vec<String>, Vec<String>) {
+    let mut bands = Vec::new();
+    let (mut ht, mut vht, mut he, mut eht) = (false, false, false, false);
+    for line in output.lines() {
+        let line = line.trim();
+        match line {
+            "Band 1:" => bands.push("2.4 GHz"),
+            "Band 2:" => bands.push("5 GHz"),
+            "Band 3:" => bands.push("60 GHz"),
+            "Band 4:" => bands.push("6 GHz"),
+            _ => {}
+        }
+        ht |= line.starts_with("Capabilities: 0x") || line.starts_with("HT Capabilities");
+        vht |= line.starts_with("VHT Capabilities");
+        he |= line.starts_with("HE Iftypes");
+        eht |= line.starts_with("EHT Iftypes");
+    }
+    bands.dedup();
+
+    let mut standards = Vec::new();
+    if eht {
+        standards.push("Wi-Fi 7 (802.11be)");
+    }
+    if he {
+        standards.push(if bands.contains(&"6 GHz") {
+            "Wi-Fi 6E (802.11ax)"
+        } else {
+            "Wi-Fi 6 (802.11ax)"
+        });
+    }
+    if vht {
+        standards.push("Wi-Fi 5 (802.11ac)");
+    }
+    if ht {
+        standards.push("Wi-Fi 4 (802.11n)");
+    }
+    (
+        bands.into_iter().map(String::from).collect(),
+        standards.into_iter().map(String::from).collect(),
+    )
+}
+
+fn parse_link(output: &str) -> Option<Link> {
+    let mut lines = output.lines();
+    let first = lines.next()?;
+    let bssid = first.strip_prefix("Connected to ")?.split_whitespace().next().map(str::to_string);
+    let mut link = Link {
+        bssid,
+        ..Link::default()
+    };
+
+    for line in lines {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "SSID" => link.ssid = Some(value.to_string()),
+            "freq" => link.frequency = value.split('.').next().and_then(|freq| freq.parse().ok()),
+            "signal" => link.signal = value.split_whitespace().next().and_then(|signal| signal.parse().ok()),
+            "rx bitrate" => link.rx_bitrate = Some(value.to_string()),
+            "tx bitrate" => link.tx_bitrate = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    Some(link)
+}
+
+fn driver(interface: &str) -> Option<String> {
+    fs::read_link(host::path("/sys/class/net").join(interface).join("device/driver"))
+        .ok()
+        .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().into_owned()))
+}
+
+/// Lists wireless interfaces by their `phy80211` link, leaving out the
+/// capabilities and link only `iw` knows.
+fn collect_sysfs() -> Vec<Adapter> {
+    let Ok(entries) = fs::read_dir(host::path("/sys/class/net")) else {
+        return Vec::new();
+    };
+    let mut adapters = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let phy = fs::read_to_string(path.join("phy80211/name")).ok()?;
+            let interface = entry.file_name().to_string_lossy().into_owned();
+            Some(Adapter {
+                driver: driver(&interface),
+                address: fs::read_to_string(path.join("address")).ok().map(|address| address.trim().to_string()),
+                interface,
+                phy: phy.trim().to_string(),
+                bands: Vec::new(),
+                standards: Vec::new(),
+                link: None,
+            })
+        })
+        .collect::<Vec<_>>();
+    adapters.sort_by(|a, b| a.interface.cmp(&b.interface));
+    adapters
+}
+
+pub fn collect() -> Result<Vec<Adapter>, host::Error> {
+    if !tools::is_available("iw") {
+        return Ok(collect_sysfs());
+    }
+
+    let adapters = parse_dev(&iw(&["dev"])?)
+        .into_iter()
+        .map(|(phy, interface, address)| {
+            let (bands, standards) = iw(&["phy", &phy, "info"])
+                .map(|info| parse_phy(&info))
+                .unwrap_or_default();
+            let driver = driver(&interface);
+
+            Adapter {
+                link: iw(&["dev", &interface, "link"]).ok().and_then(|link| parse_link(&link)),
+                interface,
+                phy,
+                driver,
+                address,
+                bands,
+                standards,
+            }
+        })
+        .collect();
+
+    Ok(adapters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEV: &str = "phy#0
+\tInterface wlp0s20f3
+\t\tifindex 3
+\t\twdev 0x1
+\t\taddr 8c:f8:c5:12:34:56
+\t\tssid Home
+\t\ttype managed
+\t\tchannel 36 (5180 MHz), width: 80 MHz, center1: 5210 MHz
+phy#1
+\tInterface wlx001122334455
+\t\ttype managed
+";
+
+    const PHY: &str = "Wiphy phy0
+\tBand 1:
+\t\tCapabilities: 0x19ef
+\t\tHE Iftypes: managed
+\tBand 2:
+\t\tVHT Capabilities (0x039071f6):
+\t\tHE Iftypes: managed
+\tBand 4:
+\t\tHE Iftypes: managed
+";
+
+    const LINK: &str = "Connected to 11:22:33:44:55:66 (on wlp0s20f3)
+\tSSID: Home: 5G
+\tfreq: 5180.0
+\tRX: 123456 bytes (789 packets)
+\tsignal: -52 dBm
+\trx bitrate: 1200.9 MBit/s 80MHz HE-MCS 11 HE-NSS 2 HE-GI 0 HE-DCM 0
+\ttx bitrate: 960.7 MBit/s 80MHz HE-MCS 9 HE-NSS 2 HE-GI 0 HE-DCM 0
+";
+
+    #[test]
+    fn parses_interfaces() {
+        assert_eq!(
+            parse_dev(DEV),
+            [
+                ("phy0".to_string(), "wlp0s20f3".to_string(), Some("8c:f8:c5:12:34:56".to_string())),
+                ("phy1".to_string(), "wlx001122334455".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn derives_bands_and_standards() {
+        let (bands, standards) = parse_phy(PHY);
+        assert_eq!(bands, ["2.4 GHz", "5 GHz", "6 GHz"]);
+        assert_eq!(standards, ["Wi-Fi 6E (802.11ax)", "Wi-Fi 5 (802.11ac)", "Wi-Fi 4 (802.11n)"]);
+
+        let (bands, standards) = parse_phy("\tBand 1:\n\t\tCapabilities: 0x1862\n\tBand 2:\n\t\tHE Iftypes: managed\n");
+        assert_eq!(bands, ["2.4 GHz", "5 GHz"]);
+        assert_eq!(standards, ["Wi-Fi 6 (802.11ax)", "Wi-Fi 4 (802.11n)"]);
+    }
+
+    #[test]
+    fn parses_link() {
+        let link = parse_link(LINK).unwrap();
+        assert_eq!(link.bssid.as_deref(), Some("11:22:33:44:55:66"));
+        assert_eq!(link.ssid.as_deref(), Some("Home: 5G"));
+        assert_eq!(link.frequency, Some(5180));
+        assert_eq!(link.signal, Some(-52));
+        assert!(link.rx_bitrate.unwrap().starts_with("1200.9 MBit/s"));
+        assert!(link.tx_bitrate.unwrap().starts_with("960.7 MBit/s"));
+    }
+
+    #[test]
+    fn ignores_malformed_output() {
+        assert!(parse_dev("").is_empty());
+        // An address before any interface has nothing to attach to.
+        assert!(parse_dev("\t\taddr 00:00:00:00:00:00\n").is_empty());
+
+        assert_eq!(parse_phy(""), (Vec::new(), Vec::new()));
+
+        assert!(parse_link("").is_none());
+        assert!(parse_link("Not connected.\n").is_none());
+        let link = parse_link("Connected to 11:22:33:44:55:66\n\tfreq: fast\n\tsignal: strong\n\tgarbage\n").unwrap();
+        assert_eq!((link.frequency, link.signal, link.ssid), (None, None, None));
+    }
+}